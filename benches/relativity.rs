@@ -23,7 +23,7 @@ fn bench_lorentz(c: &mut Criterion) {
 
     for speed in SPEEDS_TO_MEASURE_AT {
         group.bench_with_input("Lorentz Factor", &speed, |b, i| {
-            b.iter(|| lorentz::slorentz(*i));
+            b.iter(|| lorentz::slorentz(black_box(*i)));
         });
     }
 }
@@ -34,7 +34,7 @@ fn bench_relative_kinetic_energy(c: &mut Criterion) {
     for speed in SPEEDS_TO_MEASURE_AT {
         for mass in MASSES_TO_MEASURE_AT {
             group.bench_with_input("Relative Kinetic Energy", &(speed, mass), |b, i| {
-                b.iter(|| energy::skinetic_energy(speed, mass));
+                b.iter(|| energy::skinetic_energy(black_box(i.0), black_box(i.1)));
             });
         }
     }
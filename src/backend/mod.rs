@@ -0,0 +1,32 @@
+//! # Math Backends
+//! Rust's `core` library does not provide elementary functions such as `sqrt` or `sin` for
+//! floating point numbers on the stable toolchain. This module selects an implementation for those
+//! functions depending on the enabled cargo features:
+//!
+//! - `std` (default) => Uses the inherent f64 methods of the standard library where they are correctly rounded
+//! - `libm` => Uses the pure Rust port of musl's libm, which works without `std`
+//!
+//! If both features are enabled, `std` wins.
+//!
+//! ## Identical results
+//! Both backends implement the same set of functions with the same signatures, once for f64 and once
+//! with a `_f32` suffix for f32, and return identical results for every input:
+//!
+//! - `sqrt` and `mul_add` are correctly rounded, so the inherent methods of `std` and `libm` agree
+//! - `powi` is the same binary exponentiation in both backends
+//! - All other functions are not correctly rounded, so `std` would differ from `libm` in the last bits.
+//!   Both backends use the `libm` implementation of them
+//!
+//! `tests/prelude/backends.rs` checks this.
+//!
+//! The functions of this module are not exposed directly, use [`crate::prelude::NumericalExtensions`] instead.
+
+#[cfg(feature = "std")]
+mod native;
+#[cfg(feature = "std")]
+pub(crate) use native::*;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+mod soft;
+#[cfg(all(feature = "libm", not(feature = "std")))]
+pub(crate) use soft::*;
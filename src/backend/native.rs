@@ -0,0 +1,32 @@
+//! # Standard Library Backend
+//! Forwards `sqrt` and `mul_add` to the inherent f64 methods of the same name. Both are correctly rounded,
+//! so they return the same results as the [`super::soft`] backend while using the hardware instructions.
+//!
+//! The inherent versions of all other functions are not correctly rounded and depend on the platform's
+//! math library, so they are taken from [`super::soft`] instead.
+
+extern crate std;
+
+pub(crate) use super::soft::*;
+
+#[inline]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[inline]
+pub(crate) fn mul_add(x: f64, a: f64, b: f64) -> f64 {
+    x.mul_add(a, b)
+}
+
+// Single precision versions, used by the f32 implementation of `NumericalExtensions`
+
+#[inline]
+pub(crate) fn sqrt_f32(x: f32) -> f32 {
+    x.sqrt()
+}
+
+#[inline]
+pub(crate) fn mul_add_f32(x: f32, a: f32, b: f32) -> f32 {
+    x.mul_add(a, b)
+}
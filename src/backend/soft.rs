@@ -0,0 +1,305 @@
+//! # libm Backend
+//! Forwards every function to [`libm`], a port of musl's libm that works in `no_std` environments.
+//! The `std` backend uses the same functions, except for the correctly rounded `sqrt` and `mul_add`.
+
+// The `std` backend uses the inherent, correctly rounded method instead
+#[cfg_attr(feature = "std", allow(dead_code))]
+#[inline]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[inline]
+pub(crate) fn cbrt(x: f64) -> f64 {
+    libm::cbrt(x)
+}
+
+#[inline]
+pub(crate) fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+#[inline]
+pub(crate) fn exp_m1(x: f64) -> f64 {
+    libm::expm1(x)
+}
+
+#[inline]
+pub(crate) fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+#[inline]
+pub(crate) fn ln_1p(x: f64) -> f64 {
+    libm::log1p(x)
+}
+
+#[inline]
+pub(crate) fn log10(x: f64) -> f64 {
+    libm::log10(x)
+}
+
+#[inline]
+pub(crate) fn log2(x: f64) -> f64 {
+    libm::log2(x)
+}
+
+#[inline]
+pub(crate) fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[inline]
+pub(crate) fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[inline]
+pub(crate) fn tan(x: f64) -> f64 {
+    libm::tan(x)
+}
+
+#[inline]
+pub(crate) fn asin(x: f64) -> f64 {
+    libm::asin(x)
+}
+
+#[inline]
+pub(crate) fn acos(x: f64) -> f64 {
+    libm::acos(x)
+}
+
+#[inline]
+pub(crate) fn atan(x: f64) -> f64 {
+    libm::atan(x)
+}
+
+#[inline]
+pub(crate) fn sinh(x: f64) -> f64 {
+    libm::sinh(x)
+}
+
+#[inline]
+pub(crate) fn cosh(x: f64) -> f64 {
+    libm::cosh(x)
+}
+
+#[inline]
+pub(crate) fn tanh(x: f64) -> f64 {
+    libm::tanh(x)
+}
+
+#[inline]
+pub(crate) fn asinh(x: f64) -> f64 {
+    libm::asinh(x)
+}
+
+#[inline]
+pub(crate) fn acosh(x: f64) -> f64 {
+    libm::acosh(x)
+}
+
+#[inline]
+pub(crate) fn atanh(x: f64) -> f64 {
+    libm::atanh(x)
+}
+
+/// Binary exponentiation, the same algorithm the compiler uses for `f64::powi` (compiler-rt's `__powidf2`).
+/// Both backends use this function, as Rust does not guarantee the rounding of `f64::powi`
+#[inline]
+pub(crate) fn powi(x: f64, n: i32) -> f64 {
+    let mut base = x;
+    let mut exp = n;
+    let mut result = 1f64;
+
+    loop {
+        if exp & 1 != 0 {
+            result *= base;
+        }
+        exp /= 2;
+        if exp == 0 {
+            break;
+        }
+        base *= base;
+    }
+
+    if n < 0 {
+        1f64 / result
+    } else {
+        result
+    }
+}
+
+#[inline]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    libm::pow(x, n)
+}
+
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+#[inline]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    libm::hypot(x, y)
+}
+
+// The `std` backend uses the inherent, correctly rounded method instead
+#[cfg_attr(feature = "std", allow(dead_code))]
+#[inline]
+pub(crate) fn mul_add(x: f64, a: f64, b: f64) -> f64 {
+    libm::fma(x, a, b)
+}
+
+// Single precision versions, used by the f32 implementation of `NumericalExtensions`
+
+// The `std` backend uses the inherent, correctly rounded method instead
+#[cfg_attr(feature = "std", allow(dead_code))]
+#[inline]
+pub(crate) fn sqrt_f32(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+#[inline]
+pub(crate) fn cbrt_f32(x: f32) -> f32 {
+    libm::cbrtf(x)
+}
+
+#[inline]
+pub(crate) fn exp_f32(x: f32) -> f32 {
+    libm::expf(x)
+}
+
+#[inline]
+pub(crate) fn exp_m1_f32(x: f32) -> f32 {
+    libm::expm1f(x)
+}
+
+#[inline]
+pub(crate) fn ln_f32(x: f32) -> f32 {
+    libm::logf(x)
+}
+
+#[inline]
+pub(crate) fn ln_1p_f32(x: f32) -> f32 {
+    libm::log1pf(x)
+}
+
+#[inline]
+pub(crate) fn log10_f32(x: f32) -> f32 {
+    libm::log10f(x)
+}
+
+#[inline]
+pub(crate) fn log2_f32(x: f32) -> f32 {
+    libm::log2f(x)
+}
+
+#[inline]
+pub(crate) fn sin_f32(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+#[inline]
+pub(crate) fn cos_f32(x: f32) -> f32 {
+    libm::cosf(x)
+}
+
+#[inline]
+pub(crate) fn tan_f32(x: f32) -> f32 {
+    libm::tanf(x)
+}
+
+#[inline]
+pub(crate) fn asin_f32(x: f32) -> f32 {
+    libm::asinf(x)
+}
+
+#[inline]
+pub(crate) fn acos_f32(x: f32) -> f32 {
+    libm::acosf(x)
+}
+
+#[inline]
+pub(crate) fn atan_f32(x: f32) -> f32 {
+    libm::atanf(x)
+}
+
+#[inline]
+pub(crate) fn sinh_f32(x: f32) -> f32 {
+    libm::sinhf(x)
+}
+
+#[inline]
+pub(crate) fn cosh_f32(x: f32) -> f32 {
+    libm::coshf(x)
+}
+
+#[inline]
+pub(crate) fn tanh_f32(x: f32) -> f32 {
+    libm::tanhf(x)
+}
+
+#[inline]
+pub(crate) fn asinh_f32(x: f32) -> f32 {
+    libm::asinhf(x)
+}
+
+#[inline]
+pub(crate) fn acosh_f32(x: f32) -> f32 {
+    libm::acoshf(x)
+}
+
+#[inline]
+pub(crate) fn atanh_f32(x: f32) -> f32 {
+    libm::atanhf(x)
+}
+
+/// Binary exponentiation, the same algorithm the compiler uses for `f32::powi` (compiler-rt's `__powisf2`).
+/// Both backends use this function, as Rust does not guarantee the rounding of `f32::powi`
+#[inline]
+pub(crate) fn powi_f32(x: f32, n: i32) -> f32 {
+    let mut base = x;
+    let mut exp = n;
+    let mut result = 1f32;
+
+    loop {
+        if exp & 1 != 0 {
+            result *= base;
+        }
+        exp /= 2;
+        if exp == 0 {
+            break;
+        }
+        base *= base;
+    }
+
+    if n < 0 {
+        1f32 / result
+    } else {
+        result
+    }
+}
+
+#[inline]
+pub(crate) fn powf_f32(x: f32, n: f32) -> f32 {
+    libm::powf(x, n)
+}
+
+#[inline]
+pub(crate) fn atan2_f32(y: f32, x: f32) -> f32 {
+    libm::atan2f(y, x)
+}
+
+#[inline]
+pub(crate) fn hypot_f32(x: f32, y: f32) -> f32 {
+    libm::hypotf(x, y)
+}
+
+// The `std` backend uses the inherent, correctly rounded method instead
+#[cfg_attr(feature = "std", allow(dead_code))]
+#[inline]
+pub(crate) fn mul_add_f32(x: f32, a: f32, b: f32) -> f32 {
+    libm::fmaf(x, a, b)
+}
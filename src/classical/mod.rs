@@ -1 +1,5 @@
+//! # Classical Mechanics
+//! Classical mechanics describes the motion of macroscopic bodies at speeds much smaller than the speed
+//! of light. For speeds above roughly 1% of `c`, use the functions of [`crate::relativity`] instead.
+
 pub mod newtonian;
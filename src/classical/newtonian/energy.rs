@@ -0,0 +1,224 @@
+//! # Newtonian Energy, Work & Power
+//! Energy is the capacity of a body to perform work. In classical mechanics, the mechanical energy of a body
+//! is split into kinetic energy (due to its motion) and potential energy (due to its position in a field).
+//!
+//! Work is the energy transferred to a body by a force acting along a displacement,
+//! power is the rate at which work is done.
+//!
+//! ## Relativistic vs Newtonian
+//! The newtonian kinetic energy is only accurate for speeds well below the speed of light.
+//! Use [`crate::relativity::energy`] if `v` is bigger than 1% of `c`.
+
+use crate::consts::STANDARD_GRAVITY;
+use crate::prelude::*;
+use crate::vectors::{vec3::Vec3, GenericVector};
+
+/// # Simple Kinetic Energy
+/// Calculates the newtonian kinetic energy, KE = ½mv^2
+///
+/// ## Relativistic vs Newtonian
+/// Use this equation if `v` is smaller than 1% of `c`
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Kinetic Energy (KE :: J)
+///
+/// ## Examples
+/// ```rs
+/// let joules = skinetic_energy(30f64, 1000f64);
+/// ```
+///
+/// ## Related Functions
+/// `skinetic_energy_vec3` => Takes a velocity vector
+/// `relativity::energy::skinetic_energy` => The relativistic kinetic energy
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy>
+pub fn skinetic_energy<T: Real>(speed: T, mass: T) -> T {
+    T::from_f64(0.5f64) * mass * speed.powi(2)
+}
+
+/// # Simple Kinetic Energy (Vector)
+/// Calculates the newtonian kinetic energy from a velocity vector, KE = ½m(v⃗ · v⃗)
+///
+/// ## Variables
+/// ### Parameters
+/// - velocity (v⃗ :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Kinetic Energy (KE :: J)
+///
+/// ## Related Functions
+/// `skinetic_energy` => Takes the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy>
+pub fn skinetic_energy_vec3<T: Real>(velocity: Vec3<T>, mass: T) -> T {
+    T::from_f64(0.5f64) * mass * velocity.length_squared()
+}
+
+/// # Simple Potential Energy
+/// Calculates the gravitational potential energy near the surface of the earth, PE = mgh,
+/// using the standard gravity
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The mass of the body
+/// - height (h :: m) = The height above the reference level
+/// ### Returns
+/// - Potential Energy (PE :: J)
+///
+/// ## Related Functions
+/// `cpotential_energy` => Takes a custom gravitational acceleration
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_energy>
+pub fn spotential_energy<T: Real>(mass: T, height: T) -> T {
+    cpotential_energy(mass, height, T::from_f64(STANDARD_GRAVITY))
+}
+
+/// # Complex Potential Energy
+/// Calculates the gravitational potential energy in a uniform gravitational field, PE = mgh
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The mass of the body
+/// - height (h :: m) = The height above the reference level
+/// - gravity (g :: m/s^2) = The gravitational acceleration
+/// ### Returns
+/// - Potential Energy (PE :: J)
+///
+/// ## Examples
+/// ```rs
+/// let on_the_moon = cpotential_energy(80f64, 10f64, 1.62f64);
+/// ```
+///
+/// ## Related Functions
+/// `spotential_energy` => Uses the standard gravity of the earth
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_energy>
+pub fn cpotential_energy<T: Real>(mass: T, height: T, gravity: T) -> T {
+    mass * gravity * height
+}
+
+/// # Simple Work
+/// Calculates the work done by a constant force along the direction of motion, W = Fd
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The force, parallel to the displacement
+/// - distance (d :: m) = The distance the body moved
+/// ### Returns
+/// - Work (W :: J)
+///
+/// ## Related Functions
+/// `cwork` => The force acts at an angle to the displacement
+/// `swork_vec3` => Vector form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Work_(physics)>
+pub fn swork<T: Real>(force: T, distance: T) -> T {
+    force * distance
+}
+
+/// # Complex Work
+/// Calculates the work done by a constant force acting at an angle to the displacement, W = Fd cos(θ)
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The magnitude of the force
+/// - distance (d :: m) = The distance the body moved
+/// - angle (θ :: rad) = The angle between the force and the displacement
+/// ### Returns
+/// - Work (W :: J)
+///
+/// ## Related Functions
+/// `swork` => The force is parallel to the displacement
+/// `swork_vec3` => Vector form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Work_(physics)>
+pub fn cwork<T: Real>(force: T, distance: T, angle: T) -> T {
+    force * distance * angle.cos()
+}
+
+/// # Simple Work (Vector)
+/// Calculates the work done by a constant force, W = F⃗ · d⃗
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F⃗ :: N) = The force acting on the body
+/// - displacement (d⃗ :: m) = The displacement of the body
+/// ### Returns
+/// - Work (W :: J)
+///
+/// ## Related Functions
+/// `swork` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Work_(physics)>
+pub fn swork_vec3<T: Real>(force: Vec3<T>, displacement: Vec3<T>) -> T {
+    force | displacement
+}
+
+/// # Simple Power
+/// Calculates the average power, P = W / Δt
+///
+/// ## Variables
+/// ### Parameters
+/// - work (W :: J) = The work done
+/// - time (Δt :: s) = The time it took to do the work
+/// ### Returns
+/// - Power (P :: W)
+///
+/// ## Related Functions
+/// `cpower` => The instantaneous power from force and speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Power_(physics)>
+pub fn spower<T: Real>(work: T, time: T) -> T {
+    work / time
+}
+
+/// # Complex Power
+/// Calculates the instantaneous power of a force acting along the direction of motion, P = Fv
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The force, parallel to the velocity
+/// - speed (v :: m/s)
+/// ### Returns
+/// - Power (P :: W)
+///
+/// ## Related Functions
+/// `cpower_vec3` => Vector form
+/// `spower` => The average power from work and time
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Power_(physics)#Mechanical_power>
+pub fn cpower<T: Real>(force: T, speed: T) -> T {
+    force * speed
+}
+
+/// # Complex Power (Vector)
+/// Calculates the instantaneous power of a force, P = F⃗ · v⃗
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F⃗ :: N) = The force acting on the body
+/// - velocity (v⃗ :: m/s)
+/// ### Returns
+/// - Power (P :: W)
+///
+/// ## Related Functions
+/// `cpower` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Power_(physics)#Mechanical_power>
+pub fn cpower_vec3<T: Real>(force: Vec3<T>, velocity: Vec3<T>) -> T {
+    force | velocity
+}
@@ -0,0 +1,343 @@
+//! # Kinematics with constant acceleration
+//! If the acceleration of a body is constant, its motion is fully described by five variables,
+//! often remembered by the acronym SUVAT:
+//!
+//! - s = displacement (m)
+//! - u = initial velocity (m/s)
+//! - v = final velocity (m/s)
+//! - a = acceleration (m/s^2)
+//! - t = time (s)
+//!
+//! They are related by the following equations, each of which is missing one of the variables:
+//!
+//! v = u + at
+//!
+//! s = (u + v)t / 2
+//!
+//! s = ut + ½at^2
+//!
+//! s = vt - ½at^2
+//!
+//! v^2 = u^2 + 2as
+//!
+//! Therefore, if any three of the variables are known, the other two can be calculated. This is what [`csuvat`] does.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Equations_of_motion#Constant_translational_acceleration_in_a_straight_line>
+
+use core::cmp::Ordering;
+
+use crate::prelude::*;
+use crate::vectors::vec3::Vec3;
+
+/// # SUVAT Variables
+/// The known variables of a motion with constant acceleration. Set at least three of them
+///
+/// ## Example
+/// ```rs
+/// // Dropping a ball from 20m
+/// let known = Suvat {
+///     displacement: Some(20f64),
+///     initial_velocity: Some(0f64),
+///     acceleration: Some(STANDARD_GRAVITY),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Suvat<T = f64> {
+    /// s :: m
+    pub displacement: Option<T>,
+    /// u :: m/s
+    pub initial_velocity: Option<T>,
+    /// v :: m/s
+    pub final_velocity: Option<T>,
+    /// a :: m/s^2
+    pub acceleration: Option<T>,
+    /// t :: s
+    pub time: Option<T>,
+}
+
+/// # SUVAT Solution
+/// All five variables of a motion with constant acceleration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SuvatSolution<T = f64> {
+    /// s :: m
+    pub displacement: T,
+    /// u :: m/s
+    pub initial_velocity: T,
+    /// v :: m/s
+    pub final_velocity: T,
+    /// a :: m/s^2
+    pub acceleration: T,
+    /// t :: s
+    pub time: T,
+}
+
+/// # Simple Final Velocity
+/// Calculates the velocity after accelerating for some time, v = u + at
+///
+/// ## Variables
+/// ### Parameters
+/// - initial_velocity (u :: m/s)
+/// - accel (a :: m/s^2) = The constant acceleration
+/// - time (t :: s)
+/// ### Returns
+/// - Final Velocity (v :: m/s)
+///
+/// ## Examples
+/// ```rs
+/// let after_one_second_of_free_fall = sfinal_velocity(0f64, STANDARD_GRAVITY, 1f64);
+/// ```
+///
+/// ## Related Functions
+/// `sfinal_velocity_vec3` => Vector form
+/// `csuvat` => Solve for any two unknowns
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Equations_of_motion#Constant_translational_acceleration_in_a_straight_line>
+pub fn sfinal_velocity<T: Real>(initial_velocity: T, accel: T, time: T) -> T {
+    initial_velocity + accel * time
+}
+
+/// # Simple Final Velocity (Vector)
+/// Calculates the velocity after accelerating for some time, v⃗ = u⃗ + a⃗t
+///
+/// ## Variables
+/// ### Parameters
+/// - initial_velocity (u⃗ :: m/s)
+/// - accel (a⃗ :: m/s^2) = The constant acceleration
+/// - time (t :: s)
+/// ### Returns
+/// - Final Velocity (v⃗ :: m/s)
+///
+/// ## Related Functions
+/// `sfinal_velocity` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Equations_of_motion#Constant_translational_acceleration_in_a_straight_line>
+pub fn sfinal_velocity_vec3<T: Real>(
+    initial_velocity: Vec3<T>,
+    accel: Vec3<T>,
+    time: T,
+) -> Vec3<T> {
+    initial_velocity + accel * time
+}
+
+/// # Simple Displacement
+/// Calculates the displacement after accelerating for some time, s = ut + ½at^2
+///
+/// ## Variables
+/// ### Parameters
+/// - initial_velocity (u :: m/s)
+/// - accel (a :: m/s^2) = The constant acceleration
+/// - time (t :: s)
+/// ### Returns
+/// - Displacement (s :: m)
+///
+/// ## Examples
+/// ```rs
+/// let fallen = sdisplacement(0f64, STANDARD_GRAVITY, 2f64);
+/// ```
+///
+/// ## Related Functions
+/// `sdisplacement_vec3` => Vector form
+/// `csuvat` => Solve for any two unknowns
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Equations_of_motion#Constant_translational_acceleration_in_a_straight_line>
+pub fn sdisplacement<T: Real>(initial_velocity: T, accel: T, time: T) -> T {
+    initial_velocity * time + T::from_f64(0.5f64) * accel * time.powi(2)
+}
+
+/// # Simple Displacement (Vector)
+/// Calculates the displacement after accelerating for some time, s⃗ = u⃗t + ½a⃗t^2
+///
+/// ## Variables
+/// ### Parameters
+/// - initial_velocity (u⃗ :: m/s)
+/// - accel (a⃗ :: m/s^2) = The constant acceleration
+/// - time (t :: s)
+/// ### Returns
+/// - Displacement (s⃗ :: m)
+///
+/// ## Examples
+/// ```rs
+/// // A projectile launched at 45°
+/// let position = sdisplacement_vec3(Vec3::new(10f64, 0f64, 10f64), Vec3::down() * STANDARD_GRAVITY, 1f64);
+/// ```
+///
+/// ## Related Functions
+/// `sdisplacement` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Equations_of_motion#Constant_translational_acceleration_in_a_straight_line>
+pub fn sdisplacement_vec3<T: Real>(initial_velocity: Vec3<T>, accel: Vec3<T>, time: T) -> Vec3<T> {
+    initial_velocity * time + accel * (T::from_f64(0.5f64) * time.powi(2))
+}
+
+/// # Complex SUVAT
+/// Solves the equations of motion with constant acceleration for the unknown variables.
+///
+/// Returns `None` if fewer than three variables are known, or if there is no physical solution
+/// (e.g. a negative time, or a body that never reaches the displacement). A time of 0 is only accepted
+/// if the acceleration and the initial velocity are known, as the other variables cannot be derived from it.
+/// If more than three variables are known, the solution has to satisfy v = u + at and s = (u + v)t / 2
+/// with all of them, within a relative tolerance of 1e-6. Otherwise, `None` is returned.
+///
+/// If there are two possible solutions (e.g. a ball thrown upwards passes a height twice),
+/// the one with the smaller non-negative time is returned.
+///
+/// ## Variables
+/// ### Parameters
+/// - known (s, u, v, a, t) = At least three known variables
+/// ### Returns
+/// - Solution (s, u, v, a, t) = All five variables
+///
+/// ## Examples
+/// ```rs
+/// // How long does a ball dropped from 20m take to hit the ground, and how fast is it?
+/// let solution = csuvat(Suvat {
+///     displacement: Some(20f64),
+///     initial_velocity: Some(0f64),
+///     acceleration: Some(STANDARD_GRAVITY),
+///     ..Default::default()
+/// }).unwrap();
+/// ```
+///
+/// ## Related Functions
+/// `sfinal_velocity` => v from u, a and t
+/// `sdisplacement` => s from u, a and t
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Equations_of_motion#Constant_translational_acceleration_in_a_straight_line>
+pub fn csuvat<T: Real>(known: Suvat<T>) -> Option<SuvatSolution<T>> {
+    let (two, half) = (T::from_f64(2f64), T::from_f64(0.5f64));
+    let Suvat {
+        displacement: s,
+        initial_velocity: u,
+        final_velocity: v,
+        acceleration: a,
+        time: t,
+    } = known;
+    if let Some(t) = t {
+        non_negative(t)?;
+    }
+    let known_count = [
+        s.is_some(),
+        u.is_some(),
+        v.is_some(),
+        a.is_some(),
+        t.is_some(),
+    ]
+    .into_iter()
+    .filter(|&is_known| is_known)
+    .count();
+
+    let (s, u, v, a, t) = match (s, u, v, a, t) {
+        (_, Some(u), _, Some(a), Some(t)) => {
+            let v = v.unwrap_or(sfinal_velocity(u, a, t));
+            (s.unwrap_or(sdisplacement(u, a, t)), u, v, a, t)
+        }
+        (_, _, Some(v), Some(a), Some(t)) => {
+            let u = v - a * t;
+            (s.unwrap_or(sdisplacement(u, a, t)), u, v, a, t)
+        }
+        (_, Some(u), Some(v), _, Some(t)) => {
+            let t = positive(t)?;
+            let s = s.unwrap_or((u + v) * t / two);
+            (s, u, v, (v - u) / t, t)
+        }
+        (Some(s), _, _, Some(a), Some(t)) => {
+            let t = positive(t)?;
+            let u = s / t - half * a * t;
+            (s, u, sfinal_velocity(u, a, t), a, t)
+        }
+        (Some(s), _, Some(v), _, Some(t)) => {
+            let t = positive(t)?;
+            let u = two * s / t - v;
+            (s, u, v, (v - u) / t, t)
+        }
+        (Some(s), Some(u), _, _, Some(t)) => {
+            let t = positive(t)?;
+            let v = two * s / t - u;
+            (s, u, v, (v - u) / t, t)
+        }
+        (_, Some(u), Some(v), Some(a), None) if a != T::ZERO => {
+            let t = non_negative((v - u) / a)?;
+            (s.unwrap_or((v.powi(2) - u.powi(2)) / (two * a)), u, v, a, t)
+        }
+        (Some(s), Some(u), Some(v), None, None) => {
+            let t = positive(two * s / (u + v))?;
+            (s, u, v, (v - u) / t, t)
+        }
+        (Some(s), Some(u), _, Some(a), None) => {
+            // v is only known here if a = 0, in which case it has to be u
+            let (v, t) = if a == T::ZERO {
+                (v.unwrap_or(u), non_negative(s / u)?)
+            } else {
+                // v^2 = u^2 + 2as
+                earliest(u.powi(2) + two * a * s, |v| (v - u) / a)?
+            };
+            (s, u, v, a, t)
+        }
+        (Some(s), None, Some(v), Some(a), None) => {
+            let (u, t) = if a == T::ZERO {
+                (v, non_negative(s / v)?)
+            } else {
+                // u^2 = v^2 - 2as
+                earliest(v.powi(2) - two * a * s, |u| (v - u) / a)?
+            };
+            (s, u, v, a, t)
+        }
+        _ => return None,
+    };
+
+    if known_count > 3 && !consistent(s, u, v, a, t) {
+        return None;
+    }
+
+    Some(SuvatSolution {
+        displacement: s,
+        initial_velocity: u,
+        final_velocity: v,
+        acceleration: a,
+        time: t,
+    })
+}
+
+/// Returns the time if it is finite and not negative
+fn non_negative<T: Real>(time: T) -> Option<T> {
+    (time.is_finite() && time >= T::ZERO).then_some(time)
+}
+
+/// Returns the time if it is finite and greater than 0, for solutions that divide by it
+fn positive<T: Real>(time: T) -> Option<T> {
+    (time.is_finite() && time > T::ZERO).then_some(time)
+}
+
+/// Checks v = u + at and s = (u + v)t / 2 within a relative tolerance of 1e-6
+fn consistent<T: Real>(s: T, u: T, v: T, a: T, t: T) -> bool {
+    let (two, tolerance) = (T::from_f64(2f64), T::from_f64(1e-6));
+    let close = |lhs: T, rhs: T, scale: T| (lhs - rhs).abs() <= tolerance * scale;
+
+    close(v, u + a * t, v.abs() + u.abs() + (a * t).abs())
+        && close(
+            two * s,
+            (u + v) * t,
+            two * s.abs() + (u.abs() + v.abs()) * t,
+        )
+}
+
+/// Solves velocity^2 = `squared` for both signs of the velocity and returns the velocity
+/// with the earliest non-negative time according to `time_of`
+fn earliest<T: Real>(squared: T, time_of: impl Fn(T) -> T) -> Option<(T, T)> {
+    if squared < T::ZERO {
+        return None;
+    }
+
+    let root = squared.sqrt();
+    [root, -root]
+        .into_iter()
+        .filter_map(|velocity| Some((velocity, non_negative(time_of(velocity))?)))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+}
@@ -1,13 +1,128 @@
-use crate::prelude::*;
+//! # Newtonian Mechanics
+//! Newton's laws of motion, published in 1687 in the "Philosophiæ Naturalis Principia Mathematica":
+//!
+//! 1. A body remains at rest or in uniform motion unless acted upon by a force
+//! 2. F = ma, the force acting on a body equals its mass times its acceleration
+//! 3. For every action, there is an equal and opposite reaction
+//!
+//! ## About this collection of equations
+//! - This file => Newton's second law
+//! - [`kinematics`] => Motion with constant acceleration (SUVAT equations)
+//! - [`momentum`] => Momentum and impulse
+//! - [`energy`] => Kinetic & potential energy, work and power
+//! - [`typed`] => Variants of the functions in this file using [`crate::quantities`]
+//!
+//! Most functions have a `_vec3` variant for the vector form of the equation.
 
-pub fn sforce(mass: f64, accel: f64) -> f64 {
+pub mod energy;
+pub mod kinematics;
+pub mod momentum;
+pub mod typed;
+
+use crate::prelude::Real;
+use crate::vectors::vec3::Vec3;
+
+/// # Simple Force
+/// Newton's second law, F = ma
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The mass of the body
+/// - accel (a :: m/s^2) = The acceleration of the body
+/// ### Returns
+/// - Force (F :: N)
+///
+/// ## Examples
+/// ```rs
+/// let weight = sforce(80f64, STANDARD_GRAVITY);
+/// ```
+///
+/// ## Related Functions
+/// `sforce_vec3` => Vector form
+/// `smass` => Solved for the mass
+/// `saccel` => Solved for the acceleration
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Newton%27s_laws_of_motion#Second_law>
+pub fn sforce<T: Real>(mass: T, accel: T) -> T {
     mass * accel
 }
 
-pub fn smass(force: f64, accel: f64) -> f64 {
+/// # Simple Force (Vector)
+/// Newton's second law, F⃗ = ma⃗
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The mass of the body
+/// - accel (a⃗ :: m/s^2) = The acceleration of the body
+/// ### Returns
+/// - Force (F⃗ :: N)
+///
+/// ## Related Functions
+/// `sforce` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Newton%27s_laws_of_motion#Second_law>
+pub fn sforce_vec3<T: Real>(mass: T, accel: Vec3<T>) -> Vec3<T> {
+    accel * mass
+}
+
+/// # Simple Mass
+/// Newton's second law solved for the mass, m = F / a
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The force acting on the body
+/// - accel (a :: m/s^2) = The acceleration of the body
+/// ### Returns
+/// - Mass (m :: kg)
+///
+/// ## Related Functions
+/// `sforce` => Solved for the force
+/// `saccel` => Solved for the acceleration
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Newton%27s_laws_of_motion#Second_law>
+pub fn smass<T: Real>(force: T, accel: T) -> T {
     force / accel
 }
 
-pub fn saccel(force: f64, mass: f64) -> f64 {
+/// # Simple Acceleration
+/// Newton's second law solved for the acceleration, a = F / m
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The force acting on the body
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Acceleration (a :: m/s^2)
+///
+/// ## Related Functions
+/// `saccel_vec3` => Vector form
+/// `sforce` => Solved for the force
+/// `smass` => Solved for the mass
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Newton%27s_laws_of_motion#Second_law>
+pub fn saccel<T: Real>(force: T, mass: T) -> T {
+    force / mass
+}
+
+/// # Simple Acceleration (Vector)
+/// Newton's second law solved for the acceleration, a⃗ = F⃗ / m
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F⃗ :: N) = The force acting on the body
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Acceleration (a⃗ :: m/s^2)
+///
+/// ## Related Functions
+/// `saccel` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Newton%27s_laws_of_motion#Second_law>
+pub fn saccel_vec3<T: Real>(force: Vec3<T>, mass: T) -> Vec3<T> {
     force / mass
 }
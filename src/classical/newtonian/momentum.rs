@@ -0,0 +1,136 @@
+//! # Momentum & Impulse
+//! The (linear) momentum of a body is the product of its mass and its velocity. In a closed system,
+//! the total momentum is conserved.
+//!
+//! An impulse is the change of momentum caused by a force acting on a body over a period of time.
+//! According to Newton's second law, J = FΔt = Δp.
+
+use crate::prelude::Real;
+use crate::vectors::vec3::Vec3;
+
+/// # Simple Momentum
+/// Calculates the newtonian momentum of a body, p = mv
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Momentum (p :: kg m/s)
+///
+/// ## Examples
+/// ```rs
+/// let momentum = smomentum(30f64, 1000f64);
+/// ```
+///
+/// ## Related Functions
+/// `smomentum_vec3` => Vector form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Momentum>
+pub fn smomentum<T: Real>(speed: T, mass: T) -> T {
+    speed * mass
+}
+
+/// # Simple Momentum (Vector)
+/// Calculates the newtonian momentum of a body, p⃗ = mv⃗
+///
+/// ## Variables
+/// ### Parameters
+/// - velocity (v⃗ :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Momentum (p⃗ :: kg m/s)
+///
+/// ## Related Functions
+/// `smomentum` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Momentum>
+pub fn smomentum_vec3<T: Real>(velocity: Vec3<T>, mass: T) -> Vec3<T> {
+    velocity * mass
+}
+
+/// # Simple Impulse
+/// Calculates the impulse of a constant force, J = FΔt
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The constant force acting on the body
+/// - time (Δt :: s) = The time the force acts on the body
+/// ### Returns
+/// - Impulse (J :: N s)
+///
+/// ## Related Functions
+/// `simpulse_vec3` => Vector form
+/// `cimpulse` => The impulse from the change in velocity
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Impulse_(physics)>
+pub fn simpulse<T: Real>(force: T, time: T) -> T {
+    force * time
+}
+
+/// # Simple Impulse (Vector)
+/// Calculates the impulse of a constant force, J⃗ = F⃗Δt
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F⃗ :: N) = The constant force acting on the body
+/// - time (Δt :: s) = The time the force acts on the body
+/// ### Returns
+/// - Impulse (J⃗ :: N s)
+///
+/// ## Related Functions
+/// `simpulse` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Impulse_(physics)>
+pub fn simpulse_vec3<T: Real>(force: Vec3<T>, time: T) -> Vec3<T> {
+    force * time
+}
+
+/// # Complex Impulse
+/// Calculates the impulse from the change in momentum of a body, J = m(v - u)
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The mass of the body
+/// - initial_speed (u :: m/s)
+/// - final_speed (v :: m/s)
+/// ### Returns
+/// - Impulse (J :: N s)
+///
+/// ## Related Functions
+/// `cimpulse_vec3` => Vector form
+/// `simpulse` => The impulse from a constant force
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Impulse_(physics)>
+pub fn cimpulse<T: Real>(mass: T, initial_speed: T, final_speed: T) -> T {
+    mass * (final_speed - initial_speed)
+}
+
+/// # Complex Impulse (Vector)
+/// Calculates the impulse from the change in momentum of a body, J⃗ = m(v⃗ - u⃗)
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The mass of the body
+/// - initial_velocity (u⃗ :: m/s)
+/// - final_velocity (v⃗ :: m/s)
+/// ### Returns
+/// - Impulse (J⃗ :: N s)
+///
+/// ## Related Functions
+/// `cimpulse` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Impulse_(physics)>
+pub fn cimpulse_vec3<T: Real>(
+    mass: T,
+    initial_velocity: Vec3<T>,
+    final_velocity: Vec3<T>,
+) -> Vec3<T> {
+    (final_velocity - initial_velocity) * mass
+}
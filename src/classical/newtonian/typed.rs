@@ -0,0 +1,44 @@
+//! # Typed Newtonian Mechanics
+//! The functions of this file are the same as the ones in [`super`], but take and return
+//! [`crate::quantities`] instead of raw f64's, so the arguments cannot be swapped by accident.
+
+use crate::quantities::{Acceleration, Force, Mass};
+
+/// # Simple Force
+/// Newton's second law, F = ma
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The mass of the body
+/// - accel (a :: m/s^2) = The acceleration of the body
+/// ### Returns
+/// - Force (F :: N)
+pub fn sforce(mass: Mass, accel: Acceleration) -> Force {
+    Force(super::sforce(mass.0, accel.0))
+}
+
+/// # Simple Mass
+/// Newton's second law solved for the mass, m = F / a
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The force acting on the body
+/// - accel (a :: m/s^2) = The acceleration of the body
+/// ### Returns
+/// - Mass (m :: kg)
+pub fn smass(force: Force, accel: Acceleration) -> Mass {
+    Mass(super::smass(force.0, accel.0))
+}
+
+/// # Simple Acceleration
+/// Newton's second law solved for the acceleration, a = F / m
+///
+/// ## Variables
+/// ### Parameters
+/// - force (F :: N) = The force acting on the body
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Acceleration (a :: m/s^2)
+pub fn saccel(force: Force, mass: Mass) -> Acceleration {
+    Acceleration(super::saccel(force.0, mass.0))
+}
@@ -0,0 +1,162 @@
+//! # Astronomical Constants
+//! Constants for orbital mechanics and astronomy, mostly the nominal values adopted by the International
+//! Astronomical Union (IAU). Every constant records its source in `source`.
+//!
+//! ## Nominal values
+//! Bodies like the sun do not have one exact radius or mass. The IAU fixed nominal values (with the superscript N)
+//! that are exact by definition and serve as units, e.g. for exoplanet radii. They are marked as exact, even though
+//! the true value of the body may differ.
+//!
+//! ## GM vs G·M
+//! The product of the gravitational constant and the mass of a body is known far more precisely than either factor:
+//! GM of the sun is known to 11 digits from planetary orbits, G only to 5. Use the GM constants for orbits.
+//! The masses in this file are GM / G and inherit the uncertainty of G.
+//!
+//! ## Read more
+//! <https://www.iau.org/static/resolutions/IAU2015_English.pdf>
+//! <https://en.wikipedia.org/wiki/Astronomical_constant>
+
+use super::codata::{find_in, PhysicalConstant};
+use super::SPEED_OF_LIGHT;
+
+const IAU_2012: &str = "IAU 2012 Resolution B2";
+const IAU_2015: &str = "IAU 2015 Resolution B2";
+const IAU_2015_NOMINAL: &str = "IAU 2015 Resolution B3";
+const IERS_2010: &str = "IERS Conventions 2010";
+const MASS_FROM_GM: &str = "GM / G with G from CODATA 2018";
+
+// Lengths and times
+
+/// # Astronomical unit (au :: m)
+pub const ASTRONOMICAL_UNIT: PhysicalConstant =
+    PhysicalConstant::exact("astronomical unit", "au", 149597870700f64, "m").with_source(IAU_2012);
+/// # Parsec (pc = 648000 / π au :: m)
+pub const PARSEC: PhysicalConstant =
+    PhysicalConstant::exact("parsec", "pc", 3.085677581491367e16, "m").with_source(IAU_2015);
+/// # Julian year (a = 365.25 d :: s)
+pub const JULIAN_YEAR: PhysicalConstant =
+    PhysicalConstant::exact("Julian year", "a", 31557600f64, "s").with_source("IAU 1976");
+/// # Light-year (ly = c · 1 a :: m)
+pub const LIGHT_YEAR: PhysicalConstant =
+    PhysicalConstant::exact("light-year", "ly", SPEED_OF_LIGHT * 31557600f64, "m")
+        .with_source("IAU 1976");
+/// # Standard acceleration of gravity (g_n :: m/s^2)
+/// The same constant as in [`super::codata`]
+pub use super::codata::STANDARD_ACCELERATION_OF_GRAVITY;
+
+// Sun
+
+/// # Nominal solar mass parameter (GM☉ :: m^3/s^2)
+pub const NOMINAL_SOLAR_GM: PhysicalConstant = PhysicalConstant::exact(
+    "nominal solar mass parameter",
+    "(GM)☉ᴺ",
+    1.3271244e20,
+    "m^3/s^2",
+)
+.with_source(IAU_2015_NOMINAL);
+/// # Solar mass parameter (GM☉ :: m^3/s^2), TDB-compatible
+pub const SOLAR_GM: PhysicalConstant = PhysicalConstant::measured(
+    "solar mass parameter",
+    "GM☉",
+    1.32712440041e20,
+    1e10,
+    "m^3/s^2",
+)
+.with_source(IERS_2010);
+/// # Solar mass (M☉ :: kg)
+pub const SOLAR_MASS: PhysicalConstant =
+    PhysicalConstant::measured("solar mass", "M☉", 1.98841e30, 4.5e25, "kg")
+        .with_source(MASS_FROM_GM);
+/// # Nominal solar radius (R☉ :: m)
+pub const NOMINAL_SOLAR_RADIUS: PhysicalConstant =
+    PhysicalConstant::exact("nominal solar radius", "R☉ᴺ", 6.957e8, "m")
+        .with_source(IAU_2015_NOMINAL);
+/// # Nominal solar luminosity (L☉ :: W)
+pub const NOMINAL_SOLAR_LUMINOSITY: PhysicalConstant =
+    PhysicalConstant::exact("nominal solar luminosity", "L☉ᴺ", 3.828e26, "W")
+        .with_source(IAU_2015_NOMINAL);
+
+// Earth
+
+/// # Nominal terrestrial mass parameter (GM🜨 :: m^3/s^2)
+pub const NOMINAL_EARTH_GM: PhysicalConstant = PhysicalConstant::exact(
+    "nominal terrestrial mass parameter",
+    "(GM)🜨ᴺ",
+    3.986004e14,
+    "m^3/s^2",
+)
+.with_source(IAU_2015_NOMINAL);
+/// # Geocentric gravitational constant (GM🜨 :: m^3/s^2), TCG-compatible
+/// The TT-compatible value is 3.986004415e14 m^3/s^2
+pub const EARTH_GM: PhysicalConstant = PhysicalConstant::measured(
+    "geocentric gravitational constant",
+    "GM🜨",
+    3.986004418e14,
+    8e5,
+    "m^3/s^2",
+)
+.with_source(IERS_2010);
+/// # Earth mass (M🜨 :: kg)
+pub const EARTH_MASS: PhysicalConstant =
+    PhysicalConstant::measured("Earth mass", "M🜨", 5.97217e24, 1.34e20, "kg")
+        .with_source(MASS_FROM_GM);
+/// # Nominal equatorial Earth radius (R🜨 :: m)
+pub const NOMINAL_EARTH_EQUATORIAL_RADIUS: PhysicalConstant =
+    PhysicalConstant::exact("nominal equatorial Earth radius", "R🜨ₑᴺ", 6.3781e6, "m")
+        .with_source(IAU_2015_NOMINAL);
+/// # Nominal polar Earth radius (R🜨 :: m)
+pub const NOMINAL_EARTH_POLAR_RADIUS: PhysicalConstant =
+    PhysicalConstant::exact("nominal polar Earth radius", "R🜨ₚᴺ", 6.3568e6, "m")
+        .with_source(IAU_2015_NOMINAL);
+
+// Jupiter
+
+/// # Nominal Jovian mass parameter (GM♃ :: m^3/s^2)
+pub const NOMINAL_JUPITER_GM: PhysicalConstant = PhysicalConstant::exact(
+    "nominal Jovian mass parameter",
+    "(GM)♃ᴺ",
+    1.2668653e17,
+    "m^3/s^2",
+)
+.with_source(IAU_2015_NOMINAL);
+/// # Jupiter mass (M♃ :: kg)
+pub const JUPITER_MASS: PhysicalConstant =
+    PhysicalConstant::measured("Jupiter mass", "M♃", 1.89812e27, 4.3e22, "kg")
+        .with_source(MASS_FROM_GM);
+/// # Nominal equatorial Jovian radius (R♃ :: m)
+pub const NOMINAL_JUPITER_EQUATORIAL_RADIUS: PhysicalConstant =
+    PhysicalConstant::exact("nominal equatorial Jovian radius", "R♃ₑᴺ", 7.1492e7, "m")
+        .with_source(IAU_2015_NOMINAL);
+/// # Nominal polar Jovian radius (R♃ :: m)
+pub const NOMINAL_JUPITER_POLAR_RADIUS: PhysicalConstant =
+    PhysicalConstant::exact("nominal polar Jovian radius", "R♃ₚᴺ", 6.6854e7, "m")
+        .with_source(IAU_2015_NOMINAL);
+
+/// All constants of this module, for tools that list them
+pub static CONSTANTS: [PhysicalConstant; 19] = [
+    ASTRONOMICAL_UNIT,
+    PARSEC,
+    JULIAN_YEAR,
+    LIGHT_YEAR,
+    STANDARD_ACCELERATION_OF_GRAVITY,
+    NOMINAL_SOLAR_GM,
+    SOLAR_GM,
+    SOLAR_MASS,
+    NOMINAL_SOLAR_RADIUS,
+    NOMINAL_SOLAR_LUMINOSITY,
+    NOMINAL_EARTH_GM,
+    EARTH_GM,
+    EARTH_MASS,
+    NOMINAL_EARTH_EQUATORIAL_RADIUS,
+    NOMINAL_EARTH_POLAR_RADIUS,
+    NOMINAL_JUPITER_GM,
+    JUPITER_MASS,
+    NOMINAL_JUPITER_EQUATORIAL_RADIUS,
+    NOMINAL_JUPITER_POLAR_RADIUS,
+];
+
+/// # Find
+/// Looks up a constant by its name (ignoring ASCII case, e.g. "parsec") or its symbol (exact, e.g. "M☉")
+pub fn find(name_or_symbol: &str) -> Option<&'static PhysicalConstant> {
+    find_in(&CONSTANTS, name_or_symbol)
+}
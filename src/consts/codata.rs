@@ -0,0 +1,420 @@
+//! # CODATA 2018
+//! The fundamental physical constants recommended by the Committee on Data for Science and Technology (CODATA)
+//! in 2018. Every constant carries its standard uncertainty and whether it is exact.
+//!
+//! ## Exact constants
+//! Since the redefinition of the SI in 2019, the units are defined by fixing the values of seven constants, among them
+//! c, h, e, k and N_A. Those constants, and every constant calculated only from them (like ħ or the
+//! Stefan–Boltzmann constant), are exact. Exact values that do not fit into an f64 are rounded to the nearest f64,
+//! their uncertainty is still 0.
+//!
+//! ## Measured constants
+//! All other constants, like G or the electron mass, are measured. `uncertainty` is the standard uncertainty
+//! (one standard deviation) in the unit of the value.
+//!
+//! ## Example
+//! ```rs
+//! let planck = codata::PLANCK_CONSTANT.value;
+//! let electron = codata::find("mₑ").unwrap();
+//! for constant in codata::CONSTANTS.iter().filter(|constant| !constant.exact) {
+//!     // constant.name, constant.value, constant.uncertainty, constant.unit
+//! }
+//! ```
+//!
+//! ## Read more
+//! <https://physics.nist.gov/cuu/Constants/>
+//! <https://en.wikipedia.org/wiki/2019_redefinition_of_the_SI_base_units>
+
+use super::{GRAVITATIONAL_CONSTANT, SPEED_OF_LIGHT, STANDARD_GRAVITY};
+use crate::uncertain::Uncertain;
+
+/// # Physical Constant
+/// A constant with its symbol, unit and standard uncertainty
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhysicalConstant {
+    /// The full name, e.g. "Planck constant"
+    pub name: &'static str,
+    /// The usual symbol, e.g. "h"
+    pub symbol: &'static str,
+    pub value: f64,
+    /// The standard uncertainty in the unit of the value, 0 for exact constants
+    pub uncertainty: f64,
+    /// The SI unit, e.g. "J·s"
+    pub unit: &'static str,
+    /// Whether the value is exact by definition
+    pub exact: bool,
+    /// Where the value comes from, e.g. "CODATA 2018"
+    pub source: &'static str,
+}
+
+impl PhysicalConstant {
+    pub(super) const fn exact(
+        name: &'static str,
+        symbol: &'static str,
+        value: f64,
+        unit: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            symbol,
+            value,
+            uncertainty: 0f64,
+            unit,
+            exact: true,
+            source: "CODATA 2018",
+        }
+    }
+
+    pub(super) const fn measured(
+        name: &'static str,
+        symbol: &'static str,
+        value: f64,
+        uncertainty: f64,
+        unit: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            symbol,
+            value,
+            uncertainty,
+            unit,
+            exact: false,
+            source: "CODATA 2018",
+        }
+    }
+
+    pub(super) const fn with_source(self, source: &'static str) -> Self {
+        Self { source, ..self }
+    }
+
+    /// # PhysicalConstant.relative_uncertainty
+    /// The standard uncertainty divided by the value
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty / self.value.abs()
+    }
+
+    /// # PhysicalConstant.uncertain
+    /// The value with its standard uncertainty, to propagate the uncertainty through a calculation
+    ///
+    /// ## Example
+    /// ```rs
+    /// let rest_energy = srest_energy(ELECTRON_MASS.uncertain()); // -> 8.1871057768e-14 ± 2.5e-23 J
+    /// ```
+    pub fn uncertain(&self) -> Uncertain {
+        Uncertain::new(self.value, self.uncertainty)
+    }
+}
+
+// Defining constants of the SI
+
+/// # Speed of light in vacuum (c :: m/s)
+pub const SPEED_OF_LIGHT_IN_VACUUM: PhysicalConstant =
+    PhysicalConstant::exact("speed of light in vacuum", "c", SPEED_OF_LIGHT, "m/s");
+/// # Planck constant (h :: J·s)
+pub const PLANCK_CONSTANT: PhysicalConstant =
+    PhysicalConstant::exact("Planck constant", "h", 6.62607015e-34, "J·s");
+/// # Elementary charge (e :: C)
+pub const ELEMENTARY_CHARGE: PhysicalConstant =
+    PhysicalConstant::exact("elementary charge", "e", 1.602176634e-19, "C");
+/// # Boltzmann constant (k :: J/K)
+pub const BOLTZMANN_CONSTANT: PhysicalConstant =
+    PhysicalConstant::exact("Boltzmann constant", "k", 1.380649e-23, "J/K");
+/// # Avogadro constant (N_A :: 1/mol)
+pub const AVOGADRO_CONSTANT: PhysicalConstant =
+    PhysicalConstant::exact("Avogadro constant", "N_A", 6.02214076e23, "1/mol");
+/// # Hyperfine transition frequency of Cs-133 (Δν_Cs :: Hz), which defines the second
+pub const CAESIUM_FREQUENCY: PhysicalConstant = PhysicalConstant::exact(
+    "hyperfine transition frequency of Cs-133",
+    "Δν_Cs",
+    9192631770f64,
+    "Hz",
+);
+/// # Luminous efficacy (K_cd :: lm/W) of light with 540 THz
+pub const LUMINOUS_EFFICACY: PhysicalConstant =
+    PhysicalConstant::exact("luminous efficacy", "K_cd", 683f64, "lm/W");
+
+// Exact constants derived from the defining constants
+
+/// # Reduced Planck constant (ħ = h / 2π :: J·s)
+pub const REDUCED_PLANCK_CONSTANT: PhysicalConstant = PhysicalConstant::exact(
+    "reduced Planck constant",
+    "ħ",
+    1.0545718176461565e-34,
+    "J·s",
+);
+/// # Molar gas constant (R = N_A k :: J/(mol·K))
+pub const MOLAR_GAS_CONSTANT: PhysicalConstant =
+    PhysicalConstant::exact("molar gas constant", "R", 8.31446261815324, "J/(mol·K)");
+/// # Faraday constant (F = N_A e :: C/mol)
+pub const FARADAY_CONSTANT: PhysicalConstant =
+    PhysicalConstant::exact("Faraday constant", "F", 96485.33212331001, "C/mol");
+/// # Stefan–Boltzmann constant (σ :: W/(m^2·K^4))
+pub const STEFAN_BOLTZMANN_CONSTANT: PhysicalConstant = PhysicalConstant::exact(
+    "Stefan–Boltzmann constant",
+    "σ",
+    5.6703744191844294e-8,
+    "W/(m^2·K^4)",
+);
+/// # Wien wavelength displacement law constant (b :: m·K)
+pub const WIEN_WAVELENGTH_DISPLACEMENT: PhysicalConstant = PhysicalConstant::exact(
+    "Wien wavelength displacement law constant",
+    "b",
+    2.8977719551851727e-3,
+    "m·K",
+);
+/// # Electron volt (eV :: J)
+pub const ELECTRON_VOLT: PhysicalConstant =
+    PhysicalConstant::exact("electron volt", "eV", 1.602176634e-19, "J");
+/// # Magnetic flux quantum (Φ₀ = h / 2e :: Wb)
+pub const MAGNETIC_FLUX_QUANTUM: PhysicalConstant =
+    PhysicalConstant::exact("magnetic flux quantum", "Φ₀", 2.0678338484619295e-15, "Wb");
+/// # Conductance quantum (G₀ = 2e^2 / h :: S)
+pub const CONDUCTANCE_QUANTUM: PhysicalConstant =
+    PhysicalConstant::exact("conductance quantum", "G₀", 7.74809172986365e-5, "S");
+/// # Von Klitzing constant (R_K = h / e^2 :: Ω)
+pub const VON_KLITZING_CONSTANT: PhysicalConstant =
+    PhysicalConstant::exact("von Klitzing constant", "R_K", 25812.807459304506, "Ω");
+/// # Josephson constant (K_J = 2e / h :: Hz/V)
+pub const JOSEPHSON_CONSTANT: PhysicalConstant =
+    PhysicalConstant::exact("Josephson constant", "K_J", 483597848416983.6, "Hz/V");
+
+// Conventional values
+
+/// # Standard acceleration of gravity (g_n :: m/s^2)
+pub const STANDARD_ACCELERATION_OF_GRAVITY: PhysicalConstant = PhysicalConstant::exact(
+    "standard acceleration of gravity",
+    "g_n",
+    STANDARD_GRAVITY,
+    "m/s^2",
+)
+.with_source("3rd CGPM 1901");
+/// # Standard atmosphere (atm :: Pa)
+pub const STANDARD_ATMOSPHERE: PhysicalConstant =
+    PhysicalConstant::exact("standard atmosphere", "atm", 101325f64, "Pa");
+
+// Measured constants
+
+/// # Newtonian constant of gravitation (G :: m^3/(kg·s^2))
+pub const NEWTONIAN_CONSTANT_OF_GRAVITATION: PhysicalConstant = PhysicalConstant::measured(
+    "Newtonian constant of gravitation",
+    "G",
+    GRAVITATIONAL_CONSTANT,
+    0.00015e-11,
+    "m^3/(kg·s^2)",
+);
+/// # Vacuum magnetic permeability (μ₀ :: N/A^2)
+pub const VACUUM_MAGNETIC_PERMEABILITY: PhysicalConstant = PhysicalConstant::measured(
+    "vacuum magnetic permeability",
+    "μ₀",
+    1.25663706212e-6,
+    0.00000000019e-6,
+    "N/A^2",
+);
+/// # Vacuum electric permittivity (ε₀ :: F/m)
+pub const VACUUM_ELECTRIC_PERMITTIVITY: PhysicalConstant = PhysicalConstant::measured(
+    "vacuum electric permittivity",
+    "ε₀",
+    8.8541878128e-12,
+    0.0000000013e-12,
+    "F/m",
+);
+/// # Characteristic impedance of vacuum (Z₀ :: Ω)
+pub const CHARACTERISTIC_IMPEDANCE_OF_VACUUM: PhysicalConstant = PhysicalConstant::measured(
+    "characteristic impedance of vacuum",
+    "Z₀",
+    376.730313668,
+    0.000000057,
+    "Ω",
+);
+/// # Fine-structure constant (α :: 1)
+pub const FINE_STRUCTURE_CONSTANT: PhysicalConstant = PhysicalConstant::measured(
+    "fine-structure constant",
+    "α",
+    7.2973525693e-3,
+    0.0000000011e-3,
+    "1",
+);
+/// # Inverse fine-structure constant (1/α :: 1)
+pub const INVERSE_FINE_STRUCTURE_CONSTANT: PhysicalConstant = PhysicalConstant::measured(
+    "inverse fine-structure constant",
+    "α⁻¹",
+    137.035999084,
+    0.000000021,
+    "1",
+);
+/// # Electron mass (mₑ :: kg)
+pub const ELECTRON_MASS: PhysicalConstant = PhysicalConstant::measured(
+    "electron mass",
+    "mₑ",
+    9.1093837015e-31,
+    0.0000000028e-31,
+    "kg",
+);
+/// # Muon mass (m_μ :: kg)
+pub const MUON_MASS: PhysicalConstant =
+    PhysicalConstant::measured("muon mass", "m_μ", 1.883531627e-28, 0.000000042e-28, "kg");
+/// # Proton mass (mₚ :: kg)
+pub const PROTON_MASS: PhysicalConstant = PhysicalConstant::measured(
+    "proton mass",
+    "mₚ",
+    1.67262192369e-27,
+    0.00000000051e-27,
+    "kg",
+);
+/// # Neutron mass (mₙ :: kg)
+pub const NEUTRON_MASS: PhysicalConstant = PhysicalConstant::measured(
+    "neutron mass",
+    "mₙ",
+    1.67492749804e-27,
+    0.00000000095e-27,
+    "kg",
+);
+/// # Atomic mass constant (m_u = m(¹²C) / 12 :: kg)
+pub const ATOMIC_MASS_CONSTANT: PhysicalConstant = PhysicalConstant::measured(
+    "atomic mass constant",
+    "m_u",
+    1.66053906660e-27,
+    0.00000000050e-27,
+    "kg",
+);
+/// # Proton-electron mass ratio (mₚ/mₑ :: 1)
+pub const PROTON_ELECTRON_MASS_RATIO: PhysicalConstant = PhysicalConstant::measured(
+    "proton-electron mass ratio",
+    "mₚ/mₑ",
+    1836.15267343,
+    0.00000011,
+    "1",
+);
+/// # Rydberg constant (R∞ :: 1/m)
+pub const RYDBERG_CONSTANT: PhysicalConstant =
+    PhysicalConstant::measured("Rydberg constant", "R∞", 10973731.568160, 0.000021, "1/m");
+/// # Bohr radius (a₀ :: m)
+pub const BOHR_RADIUS: PhysicalConstant = PhysicalConstant::measured(
+    "Bohr radius",
+    "a₀",
+    5.29177210903e-11,
+    0.00000000080e-11,
+    "m",
+);
+/// # Classical electron radius (rₑ :: m)
+pub const CLASSICAL_ELECTRON_RADIUS: PhysicalConstant = PhysicalConstant::measured(
+    "classical electron radius",
+    "rₑ",
+    2.8179403262e-15,
+    0.0000000013e-15,
+    "m",
+);
+/// # Compton wavelength of the electron (λ_C :: m)
+pub const COMPTON_WAVELENGTH: PhysicalConstant = PhysicalConstant::measured(
+    "Compton wavelength",
+    "λ_C",
+    2.42631023867e-12,
+    0.00000000073e-12,
+    "m",
+);
+/// # Hartree energy (E_h :: J)
+pub const HARTREE_ENERGY: PhysicalConstant = PhysicalConstant::measured(
+    "Hartree energy",
+    "E_h",
+    4.3597447222071e-18,
+    0.0000000000085e-18,
+    "J",
+);
+/// # Bohr magneton (μ_B :: J/T)
+pub const BOHR_MAGNETON: PhysicalConstant = PhysicalConstant::measured(
+    "Bohr magneton",
+    "μ_B",
+    9.2740100783e-24,
+    0.0000000028e-24,
+    "J/T",
+);
+/// # Nuclear magneton (μ_N :: J/T)
+pub const NUCLEAR_MAGNETON: PhysicalConstant = PhysicalConstant::measured(
+    "nuclear magneton",
+    "μ_N",
+    5.0507837461e-27,
+    0.0000000015e-27,
+    "J/T",
+);
+/// # Electron g-factor (gₑ :: 1)
+pub const ELECTRON_G_FACTOR: PhysicalConstant = PhysicalConstant::measured(
+    "electron g factor",
+    "gₑ",
+    -2.00231930436256,
+    0.00000000000035,
+    "1",
+);
+/// # Planck mass (m_P :: kg)
+pub const PLANCK_MASS: PhysicalConstant =
+    PhysicalConstant::measured("Planck mass", "m_P", 2.176434e-8, 0.000024e-8, "kg");
+/// # Planck length (l_P :: m)
+pub const PLANCK_LENGTH: PhysicalConstant =
+    PhysicalConstant::measured("Planck length", "l_P", 1.616255e-35, 0.000018e-35, "m");
+/// # Planck time (t_P :: s)
+pub const PLANCK_TIME: PhysicalConstant =
+    PhysicalConstant::measured("Planck time", "t_P", 5.391247e-44, 0.000060e-44, "s");
+/// # Planck temperature (T_P :: K)
+pub const PLANCK_TEMPERATURE: PhysicalConstant =
+    PhysicalConstant::measured("Planck temperature", "T_P", 1.416784e32, 0.000016e32, "K");
+
+/// All constants of this module, for tools that list them
+pub static CONSTANTS: [PhysicalConstant; 43] = [
+    SPEED_OF_LIGHT_IN_VACUUM,
+    PLANCK_CONSTANT,
+    ELEMENTARY_CHARGE,
+    BOLTZMANN_CONSTANT,
+    AVOGADRO_CONSTANT,
+    CAESIUM_FREQUENCY,
+    LUMINOUS_EFFICACY,
+    REDUCED_PLANCK_CONSTANT,
+    MOLAR_GAS_CONSTANT,
+    FARADAY_CONSTANT,
+    STEFAN_BOLTZMANN_CONSTANT,
+    WIEN_WAVELENGTH_DISPLACEMENT,
+    ELECTRON_VOLT,
+    MAGNETIC_FLUX_QUANTUM,
+    CONDUCTANCE_QUANTUM,
+    VON_KLITZING_CONSTANT,
+    JOSEPHSON_CONSTANT,
+    STANDARD_ACCELERATION_OF_GRAVITY,
+    STANDARD_ATMOSPHERE,
+    NEWTONIAN_CONSTANT_OF_GRAVITATION,
+    VACUUM_MAGNETIC_PERMEABILITY,
+    VACUUM_ELECTRIC_PERMITTIVITY,
+    CHARACTERISTIC_IMPEDANCE_OF_VACUUM,
+    FINE_STRUCTURE_CONSTANT,
+    INVERSE_FINE_STRUCTURE_CONSTANT,
+    ELECTRON_MASS,
+    MUON_MASS,
+    PROTON_MASS,
+    NEUTRON_MASS,
+    ATOMIC_MASS_CONSTANT,
+    PROTON_ELECTRON_MASS_RATIO,
+    RYDBERG_CONSTANT,
+    BOHR_RADIUS,
+    CLASSICAL_ELECTRON_RADIUS,
+    COMPTON_WAVELENGTH,
+    HARTREE_ENERGY,
+    BOHR_MAGNETON,
+    NUCLEAR_MAGNETON,
+    ELECTRON_G_FACTOR,
+    PLANCK_MASS,
+    PLANCK_LENGTH,
+    PLANCK_TIME,
+    PLANCK_TEMPERATURE,
+];
+
+/// # Find
+/// Looks up a constant by its name (ignoring ASCII case, e.g. "planck constant") or its symbol (exact, e.g. "ħ")
+pub fn find(name_or_symbol: &str) -> Option<&'static PhysicalConstant> {
+    find_in(&CONSTANTS, name_or_symbol)
+}
+
+pub(super) fn find_in(
+    constants: &'static [PhysicalConstant],
+    name_or_symbol: &str,
+) -> Option<&'static PhysicalConstant> {
+    constants.iter().find(|constant| {
+        constant.symbol == name_or_symbol || constant.name.eq_ignore_ascii_case(name_or_symbol)
+    })
+}
@@ -6,6 +6,13 @@
 //! If you are interested in what values truly are / some fun facts about them,
 //! visit the pages of the value. **Some** values have docs attached to them. Those values only get
 //! Documentations if they are often used. Other constants just exist for completions sake.
+//!
+//! ## Uncertainties
+//! The constants in this file are plain f64 values. The [`codata`] module has the full CODATA 2018 set, each with
+//! its symbol, unit and standard uncertainty. The [`astro`] module has the astronomical constants of the IAU.
+
+pub mod astro;
+pub mod codata;
 
 /// # Speed Of Light in a vacuum
 /// Speed of Photons **in a vacuum**
@@ -68,3 +75,13 @@ pub const SPEED_OF_LIGHT_SQUARED: f64 = 89875517873681760f64;
 /// In Programming, Infinity is **not** the maximum of a number. It is something defined within the compiler.
 /// In the Standard Library it is just defined as 1 / 0.
 pub const INFINITY: f64 = f64::INFINITY;
+
+/// # Standard Gravity
+/// The standard acceleration due to gravity on the surface of the earth (g :: m/s^2).
+/// The real value depends on the location, this is the conventional value defined by the CGPM in 1901.
+pub const STANDARD_GRAVITY: f64 = 9.80665f64;
+
+/// # Gravitational Constant
+/// The Newtonian constant of gravitation (G :: m^3/(kg·s^2)), CODATA 2018.
+/// It is only known to about 5 significant digits, so prefer GM of the body if it is known.
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.67430e-11f64;
@@ -0,0 +1,423 @@
+//! # Dual Numbers
+//! Forward-mode automatic differentiation. A dual number a + b·ε with ε^2 = 0 carries a value and its derivative
+//! through a calculation:
+//!
+//! f(a + b·ε) = f(a) + f'(a)·b·ε
+//!
+//! [`Dual`] implements [`Real`], so every generic function of this crate (the formulas of
+//! [`crate::relativity`] and [`crate::classical`], the vectors, ...) can be evaluated with it. The result holds the
+//! exact derivative, without the truncation error of finite differences.
+//!
+//! ## Gradients
+//! `Dual<N>` stores N derivatives, one for each input variable. Mark every input with [`Dual::variable`] and its
+//! index, and the result holds the full gradient after a single evaluation:
+//!
+//! ```rs
+//! let speed = Dual::<2>::variable(0.6 * SPEED_OF_LIGHT, 0);
+//! let mass = Dual::<2>::variable(1f64, 1);
+//! let energy = skinetic_energy(speed, mass); // energy.gradient = [∂E/∂v, ∂E/∂m]
+//! ```
+//!
+//! [`sderivative`] and [`sgradient`] do the marking for you.
+//!
+//! ## Comparisons
+//! Dual numbers are compared by their value only, the derivatives are ignored. Branches such as `if mass < 0`
+//! therefore behave exactly as for f64.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Automatic_differentiation>
+//! <https://en.wikipedia.org/wiki/Dual_number>
+
+use core::cmp::Ordering;
+use core::f64::consts::{LN_10, LN_2};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::prelude::*;
+
+/// # Dual
+/// A value and its derivatives with respect to N variables
+#[derive(Clone, Copy, Debug)]
+pub struct Dual<const N: usize = 1> {
+    pub value: f64,
+    pub gradient: [f64; N],
+}
+
+impl<const N: usize> Dual<N> {
+    /// # Dual.new
+    /// Creates a dual number from its value and derivatives
+    pub fn new(value: f64, gradient: [f64; N]) -> Self {
+        Self { value, gradient }
+    }
+
+    /// # Dual.constant
+    /// A constant, all derivatives are 0
+    pub fn constant(value: f64) -> Self {
+        Self::new(value, [0f64; N])
+    }
+
+    /// # Dual.variable
+    /// The input variable with the given index: its derivative with respect to itself is 1, all others are 0
+    ///
+    /// ## Example
+    /// ```rs
+    /// let x = Dual::<1>::variable(3f64, 0);
+    /// let y = x * x; // -> value 9, gradient [6]
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if `index` is not smaller than N
+    pub fn variable(value: f64, index: usize) -> Self {
+        let mut gradient = [0f64; N];
+        gradient[index] = 1f64;
+        Self::new(value, gradient)
+    }
+
+    /// # Dual.derivative
+    /// The derivative with respect to the variable with the given index
+    ///
+    /// ## Panics
+    /// Panics if `index` is not smaller than N
+    pub fn derivative(self, index: usize) -> f64 {
+        self.gradient[index]
+    }
+
+    /// The chain rule: f(self) has the given value and f'(self) = `derivative`
+    #[inline]
+    fn chain(self, value: f64, derivative: f64) -> Self {
+        Self::new(value, self.gradient.map(|d| d * derivative))
+    }
+
+    /// Combines the derivatives of two dual numbers as a·∂self + b·∂other
+    #[inline]
+    fn combine(self, a: f64, other: Self, b: f64, value: f64) -> Self {
+        Self::new(
+            value,
+            core::array::from_fn(|i| a * self.gradient[i] + b * other.gradient[i]),
+        )
+    }
+}
+
+/// # Simple Derivative
+/// Evaluates `f` and its derivative at `x`
+///
+/// ## Variables
+/// ### Parameters
+/// - f = The function, evaluated with dual numbers
+/// - x = The point
+/// ### Returns
+/// - (f(x), f'(x))
+///
+/// ## Examples
+/// ```rs
+/// let (lorentz, slope) = sderivative(slorentz, 0.6 * SPEED_OF_LIGHT); // -> (1.25, γ^3 v / c^2)
+/// ```
+///
+/// ## Related Functions
+/// `sgradient` => All partial derivatives of a function of several variables
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Automatic_differentiation#Forward_accumulation>
+pub fn sderivative(f: impl Fn(Dual) -> Dual, x: f64) -> (f64, f64) {
+    let result = f(Dual::variable(x, 0));
+    (result.value, result.gradient[0])
+}
+
+/// # Simple Gradient
+/// Evaluates `f` and all its partial derivatives at `point`
+///
+/// ## Variables
+/// ### Parameters
+/// - f = The function of N variables, evaluated with dual numbers
+/// - point = The values of the variables
+/// ### Returns
+/// - (f(point), ∇f(point))
+///
+/// ## Examples
+/// ```rs
+/// let (energy, [by_speed, by_mass]) = sgradient(|[v, m]| skinetic_energy(v, m), [1e8, 2f64]);
+/// ```
+///
+/// ## Related Functions
+/// `sderivative` => The derivative of a function of one variable
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gradient>
+pub fn sgradient<const N: usize>(
+    f: impl Fn([Dual<N>; N]) -> Dual<N>,
+    point: [f64; N],
+) -> (f64, [f64; N]) {
+    let result = f(core::array::from_fn(|i| Dual::variable(point[i], i)));
+    (result.value, result.gradient)
+}
+
+impl<const N: usize> PartialEq for Dual<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const N: usize> PartialOrd for Dual<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.combine(1f64, rhs, 1f64, self.value + rhs.value)
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.combine(1f64, rhs, -1f64, self.value - rhs.value)
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.combine(rhs.value, rhs, self.value, self.value * rhs.value)
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        self.combine(1f64 / rhs.value, rhs, -value / rhs.value, value)
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.chain(-self.value, -1f64)
+    }
+}
+
+impl<const N: usize> Add<f64> for Dual<N> {
+    type Output = Self;
+    fn add(self, rhs: f64) -> Self {
+        Self::new(self.value + rhs, self.gradient)
+    }
+}
+
+impl<const N: usize> Sub<f64> for Dual<N> {
+    type Output = Self;
+    fn sub(self, rhs: f64) -> Self {
+        Self::new(self.value - rhs, self.gradient)
+    }
+}
+
+impl<const N: usize> Mul<f64> for Dual<N> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        self.chain(self.value * rhs, rhs)
+    }
+}
+
+impl<const N: usize> Div<f64> for Dual<N> {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        self.chain(self.value / rhs, 1f64 / rhs)
+    }
+}
+
+impl<const N: usize> From<f64> for Dual<N> {
+    fn from(value: f64) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<const N: usize> NumericalExtensions for Dual<N> {
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::constant(1f64);
+        }
+        self.chain(self.value.powi(n), f64::from(n) * self.value.powi(n - 1))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let value = self.value.powf(n.value);
+        let by_base = n.value * self.value.powf(n.value - 1f64);
+        // ln(self) is NaN for a negative base, which only matters if the exponent is not a constant
+        let by_exponent = if n.gradient.iter().all(|&d| d == 0f64) {
+            0f64
+        } else {
+            value * self.value.ln()
+        };
+        self.combine(by_base, n, by_exponent, value)
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.chain(root, 0.5f64 / root)
+    }
+
+    fn cbrt(self) -> Self {
+        let root = self.value.cbrt();
+        self.chain(root, 1f64 / (3f64 * root * root))
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    fn exp_m1(self) -> Self {
+        self.chain(self.value.exp_m1(), self.value.exp())
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), 1f64 / self.value)
+    }
+
+    fn ln_1p(self) -> Self {
+        self.chain(self.value.ln_1p(), 1f64 / (1f64 + self.value))
+    }
+
+    fn log10(self) -> Self {
+        self.chain(self.value.log10(), 1f64 / (self.value * LN_10))
+    }
+
+    fn log2(self) -> Self {
+        self.chain(self.value.log2(), 1f64 / (self.value * LN_2))
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.value.tan();
+        self.chain(tan, 1f64 + tan * tan)
+    }
+
+    fn asin(self) -> Self {
+        self.chain(
+            self.value.asin(),
+            1f64 / ((1f64 - self.value) * (1f64 + self.value)).sqrt(),
+        )
+    }
+
+    fn acos(self) -> Self {
+        self.chain(
+            self.value.acos(),
+            -1f64 / ((1f64 - self.value) * (1f64 + self.value)).sqrt(),
+        )
+    }
+
+    fn atan(self) -> Self {
+        self.chain(self.value.atan(), 1f64 / (1f64 + self.value * self.value))
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let squared = self.value * self.value + other.value * other.value;
+        self.combine(
+            other.value / squared,
+            other,
+            -self.value / squared,
+            self.value.atan2(other.value),
+        )
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+
+    fn tanh(self) -> Self {
+        let tanh = self.value.tanh();
+        self.chain(tanh, (1f64 - tanh) * (1f64 + tanh))
+    }
+
+    fn asinh(self) -> Self {
+        self.chain(self.value.asinh(), 1f64 / self.value.hypot(1f64))
+    }
+
+    fn acosh(self) -> Self {
+        self.chain(
+            self.value.acosh(),
+            1f64 / ((self.value - 1f64) * (self.value + 1f64)).sqrt(),
+        )
+    }
+
+    fn atanh(self) -> Self {
+        self.chain(
+            self.value.atanh(),
+            1f64 / ((1f64 - self.value) * (1f64 + self.value)),
+        )
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let hypot = self.value.hypot(other.value);
+        self.combine(self.value / hypot, other, other.value / hypot, hypot)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        let value = self.value.mul_add(a.value, b.value);
+        let product = self.combine(a.value, a, self.value, value);
+        product.combine(1f64, b, 1f64, value)
+    }
+}
+
+impl<const N: usize> Real for Dual<N> {
+    const ZERO: Self = Self {
+        value: 0f64,
+        gradient: [0f64; N],
+    };
+    const ONE: Self = Self {
+        value: 1f64,
+        gradient: [0f64; N],
+    };
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        Self::constant(value)
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.value
+    }
+
+    fn abs(self) -> Self {
+        if self.value < 0f64 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other.value < self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.value > self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// True if the value is finite, the derivatives are not checked
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.value.is_finite()
+    }
+}
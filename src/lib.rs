@@ -1,8 +1,20 @@
 #![doc = include_str!("../README.md")]
-#![feature(core_intrinsics)]
 #![no_std]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("mathonomy needs a math backend, enable either the `std` or the `libm` feature");
+
+mod backend;
+pub mod classical;
 pub mod consts;
+pub mod dual;
+pub mod matrices;
+pub mod prefixes;
 pub mod prelude;
+pub mod quantities;
+pub mod quaternions;
 pub mod relativity;
+pub mod tensors;
+pub mod uncertain;
+pub mod units;
 pub mod vectors;
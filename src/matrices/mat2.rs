@@ -0,0 +1,132 @@
+use crate::vectors::{vec2::Vec2, vecn::VecN, CrossProduct};
+
+use super::GenericMatrix;
+#[cfg_attr(any(feature = "std", test), allow(unused_imports))]
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat2 {
+    pub rows: [Vec2; 2],
+}
+
+impl Mat2 {
+    /// # Mat2.new
+    /// Creates a matrix from its elements, row by row
+    ///
+    /// ## Example
+    /// ```rs
+    /// let mat = Mat2::new(
+    ///     1, 2, // First row
+    ///     3, 4, // Second row
+    /// );
+    /// ```
+    pub fn new(m00: f64, m01: f64, m10: f64, m11: f64) -> Self {
+        Self::from_rows(Vec2::new(m00, m01), Vec2::new(m10, m11))
+    }
+
+    /// # Mat2.from_rows
+    /// Creates a matrix from its row vectors
+    pub fn from_rows(r0: Vec2, r1: Vec2) -> Self {
+        Self { rows: [r0, r1] }
+    }
+
+    /// # Mat2.from_cols
+    /// Creates a matrix from its column vectors
+    pub fn from_cols(c0: Vec2, c1: Vec2) -> Self {
+        Self::from_rows(c0, c1).transpose()
+    }
+
+    /// # Mat2.from_diagonal
+    /// Creates a matrix with the given diagonal, all other elements are 0
+    pub fn from_diagonal(diagonal: Vec2) -> Self {
+        Self::new(diagonal.x, 0f64, 0f64, diagonal.y)
+    }
+
+    /// # Mat2.rotation
+    /// A counter-clockwise rotation by `angle` (in radians)
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        Self::new(cos, -sin, sin, cos)
+    }
+}
+
+impl GenericMatrix for Mat2 {
+    type Vector = Vec2;
+
+    fn identity() -> Self {
+        Self::from_diagonal(Vec2::one())
+    }
+
+    fn zero() -> Self {
+        Self::from_rows(Vec2::zero(), Vec2::zero())
+    }
+
+    fn row(self, index: usize) -> Vec2 {
+        self.rows[index]
+    }
+
+    fn col(self, index: usize) -> Vec2 {
+        self.transpose().rows[index]
+    }
+
+    fn transpose(self) -> Self {
+        let [r0, r1] = self.rows;
+        Self::new(r0.x, r1.x, r0.y, r1.y)
+    }
+
+    fn determinant(self) -> f64 {
+        let [r0, r1] = self.rows;
+        r0.cross(r1)
+    }
+
+    fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0f64 || !det.is_finite() {
+            return None;
+        }
+
+        let [r0, r1] = self.rows;
+        Some(Self::new(r1.y, -r0.y, -r1.x, r0.x).div_f64(det))
+    }
+
+    fn trace(self) -> f64 {
+        self.rows[0].x + self.rows[1].y
+    }
+
+    fn mmul(self, other: Self) -> Self {
+        let other = other.transpose();
+        Self {
+            rows: self.rows.map(|row| other.vmul(row)),
+        }
+    }
+
+    fn vmul(self, vector: Vec2) -> Vec2 {
+        VecN::new(self.rows.map(|row| row | vector)).into()
+    }
+
+    fn madd(self, other: Self) -> Self {
+        Self {
+            rows: core::array::from_fn(|i| self.rows[i] + other.rows[i]),
+        }
+    }
+
+    fn msub(self, other: Self) -> Self {
+        Self {
+            rows: core::array::from_fn(|i| self.rows[i] - other.rows[i]),
+        }
+    }
+
+    fn mul_f64(self, by: f64) -> Self {
+        Self {
+            rows: self.rows.map(|row| row * by),
+        }
+    }
+
+    fn div_f64(self, by: f64) -> Self {
+        Self {
+            rows: self.rows.map(|row| row / by),
+        }
+    }
+}
+
+crate::impl_common_ops_for_mat!(Mat2, Vec2);
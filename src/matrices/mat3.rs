@@ -0,0 +1,140 @@
+use crate::vectors::{vec3::Vec3, vecn::VecN, CrossProduct};
+
+use super::GenericMatrix;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3 {
+    pub rows: [Vec3; 3],
+}
+
+impl Mat3 {
+    /// # Mat3.new
+    /// Creates a matrix from its elements, row by row
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        m00: f64,
+        m01: f64,
+        m02: f64,
+        m10: f64,
+        m11: f64,
+        m12: f64,
+        m20: f64,
+        m21: f64,
+        m22: f64,
+    ) -> Self {
+        Self::from_rows(
+            Vec3::new(m00, m01, m02),
+            Vec3::new(m10, m11, m12),
+            Vec3::new(m20, m21, m22),
+        )
+    }
+
+    /// # Mat3.from_rows
+    /// Creates a matrix from its row vectors
+    pub fn from_rows(r0: Vec3, r1: Vec3, r2: Vec3) -> Self {
+        Self { rows: [r0, r1, r2] }
+    }
+
+    /// # Mat3.from_cols
+    /// Creates a matrix from its column vectors
+    pub fn from_cols(c0: Vec3, c1: Vec3, c2: Vec3) -> Self {
+        Self::from_rows(c0, c1, c2).transpose()
+    }
+
+    /// # Mat3.from_diagonal
+    /// Creates a matrix with the given diagonal, all other elements are 0
+    pub fn from_diagonal(diagonal: Vec3) -> Self {
+        Self::new(
+            diagonal.x, 0f64, 0f64, //
+            0f64, diagonal.y, 0f64, //
+            0f64, 0f64, diagonal.z,
+        )
+    }
+}
+
+impl GenericMatrix for Mat3 {
+    type Vector = Vec3;
+
+    fn identity() -> Self {
+        Self::from_diagonal(Vec3::one())
+    }
+
+    fn zero() -> Self {
+        Self::from_rows(Vec3::zero(), Vec3::zero(), Vec3::zero())
+    }
+
+    fn row(self, index: usize) -> Vec3 {
+        self.rows[index]
+    }
+
+    fn col(self, index: usize) -> Vec3 {
+        self.transpose().rows[index]
+    }
+
+    fn transpose(self) -> Self {
+        let [r0, r1, r2] = self.rows;
+        Self::new(
+            r0.x, r1.x, r2.x, //
+            r0.y, r1.y, r2.y, //
+            r0.z, r1.z, r2.z,
+        )
+    }
+
+    /// The scalar triple product of the rows
+    fn determinant(self) -> f64 {
+        let [r0, r1, r2] = self.rows;
+        r0 | (r1 ^ r2)
+    }
+
+    /// The columns of the inverse are the cross products of the rows, divided by the determinant
+    fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0f64 || !det.is_finite() {
+            return None;
+        }
+
+        let [r0, r1, r2] = self.rows;
+        Some(Self::from_cols(r1.cross(r2), r2.cross(r0), r0.cross(r1)).div_f64(det))
+    }
+
+    fn trace(self) -> f64 {
+        self.rows[0].x + self.rows[1].y + self.rows[2].z
+    }
+
+    fn mmul(self, other: Self) -> Self {
+        let other = other.transpose();
+        Self {
+            rows: self.rows.map(|row| other.vmul(row)),
+        }
+    }
+
+    fn vmul(self, vector: Vec3) -> Vec3 {
+        VecN::new(self.rows.map(|row| row | vector)).into()
+    }
+
+    fn madd(self, other: Self) -> Self {
+        Self {
+            rows: core::array::from_fn(|i| self.rows[i] + other.rows[i]),
+        }
+    }
+
+    fn msub(self, other: Self) -> Self {
+        Self {
+            rows: core::array::from_fn(|i| self.rows[i] - other.rows[i]),
+        }
+    }
+
+    fn mul_f64(self, by: f64) -> Self {
+        Self {
+            rows: self.rows.map(|row| row * by),
+        }
+    }
+
+    fn div_f64(self, by: f64) -> Self {
+        Self {
+            rows: self.rows.map(|row| row / by),
+        }
+    }
+}
+
+crate::impl_common_ops_for_mat!(Mat3, Vec3);
@@ -0,0 +1,242 @@
+use crate::vectors::{vec3::Vec3, vec4::Vec4, vecn::VecN};
+
+use super::{mat3::Mat3, GenericMatrix};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4 {
+    pub rows: [Vec4; 4],
+}
+
+impl Mat4 {
+    /// # Mat4.new
+    /// Creates a matrix from its elements, row by row
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        m00: f64,
+        m01: f64,
+        m02: f64,
+        m03: f64,
+        m10: f64,
+        m11: f64,
+        m12: f64,
+        m13: f64,
+        m20: f64,
+        m21: f64,
+        m22: f64,
+        m23: f64,
+        m30: f64,
+        m31: f64,
+        m32: f64,
+        m33: f64,
+    ) -> Self {
+        Self::from_rows(
+            Vec4::new(m00, m01, m02, m03),
+            Vec4::new(m10, m11, m12, m13),
+            Vec4::new(m20, m21, m22, m23),
+            Vec4::new(m30, m31, m32, m33),
+        )
+    }
+
+    /// # Mat4.from_rows
+    /// Creates a matrix from its row vectors
+    pub fn from_rows(r0: Vec4, r1: Vec4, r2: Vec4, r3: Vec4) -> Self {
+        Self {
+            rows: [r0, r1, r2, r3],
+        }
+    }
+
+    /// # Mat4.from_cols
+    /// Creates a matrix from its column vectors
+    pub fn from_cols(c0: Vec4, c1: Vec4, c2: Vec4, c3: Vec4) -> Self {
+        Self::from_rows(c0, c1, c2, c3).transpose()
+    }
+
+    /// # Mat4.from_diagonal
+    /// Creates a matrix with the given diagonal, all other elements are 0
+    pub fn from_diagonal(diagonal: Vec4) -> Self {
+        Self::new(
+            diagonal.x, 0f64, 0f64, 0f64, //
+            0f64, diagonal.y, 0f64, 0f64, //
+            0f64, 0f64, diagonal.z, 0f64, //
+            0f64, 0f64, 0f64, diagonal.w,
+        )
+    }
+
+    /// # Mat4.from_mat3
+    /// Embeds a 3x3 transformation (e.g. a rotation) into a homogeneous 4x4 transformation
+    pub fn from_mat3(mat: Mat3) -> Self {
+        let [r0, r1, r2] = mat.rows;
+        Self::from_rows(
+            Vec4::from_direction(r0),
+            Vec4::from_direction(r1),
+            Vec4::from_direction(r2),
+            Vec4::waxis(),
+        )
+    }
+
+    /// # Mat4.translation
+    /// A homogeneous transformation that moves points by `offset`. Directions are not affected
+    pub fn translation(offset: Vec3) -> Self {
+        let mut mat = Self::identity();
+        mat.rows[0].w = offset.x;
+        mat.rows[1].w = offset.y;
+        mat.rows[2].w = offset.z;
+        mat
+    }
+
+    /// # Mat4.scaling
+    /// A homogeneous transformation that scales each axis by the matching component of `factors`
+    pub fn scaling(factors: Vec3) -> Self {
+        Self::from_diagonal(Vec4::from_point(factors))
+    }
+
+    /// # Mat4.transform_point
+    /// Transforms a point (w = 1), including the perspective divide
+    pub fn transform_point(self, point: Vec3) -> Vec3 {
+        self.vmul(Vec4::from_point(point)).perspective_divide()
+    }
+
+    /// # Mat4.transform_direction
+    /// Transforms a direction (w = 0), translations are ignored
+    pub fn transform_direction(self, direction: Vec3) -> Vec3 {
+        self.vmul(Vec4::from_direction(direction)).xyz()
+    }
+}
+
+impl GenericMatrix for Mat4 {
+    type Vector = Vec4;
+
+    fn identity() -> Self {
+        Self::from_diagonal(Vec4::one())
+    }
+
+    fn zero() -> Self {
+        Self::from_rows(Vec4::zero(), Vec4::zero(), Vec4::zero(), Vec4::zero())
+    }
+
+    fn row(self, index: usize) -> Vec4 {
+        self.rows[index]
+    }
+
+    fn col(self, index: usize) -> Vec4 {
+        self.transpose().rows[index]
+    }
+
+    fn transpose(self) -> Self {
+        let [r0, r1, r2, r3] = self.rows;
+        Self::new(
+            r0.x, r1.x, r2.x, r3.x, //
+            r0.y, r1.y, r2.y, r3.y, //
+            r0.z, r1.z, r2.z, r3.z, //
+            r0.w, r1.w, r2.w, r3.w,
+        )
+    }
+
+    /// Laplace expansion using the 2x2 minors of the upper and lower two rows
+    fn determinant(self) -> f64 {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0f64 || !det.is_finite() {
+            return None;
+        }
+
+        let (s, c) = self.minors();
+        let [a0, a1, a2, a3] = self.rows;
+
+        let inverse = Self::new(
+            a1.y * c[5] - a1.z * c[4] + a1.w * c[3],
+            -a0.y * c[5] + a0.z * c[4] - a0.w * c[3],
+            a3.y * s[5] - a3.z * s[4] + a3.w * s[3],
+            -a2.y * s[5] + a2.z * s[4] - a2.w * s[3],
+            //
+            -a1.x * c[5] + a1.z * c[2] - a1.w * c[1],
+            a0.x * c[5] - a0.z * c[2] + a0.w * c[1],
+            -a3.x * s[5] + a3.z * s[2] - a3.w * s[1],
+            a2.x * s[5] - a2.z * s[2] + a2.w * s[1],
+            //
+            a1.x * c[4] - a1.y * c[2] + a1.w * c[0],
+            -a0.x * c[4] + a0.y * c[2] - a0.w * c[0],
+            a3.x * s[4] - a3.y * s[2] + a3.w * s[0],
+            -a2.x * s[4] + a2.y * s[2] - a2.w * s[0],
+            //
+            -a1.x * c[3] + a1.y * c[1] - a1.z * c[0],
+            a0.x * c[3] - a0.y * c[1] + a0.z * c[0],
+            -a3.x * s[3] + a3.y * s[1] - a3.z * s[0],
+            a2.x * s[3] - a2.y * s[1] + a2.z * s[0],
+        );
+
+        Some(inverse.div_f64(det))
+    }
+
+    fn trace(self) -> f64 {
+        self.rows[0].x + self.rows[1].y + self.rows[2].z + self.rows[3].w
+    }
+
+    fn mmul(self, other: Self) -> Self {
+        let other = other.transpose();
+        Self {
+            rows: self.rows.map(|row| other.vmul(row)),
+        }
+    }
+
+    fn vmul(self, vector: Vec4) -> Vec4 {
+        VecN::new(self.rows.map(|row| row | vector)).into()
+    }
+
+    fn madd(self, other: Self) -> Self {
+        Self {
+            rows: core::array::from_fn(|i| self.rows[i] + other.rows[i]),
+        }
+    }
+
+    fn msub(self, other: Self) -> Self {
+        Self {
+            rows: core::array::from_fn(|i| self.rows[i] - other.rows[i]),
+        }
+    }
+
+    fn mul_f64(self, by: f64) -> Self {
+        Self {
+            rows: self.rows.map(|row| row * by),
+        }
+    }
+
+    fn div_f64(self, by: f64) -> Self {
+        Self {
+            rows: self.rows.map(|row| row / by),
+        }
+    }
+}
+
+impl Mat4 {
+    /// The 2x2 minors of the upper two rows (s) and the lower two rows (c),
+    /// shared by `determinant` and `inverse`
+    fn minors(self) -> ([f64; 6], [f64; 6]) {
+        let [a0, a1, a2, a3] = self.rows;
+
+        let s = [
+            a0.x * a1.y - a1.x * a0.y,
+            a0.x * a1.z - a1.x * a0.z,
+            a0.x * a1.w - a1.x * a0.w,
+            a0.y * a1.z - a1.y * a0.z,
+            a0.y * a1.w - a1.y * a0.w,
+            a0.z * a1.w - a1.z * a0.w,
+        ];
+        let c = [
+            a2.x * a3.y - a3.x * a2.y,
+            a2.x * a3.z - a3.x * a2.z,
+            a2.x * a3.w - a3.x * a2.w,
+            a2.y * a3.z - a3.y * a2.z,
+            a2.y * a3.w - a3.y * a2.w,
+            a2.z * a3.w - a3.z * a2.w,
+        ];
+
+        (s, c)
+    }
+}
+
+crate::impl_common_ops_for_mat!(Mat4, Vec4);
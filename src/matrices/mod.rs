@@ -0,0 +1,112 @@
+//! # Matrices
+//! Square matrices that transform the vectors of [`crate::vectors`].
+//!
+//! All matrices are stored row by row, every row being a vector of the matching size.
+//! This means that `Mat3 * Vec3` treats the vector as a column vector and returns a `Vec3`, in which
+//! each component is the dot product of a row and the vector.
+
+pub mod mat2;
+pub mod mat3;
+pub mod mat4;
+
+use crate::vectors::GenericVector;
+
+pub trait GenericMatrix: crate::prelude::private::Sealed + Copy {
+    /// The vector type of a single row or column
+    type Vector: GenericVector;
+
+    /// # Identity
+    /// The identity matrix, 1 on the diagonal and 0 everywhere else
+    fn identity() -> Self;
+
+    /// # Zero
+    /// A matrix filled with 0
+    fn zero() -> Self;
+
+    fn row(self, index: usize) -> Self::Vector;
+
+    fn col(self, index: usize) -> Self::Vector;
+
+    fn transpose(self) -> Self;
+
+    fn determinant(self) -> f64;
+
+    /// # Inverse
+    /// Returns `None` if the matrix is singular (the determinant is 0) or the determinant is not finite,
+    /// e.g. for elements that are NaN or so large that the determinant overflows
+    fn inverse(self) -> Option<Self>;
+
+    /// # Trace
+    /// The sum of the elements on the diagonal
+    fn trace(self) -> f64;
+
+    fn mmul(self, other: Self) -> Self;
+
+    fn vmul(self, vector: Self::Vector) -> Self::Vector;
+
+    fn madd(self, other: Self) -> Self;
+
+    fn msub(self, other: Self) -> Self;
+
+    fn mul_f64(self, by: f64) -> Self;
+
+    fn div_f64(self, by: f64) -> Self;
+}
+
+pub(crate) mod mat_macros {
+    #[macro_export]
+    macro_rules! impl_common_ops_for_mat {
+        ($m:ident, $v:ident) => {
+            impl core::ops::Mul<$m> for $m {
+                type Output = Self;
+                fn mul(self, rhs: $m) -> Self {
+                    $crate::matrices::GenericMatrix::mmul(self, rhs)
+                }
+            }
+
+            impl core::ops::Mul<$v> for $m {
+                type Output = $v;
+                fn mul(self, rhs: $v) -> $v {
+                    $crate::matrices::GenericMatrix::vmul(self, rhs)
+                }
+            }
+
+            impl core::ops::Mul<f64> for $m {
+                type Output = Self;
+                fn mul(self, rhs: f64) -> Self {
+                    $crate::matrices::GenericMatrix::mul_f64(self, rhs)
+                }
+            }
+
+            impl core::ops::Div<f64> for $m {
+                type Output = Self;
+                fn div(self, rhs: f64) -> Self {
+                    $crate::matrices::GenericMatrix::div_f64(self, rhs)
+                }
+            }
+
+            impl core::ops::Add<$m> for $m {
+                type Output = Self;
+                fn add(self, rhs: $m) -> Self {
+                    $crate::matrices::GenericMatrix::madd(self, rhs)
+                }
+            }
+
+            impl core::ops::Sub<$m> for $m {
+                type Output = Self;
+                fn sub(self, rhs: $m) -> Self {
+                    $crate::matrices::GenericMatrix::msub(self, rhs)
+                }
+            }
+
+            impl core::ops::Neg for $m {
+                type Output = Self;
+                fn neg(self) -> Self {
+                    $crate::matrices::GenericMatrix::mul_f64(self, -1f64)
+                }
+            }
+
+            impl $crate::prelude::private::Sealed for $m {}
+        };
+    }
+}
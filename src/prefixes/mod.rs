@@ -0,0 +1,120 @@
+//! # Unit Prefixes
+//! All functions of this crate return values in SI base units (J, m, kg, ...). Those values are often
+//! impractical to read, e.g. the kinetic energy of a proton at 99% of the speed of light is about 0.0000000009 J.
+//! The traits in this module scale f64's to and from prefixed units.
+//!
+//! - `to_<prefix>` converts a value in the base unit into the prefixed unit (5000 J => 5 kJ)
+//! - `from_<prefix>` converts a value in the prefixed unit back into the base unit (5 kJ => 5000 J)
+//!
+//! ## Precision
+//! Decimal prefixes smaller than 1 (such as 10^-3) cannot be represented exactly as f64's. Therefore,
+//! they are applied by multiplying or dividing by their reciprocal (such as 10^3). Powers of ten up to 10^22
+//! are exact, larger ones (10^24 and up) are correctly rounded. Every conversion is a single, correctly rounded
+//! multiplication or division, so `1500f64.to_kilo()` is exactly 1.5, as 1500 / 1000 is representable.
+//! A round trip such as `value.to_micro().from_micro()` rounds twice and may be off by 1 ULP.
+//! Binary prefixes are powers of two and therefore always exact.
+//!
+//! ## Example
+//! ```rs
+//! let big_joule = skinetic_energy(SPEED_OF_LIGHT - 1f64, 1f64);
+//! let giga_joule = big_joule.to_giga();
+//! ```
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Metric_prefix>
+//! <https://en.wikipedia.org/wiki/Binary_prefix>
+
+/// Declares a prefix trait and implements it for f64.
+/// Prefixes marked `big` are >= 1 and divide in `to_*`, prefixes marked `small` are < 1 and multiply by the reciprocal
+macro_rules! prefix_trait {
+    (
+        $(#[$meta:meta])*
+        $trait:ident {
+            $($size:ident $name:ident, $to:ident, $from:ident, $symbol:literal, $factor_doc:literal, $factor:expr;)*
+        }
+    ) => {
+        $(#[$meta])*
+        // `from_*` takes `self` on purpose, it reads as "this value is given in <prefix>"
+        #[allow(clippy::wrong_self_convention)]
+        pub trait $trait: crate::prelude::private::Sealed {
+            $(
+                #[doc = concat!("Converts a value in the base unit into ", stringify!($name), " (", $symbol, ", ", $factor_doc, ")")]
+                fn $to(self) -> Self;
+                #[doc = concat!("Converts a value in ", stringify!($name), " (", $symbol, ", ", $factor_doc, ") into the base unit")]
+                fn $from(self) -> Self;
+            )*
+        }
+
+        impl $trait for f64 {
+            $(prefix_trait!(@impl $size $to, $from, $factor);)*
+        }
+    };
+    (@impl big $to:ident, $from:ident, $factor:expr) => {
+        #[inline]
+        fn $to(self) -> Self {
+            self / $factor
+        }
+
+        #[inline]
+        fn $from(self) -> Self {
+            self * $factor
+        }
+    };
+    (@impl small $to:ident, $from:ident, $factor:expr) => {
+        #[inline]
+        fn $to(self) -> Self {
+            self * $factor
+        }
+
+        #[inline]
+        fn $from(self) -> Self {
+            self / $factor
+        }
+    };
+}
+
+prefix_trait! {
+    /// # SI Prefixes
+    /// Scales values between base units and all SI prefixes, from quecto (10^-30) to quetta (10^30)
+    SiPrefixes {
+        small quecto, to_quecto, from_quecto, "q", "10^-30", 1e30;
+        small ronto, to_ronto, from_ronto, "r", "10^-27", 1e27;
+        small yocto, to_yocto, from_yocto, "y", "10^-24", 1e24;
+        small zepto, to_zepto, from_zepto, "z", "10^-21", 1e21;
+        small atto, to_atto, from_atto, "a", "10^-18", 1e18;
+        small femto, to_femto, from_femto, "f", "10^-15", 1e15;
+        small pico, to_pico, from_pico, "p", "10^-12", 1e12;
+        small nano, to_nano, from_nano, "n", "10^-9", 1e9;
+        small micro, to_micro, from_micro, "μ", "10^-6", 1e6;
+        small milli, to_milli, from_milli, "m", "10^-3", 1e3;
+        small centi, to_centi, from_centi, "c", "10^-2", 1e2;
+        small deci, to_deci, from_deci, "d", "10^-1", 1e1;
+        big deca, to_deca, from_deca, "da", "10^1", 1e1;
+        big hecto, to_hecto, from_hecto, "h", "10^2", 1e2;
+        big kilo, to_kilo, from_kilo, "k", "10^3", 1e3;
+        big mega, to_mega, from_mega, "M", "10^6", 1e6;
+        big giga, to_giga, from_giga, "G", "10^9", 1e9;
+        big tera, to_tera, from_tera, "T", "10^12", 1e12;
+        big peta, to_peta, from_peta, "P", "10^15", 1e15;
+        big exa, to_exa, from_exa, "E", "10^18", 1e18;
+        big zetta, to_zetta, from_zetta, "Z", "10^21", 1e21;
+        big yotta, to_yotta, from_yotta, "Y", "10^24", 1e24;
+        big ronna, to_ronna, from_ronna, "R", "10^27", 1e27;
+        big quetta, to_quetta, from_quetta, "Q", "10^30", 1e30;
+    }
+}
+
+prefix_trait! {
+    /// # Binary Prefixes
+    /// Scales values between base units and the IEC binary prefixes, from kibi (2^10) to yobi (2^80)
+    BinaryPrefixes {
+        big kibi, to_kibi, from_kibi, "Ki", "2^10", 1024.0f64;
+        big mebi, to_mebi, from_mebi, "Mi", "2^20", 1048576.0f64;
+        big gibi, to_gibi, from_gibi, "Gi", "2^30", 1073741824.0f64;
+        big tebi, to_tebi, from_tebi, "Ti", "2^40", 1099511627776.0f64;
+        big pebi, to_pebi, from_pebi, "Pi", "2^50", 1125899906842624.0f64;
+        big exbi, to_exbi, from_exbi, "Ei", "2^60", 1152921504606846976.0f64;
+        big zebi, to_zebi, from_zebi, "Zi", "2^70", 1180591620717411303424.0f64;
+        big yobi, to_yobi, from_yobi, "Yi", "2^80", 1208925819614629174706176.0f64;
+    }
+}
@@ -1,28 +1,461 @@
 pub use crate::consts;
+pub use crate::prefixes::{BinaryPrefixes, SiPrefixes};
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::backend;
 
 pub(crate) mod private {
     pub trait Sealed {}
     impl Sealed for f64 {}
 }
 
-pub trait NumericalExtensions: private::Sealed {
+/// # Numerical Extensions
+/// Elementary functions for f64 and f32 that work with and without `std`.
+///
+/// The implementation is chosen by the enabled backend feature (`std` or `libm`), see the
+/// crate README for details. When `std` is enabled, the inherent float methods of the same name
+/// take precedence in method calls, which is fine as this trait forwards to them anyway.
+/// This also means that a `use crate::prelude::*` which is only there for this trait is unused whenever
+/// `std` is linked (with the `std` feature or in the test harness). Such imports carry
+/// `#[cfg_attr(any(feature = "std", test), allow(unused_imports))]`, so the `libm` build still catches
+/// every other unused import.
+///
+/// The trait is not sealed, implement it (and [`Real`]) to use your own number type with the generic
+/// functions of this crate.
+pub trait NumericalExtensions {
+    /// Raises a number to an integer power
     fn powi(self, n: i32) -> Self;
-    fn powf(self, n: f64) -> Self;
+    /// Raises a number to a floating point power
+    fn powf(self, n: Self) -> Self;
+    /// Square root
     fn sqrt(self) -> Self;
+    /// Cube root
+    fn cbrt(self) -> Self;
+    /// e^(self)
+    fn exp(self) -> Self;
+    /// e^(self) - 1, accurate even if `self` is close to zero
+    fn exp_m1(self) -> Self;
+    /// Natural logarithm
+    fn ln(self) -> Self;
+    /// ln(1 + self), accurate even if `self` is close to zero
+    fn ln_1p(self) -> Self;
+    /// Base 10 logarithm
+    fn log10(self) -> Self;
+    /// Base 2 logarithm
+    fn log2(self) -> Self;
+    /// Sine (in radians)
+    fn sin(self) -> Self;
+    /// Cosine (in radians)
+    fn cos(self) -> Self;
+    /// Tangent (in radians)
+    fn tan(self) -> Self;
+    /// Arcsine (in radians)
+    fn asin(self) -> Self;
+    /// Arccosine (in radians)
+    fn acos(self) -> Self;
+    /// Arctangent (in radians)
+    fn atan(self) -> Self;
+    /// Four quadrant arctangent of `self` (y) and `other` (x)
+    fn atan2(self, other: Self) -> Self;
+    /// Hyperbolic sine
+    fn sinh(self) -> Self;
+    /// Hyperbolic cosine
+    fn cosh(self) -> Self;
+    /// Hyperbolic tangent
+    fn tanh(self) -> Self;
+    /// Inverse hyperbolic sine
+    fn asinh(self) -> Self;
+    /// Inverse hyperbolic cosine
+    fn acosh(self) -> Self;
+    /// Inverse hyperbolic tangent
+    fn atanh(self) -> Self;
+    /// SquareRoot(self^2 + other^2) without intermediate overflow
+    fn hypot(self, other: Self) -> Self;
+    /// Fused multiply-add: (self * a) + b with only one rounding error
+    fn mul_add(self, a: Self, b: Self) -> Self;
 }
 
 impl NumericalExtensions for f64 {
     #[inline]
     fn powi(self, n: i32) -> Self {
-        unsafe { core::intrinsics::powif64(self, n) }
+        backend::powi(self, n)
     }
 
     #[inline]
     fn powf(self, n: f64) -> Self {
-        unsafe { core::intrinsics::powf64(self, n) }
+        backend::powf(self, n)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        backend::sqrt(self)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        backend::cbrt(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        backend::exp(self)
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        backend::exp_m1(self)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        backend::ln(self)
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        backend::ln_1p(self)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        backend::log10(self)
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        backend::log2(self)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        backend::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        backend::cos(self)
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        backend::tan(self)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        backend::asin(self)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        backend::acos(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        backend::atan(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: f64) -> Self {
+        backend::atan2(self, other)
     }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        backend::sinh(self)
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        backend::cosh(self)
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        backend::tanh(self)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        backend::asinh(self)
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        backend::acosh(self)
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        backend::atanh(self)
+    }
+
+    #[inline]
+    fn hypot(self, other: f64) -> Self {
+        backend::hypot(self, other)
+    }
+
+    #[inline]
+    fn mul_add(self, a: f64, b: f64) -> Self {
+        backend::mul_add(self, a, b)
+    }
+}
+
+impl NumericalExtensions for f32 {
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        backend::powi_f32(self, n)
+    }
+
+    #[inline]
+    fn powf(self, n: f32) -> Self {
+        backend::powf_f32(self, n)
+    }
+
     #[inline]
     fn sqrt(self) -> Self {
-        unsafe { core::intrinsics::sqrtf64(self) }
+        backend::sqrt_f32(self)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        backend::cbrt_f32(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        backend::exp_f32(self)
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        backend::exp_m1_f32(self)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        backend::ln_f32(self)
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        backend::ln_1p_f32(self)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        backend::log10_f32(self)
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        backend::log2_f32(self)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        backend::sin_f32(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        backend::cos_f32(self)
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        backend::tan_f32(self)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        backend::asin_f32(self)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        backend::acos_f32(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        backend::atan_f32(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: f32) -> Self {
+        backend::atan2_f32(self, other)
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        backend::sinh_f32(self)
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        backend::cosh_f32(self)
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        backend::tanh_f32(self)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        backend::asinh_f32(self)
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        backend::acosh_f32(self)
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        backend::atanh_f32(self)
+    }
+
+    #[inline]
+    fn hypot(self, other: f32) -> Self {
+        backend::hypot_f32(self, other)
+    }
+
+    #[inline]
+    fn mul_add(self, a: f32, b: f32) -> Self {
+        backend::mul_add_f32(self, a, b)
+    }
+}
+
+/// # Real
+/// A real number, the scalar type of the generic functions, vectors and formulas of this crate.
+///
+/// Implemented for f64 and f32. Every function is monomorphized for the type it is called with and the
+/// conversions of f64 are the identity, so f64 code compiles to the same machine code as before.
+/// Physical constants are stored as f64 and converted with [`Real::from_f64`], which rounds them for f32.
+///
+/// ## Own number types
+/// The trait is open, so types such as dual numbers or intervals can implement it (together with
+/// [`NumericalExtensions`]) and be passed through the formulas of this crate.
+///
+/// ## Examples
+/// ```rs
+/// let single = slorentz(0.5f32 * SPEED_OF_LIGHT as f32); // -> 1.1547005f32
+/// let double = slorentz(0.5f64 * SPEED_OF_LIGHT); // -> 1.1547005383792517f64
+/// ```
+pub trait Real:
+    NumericalExtensions
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// 0
+    const ZERO: Self;
+    /// 1
+    const ONE: Self;
+
+    /// Converts an f64, e.g. a constant, into this type
+    fn from_f64(value: f64) -> Self;
+    /// Converts this number into an f64, e.g. for comparisons or output
+    fn to_f64(self) -> f64;
+    /// Absolute value
+    fn abs(self) -> Self;
+    /// The smaller of both numbers, ignoring NaN
+    fn min(self, other: Self) -> Self;
+    /// The larger of both numbers, ignoring NaN
+    fn max(self, other: Self) -> Self;
+    /// True if the number is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+
+    /// Restricts the number to the interval min..=max. NaN stays NaN.
+    ///
+    /// Unlike `f64::clamp`, this never panics, and every implementation follows the same rules: a NaN bound is
+    /// ignored, and if min > max, values below min return min and all others return max
+    fn clamp(self, min: Self, max: Self) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+impl Real for f64 {
+    const ZERO: Self = 0f64;
+    const ONE: Self = 1f64;
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        f64::min(self, other)
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+impl Real for f32 {
+    const ZERO: Self = 0f32;
+    const ONE: Self = 1f32;
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        f32::min(self, other)
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        f32::max(self, other)
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
     }
 }
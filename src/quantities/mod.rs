@@ -0,0 +1,205 @@
+//! # Quantities
+//! Physical quantities are more than numbers, they have a dimension. Adding a mass to a speed makes no sense,
+//! but passing a mass where a speed was expected is easy if both are just f64's.
+//!
+//! Every type in this module wraps a single f64 in the matching SI unit. The wrappers are `#[repr(transparent)]`,
+//! so they have no runtime cost.
+//!
+//! ## Dimension checking
+//! - Quantities of the same type can be added, subtracted and compared
+//! - Quantities can be scaled by f64's. Dividing two quantities of the same type returns a (dimensionless) f64
+//! - Multiplication and division of different quantities only compile if the result is a known quantity,
+//!   e.g. `Length / Time` is a `Velocity` and `Mass * Acceleration` is a `Force`
+//!
+//! ## Example
+//! ```rs
+//! let distance = Length::new(100f64);
+//! let time = Time::new(9.58);
+//! let speed: Velocity = distance / time;
+//! let nonsense = distance + time; // Does not compile
+//! ```
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Dimensional_analysis>
+
+/// Declares a quantity and the operations with itself and f64's
+macro_rules! quantity {
+    ($(#[$meta:meta])* $name:ident, $unit:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[repr(transparent)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            #[doc = concat!("The SI unit of the quantity (", $unit, ")")]
+            pub const UNIT: &'static str = $unit;
+
+            #[doc = concat!("Creates the quantity from a value in ", $unit)]
+            pub const fn new(value: f64) -> Self {
+                Self(value)
+            }
+
+            #[doc = concat!("The value in ", $unit)]
+            pub const fn value(self) -> f64 {
+                self.0
+            }
+        }
+
+        impl core::ops::Add<$name> for $name {
+            type Output = Self;
+            fn add(self, rhs: $name) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl core::ops::Sub<$name> for $name {
+            type Output = Self;
+            fn sub(self, rhs: $name) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl core::ops::Neg for $name {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl core::ops::Mul<f64> for $name {
+            type Output = Self;
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl core::ops::Mul<$name> for f64 {
+            type Output = $name;
+            fn mul(self, rhs: $name) -> $name {
+                $name(self * rhs.0)
+            }
+        }
+
+        impl core::ops::Div<f64> for $name {
+            type Output = Self;
+            fn div(self, rhs: f64) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+
+        impl core::ops::Div<$name> for $name {
+            type Output = f64;
+            fn div(self, rhs: $name) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)?;
+                f.write_str(concat!(" ", $unit))
+            }
+        }
+    };
+}
+
+/// Declares that `a * b = c`, which implies `b * a = c`, `c / a = b` and `c / b = a`.
+/// `a squared = c` declares `a * a = c` and `c / a = a`
+macro_rules! quantity_product {
+    ($a:ident squared = $c:ident) => {
+        impl core::ops::Mul<$a> for $a {
+            type Output = $c;
+            fn mul(self, rhs: $a) -> $c {
+                $c(self.0 * rhs.0)
+            }
+        }
+
+        impl core::ops::Div<$a> for $c {
+            type Output = $a;
+            fn div(self, rhs: $a) -> $a {
+                $a(self.0 / rhs.0)
+            }
+        }
+    };
+    ($a:ident * $b:ident = $c:ident) => {
+        impl core::ops::Mul<$b> for $a {
+            type Output = $c;
+            fn mul(self, rhs: $b) -> $c {
+                $c(self.0 * rhs.0)
+            }
+        }
+
+        impl core::ops::Mul<$a> for $b {
+            type Output = $c;
+            fn mul(self, rhs: $a) -> $c {
+                $c(self.0 * rhs.0)
+            }
+        }
+
+        impl core::ops::Div<$a> for $c {
+            type Output = $b;
+            fn div(self, rhs: $a) -> $b {
+                $b(self.0 / rhs.0)
+            }
+        }
+
+        impl core::ops::Div<$b> for $c {
+            type Output = $a;
+            fn div(self, rhs: $b) -> $a {
+                $a(self.0 / rhs.0)
+            }
+        }
+    };
+}
+
+quantity!(
+    /// # Length (l :: m)
+    Length, "m"
+);
+quantity!(
+    /// # Area (A :: m^2)
+    Area, "m^2"
+);
+quantity!(
+    /// # Time (t :: s)
+    Time, "s"
+);
+quantity!(
+    /// # Mass (m :: kg)
+    Mass, "kg"
+);
+quantity!(
+    /// # Velocity (v :: m/s)
+    Velocity, "m/s"
+);
+quantity!(
+    /// # Acceleration (a :: m/s^2)
+    Acceleration, "m/s^2"
+);
+quantity!(
+    /// # Force (F :: N)
+    Force, "N"
+);
+quantity!(
+    /// # Momentum (p :: kg m/s)
+    Momentum, "kg m/s"
+);
+quantity!(
+    /// # Energy (E :: J)
+    Energy, "J"
+);
+quantity!(
+    /// # Power (P :: W)
+    Power, "W"
+);
+
+quantity_product!(Length squared = Area);
+quantity_product!(Velocity * Time = Length);
+quantity_product!(Acceleration * Time = Velocity);
+quantity_product!(Mass * Acceleration = Force);
+quantity_product!(Mass * Velocity = Momentum);
+quantity_product!(Force * Time = Momentum);
+quantity_product!(Force * Length = Energy);
+quantity_product!(Momentum * Velocity = Energy);
+quantity_product!(Power * Time = Energy);
+quantity_product!(Force * Velocity = Power);
@@ -0,0 +1,63 @@
+//! # Euler Angles
+//! Euler angles describe a rotation as three successive rotations around coordinate axes.
+//! There is no single convention, which is why the order of the axes and the frame the rotations
+//! are performed in have to be selected explicitly.
+//!
+//! ## Tait-Bryan vs Proper Euler angles
+//! Tait-Bryan angles rotate around three different axes (e.g. XYZ, the common yaw-pitch-roll is ZYX).
+//! Proper Euler angles rotate around the first axis again as the last step (e.g. ZXZ).
+//!
+//! ## Intrinsic vs Extrinsic
+//! Intrinsic rotations rotate around the axes of the rotating body, which move with each rotation.
+//! Extrinsic rotations rotate around the fixed axes of the world.
+//! An intrinsic rotation in the order XYZ is the same as an extrinsic rotation in the order ZYX, with the angles reversed.
+
+use crate::vectors::vec3::Vec3;
+
+/// # Euler Order
+/// The order of the axes the three rotations are performed around. The first letter is the axis of the first angle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    /// The three rotation axes, in the order the angles are applied
+    pub fn axes(self) -> [Vec3; 3] {
+        let (x, y, z) = (Vec3::xaxis(), Vec3::yaxis(), Vec3::zaxis());
+        match self {
+            EulerOrder::XYZ => [x, y, z],
+            EulerOrder::XZY => [x, z, y],
+            EulerOrder::YXZ => [y, x, z],
+            EulerOrder::YZX => [y, z, x],
+            EulerOrder::ZXY => [z, x, y],
+            EulerOrder::ZYX => [z, y, x],
+            EulerOrder::XYX => [x, y, x],
+            EulerOrder::XZX => [x, z, x],
+            EulerOrder::YXY => [y, x, y],
+            EulerOrder::YZY => [y, z, y],
+            EulerOrder::ZXZ => [z, x, z],
+            EulerOrder::ZYZ => [z, y, z],
+        }
+    }
+}
+
+/// # Euler Frame
+/// Whether the rotations are performed around the moving axes of the body (intrinsic)
+/// or the fixed axes of the world (extrinsic)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerFrame {
+    Intrinsic,
+    Extrinsic,
+}
@@ -0,0 +1,18 @@
+//! # Quaternions
+//! Quaternions are an extension of the complex numbers with three imaginary units i, j and k:
+//!
+//! q = w + xi + yj + zk
+//!
+//! Unit quaternions (quaternions with a norm of 1) represent rotations in 3D space. Compared to rotation
+//! matrices, they are smaller, cheaper to compose, easy to re-normalize and can be interpolated smoothly (slerp).
+//! Compared to Euler angles, they do not suffer from gimbal lock.
+//!
+//! A rotation by the angle θ around the (normalized) axis u is represented by the quaternion
+//!
+//! cos(θ/2) + sin(θ/2)(u.x i + u.y j + u.z k)
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Quaternions_and_spatial_rotation>
+
+pub mod euler;
+pub mod quaternion;
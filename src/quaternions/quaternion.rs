@@ -0,0 +1,324 @@
+use crate::matrices::{mat3::Mat3, GenericMatrix};
+use crate::vectors::{vec3::Vec3, vec4::Vec4, CrossProduct, GenericVector};
+
+use super::euler::{EulerFrame, EulerOrder};
+#[cfg_attr(any(feature = "std", test), allow(unused_imports))]
+use crate::prelude::*;
+
+/// Above this dot product, slerp falls back to nlerp, as sin(θ) gets too close to 0
+const SLERP_THRESHOLD: f64 = 0.9995;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    /// # Quaternion.new
+    /// Creates a quaternion w + xi + yj + zk
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// # Quaternion.identity
+    /// The identity rotation (1 + 0i + 0j + 0k)
+    pub fn identity() -> Self {
+        Self::new(1f64, 0f64, 0f64, 0f64)
+    }
+
+    /// # Quaternion.from_scalar_vector
+    /// Creates a quaternion from its scalar (real) part and its vector (imaginary) part
+    pub fn from_scalar_vector(w: f64, v: Vec3) -> Self {
+        Self::new(w, v.x, v.y, v.z)
+    }
+
+    /// # Quaternion.vector
+    /// The vector (imaginary) part of the quaternion
+    pub fn vector(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// # Quaternion.from_axis_angle
+    /// A rotation by `angle` (in radians) around `axis`, following the right hand rule.
+    /// The axis does not have to be normalized
+    ///
+    /// ## Example
+    /// ```rs
+    /// let quarter_turn = Quaternion::from_axis_angle(Vec3::zaxis(), FRAC_PI_2);
+    /// let rotated = quarter_turn * Vec3::xaxis(); // -> (0,1,0)
+    /// ```
+    pub fn from_axis_angle(axis: Vec3, angle: f64) -> Self {
+        let half = angle / 2f64;
+        Self::from_scalar_vector(half.cos(), axis.normalize() * half.sin())
+    }
+
+    /// # Quaternion.to_axis_angle
+    /// The axis and angle (in radians, 0..=2π) of the rotation.
+    /// For the identity rotation, the axis is arbitrary (x axis)
+    pub fn to_axis_angle(self) -> (Vec3, f64) {
+        let q = self.normalize();
+        let sin = q.vector().length();
+        if sin == 0f64 {
+            return (Vec3::xaxis(), 0f64);
+        }
+
+        (q.vector() / sin, 2f64 * sin.atan2(q.w))
+    }
+
+    /// # Quaternion.from_euler
+    /// Creates a rotation from three Euler angles (in radians) in the given order and frame.
+    /// `angles[0]` is the angle around the first axis of `order`
+    ///
+    /// ## Example
+    /// ```rs
+    /// // Yaw, pitch and roll
+    /// let q = Quaternion::from_euler(EulerOrder::ZYX, EulerFrame::Intrinsic, [yaw, pitch, roll]);
+    /// ```
+    pub fn from_euler(order: EulerOrder, frame: EulerFrame, angles: [f64; 3]) -> Self {
+        let [a, b, c] = order.axes();
+        let first = Self::from_axis_angle(a, angles[0]);
+        let second = Self::from_axis_angle(b, angles[1]);
+        let third = Self::from_axis_angle(c, angles[2]);
+
+        match frame {
+            EulerFrame::Intrinsic => first * second * third,
+            EulerFrame::Extrinsic => third * second * first,
+        }
+    }
+
+    /// # Quaternion.from_rotation_matrix
+    /// Converts a rotation matrix (acting on column vectors) into a quaternion.
+    /// The matrix has to be orthonormal with a determinant of 1
+    ///
+    /// ## Read more
+    /// <https://en.wikipedia.org/wiki/Rotation_matrix#Quaternion>
+    pub fn from_rotation_matrix(mat: Mat3) -> Self {
+        let [r0, r1, r2] = mat.rows;
+        let trace = mat.trace();
+
+        // Use the largest of w, x, y, z to divide by, to avoid dividing by values close to 0
+        let q = if trace > 0f64 {
+            let s = (trace + 1f64).sqrt() * 2f64;
+            Self::new(
+                s / 4f64,
+                (r2.y - r1.z) / s,
+                (r0.z - r2.x) / s,
+                (r1.x - r0.y) / s,
+            )
+        } else if r0.x > r1.y && r0.x > r2.z {
+            let s = (1f64 + r0.x - r1.y - r2.z).sqrt() * 2f64;
+            Self::new(
+                (r2.y - r1.z) / s,
+                s / 4f64,
+                (r0.y + r1.x) / s,
+                (r0.z + r2.x) / s,
+            )
+        } else if r1.y > r2.z {
+            let s = (1f64 + r1.y - r0.x - r2.z).sqrt() * 2f64;
+            Self::new(
+                (r0.z - r2.x) / s,
+                (r0.y + r1.x) / s,
+                s / 4f64,
+                (r1.z + r2.y) / s,
+            )
+        } else {
+            let s = (1f64 + r2.z - r0.x - r1.y).sqrt() * 2f64;
+            Self::new(
+                (r1.x - r0.y) / s,
+                (r0.z + r2.x) / s,
+                (r1.z + r2.y) / s,
+                s / 4f64,
+            )
+        };
+
+        q.normalize()
+    }
+
+    /// # Quaternion.to_rotation_matrix
+    /// Converts a unit quaternion into a rotation matrix (acting on column vectors)
+    pub fn to_rotation_matrix(self) -> Mat3 {
+        let Self { w, x, y, z } = self;
+        Mat3::new(
+            1f64 - 2f64 * (y * y + z * z),
+            2f64 * (x * y - w * z),
+            2f64 * (x * z + w * y),
+            2f64 * (x * y + w * z),
+            1f64 - 2f64 * (x * x + z * z),
+            2f64 * (y * z - w * x),
+            2f64 * (x * z - w * y),
+            2f64 * (y * z + w * x),
+            1f64 - 2f64 * (x * x + y * y),
+        )
+    }
+
+    /// # Quaternion.from_two_vectors
+    /// The shortest rotation that turns the direction of `from` into the direction of `to`.
+    /// If both vectors point in opposite directions, the rotation is a half turn around an arbitrary
+    /// axis perpendicular to `from`
+    pub fn from_two_vectors(from: Vec3, to: Vec3) -> Self {
+        let (from, to) = (from.normalize(), to.normalize());
+        let dot = from | to;
+
+        if dot <= -1f64 + f64::EPSILON {
+            let mut axis = Vec3::xaxis().cross(from);
+            if axis.length_squared() < f64::EPSILON {
+                axis = Vec3::yaxis().cross(from);
+            }
+            return Self::from_axis_angle(axis, core::f64::consts::PI);
+        }
+
+        Self::from_scalar_vector(1f64 + dot, from.cross(to)).normalize()
+    }
+
+    /// # Quaternion.dot
+    /// The 4D dot product of two quaternions
+    pub fn dot(self, other: Self) -> f64 {
+        Vec4::from(self) | Vec4::from(other)
+    }
+
+    /// # Quaternion.norm_squared
+    pub fn norm_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// # Quaternion.norm
+    /// The length of the quaternion. Rotations have a norm of 1
+    pub fn norm(self) -> f64 {
+        self.norm_squared().sqrt()
+    }
+
+    /// # Quaternion.normalize
+    /// Scales the quaternion to a norm of 1. Use this to remove rounding errors after many compositions
+    pub fn normalize(self) -> Self {
+        self / self.norm()
+    }
+
+    /// # Quaternion.conjugate
+    /// w - xi - yj - zk. For unit quaternions, this is the inverse rotation
+    pub fn conjugate(self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// # Quaternion.inverse
+    /// The multiplicative inverse, `None` for the zero quaternion
+    pub fn inverse(self) -> Option<Self> {
+        let norm_squared = self.norm_squared();
+        if norm_squared == 0f64 {
+            return None;
+        }
+        Some(self.conjugate() / norm_squared)
+    }
+
+    /// # Quaternion.rotate
+    /// Rotates a vector. The quaternion has to be normalized
+    pub fn rotate(self, v: Vec3) -> Vec3 {
+        // Expanded form of q * v * q^-1
+        let u = self.vector();
+        let t = u.cross(v) * 2f64;
+        v + t * self.w + u.cross(t)
+    }
+
+    /// # Quaternion.nlerp
+    /// Normalized linear interpolation. Cheaper than `slerp`, but the angular velocity is not constant
+    pub fn nlerp(self, other: Self, t: f64) -> Self {
+        // Take the shorter path, q and -q are the same rotation
+        let other = if self.dot(other) < 0f64 {
+            -other
+        } else {
+            other
+        };
+        (self * (1f64 - t) + other * t).normalize()
+    }
+
+    /// # Quaternion.slerp
+    /// Spherical linear interpolation between two rotations with constant angular velocity.
+    /// `t` = 0 returns `self`, `t` = 1 returns `other`
+    ///
+    /// ## Read more
+    /// <https://en.wikipedia.org/wiki/Slerp>
+    pub fn slerp(self, other: Self, t: f64) -> Self {
+        let mut dot = self.dot(other);
+        let mut other = other;
+        if dot < 0f64 {
+            other = -other;
+            dot = -dot;
+        }
+
+        if dot > SLERP_THRESHOLD {
+            return self.nlerp(other, t);
+        }
+
+        let theta = dot.acos();
+        let sin = theta.sin();
+        (self * ((1f64 - t) * theta).sin() + other * (t * theta).sin()) / sin
+    }
+}
+
+impl From<Quaternion> for Vec4 {
+    fn from(q: Quaternion) -> Self {
+        Vec4::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Vec4> for Quaternion {
+    fn from(v: Vec4) -> Self {
+        Quaternion::new(v.w, v.x, v.y, v.z)
+    }
+}
+
+impl core::ops::Mul<Quaternion> for Quaternion {
+    type Output = Self;
+    /// The Hamilton product. `a * b` is the rotation `b` followed by `a`
+    fn mul(self, rhs: Quaternion) -> Self {
+        let (v, u) = (self.vector(), rhs.vector());
+        Self::from_scalar_vector(
+            self.w * rhs.w - (v | u),
+            u * self.w + v * rhs.w + v.cross(u),
+        )
+    }
+}
+
+impl core::ops::Mul<Vec3> for Quaternion {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.rotate(rhs)
+    }
+}
+
+impl core::ops::Mul<f64> for Quaternion {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        Vec4::from(self).mul_f64(rhs).into()
+    }
+}
+
+impl core::ops::Div<f64> for Quaternion {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        Vec4::from(self).div_f64(rhs).into()
+    }
+}
+
+impl core::ops::Add<Quaternion> for Quaternion {
+    type Output = Self;
+    fn add(self, rhs: Quaternion) -> Self {
+        Vec4::from(self).vadd(rhs.into()).into()
+    }
+}
+
+impl core::ops::Sub<Quaternion> for Quaternion {
+    type Output = Self;
+    fn sub(self, rhs: Quaternion) -> Self {
+        Vec4::from(self).vsub(rhs.into()).into()
+    }
+}
+
+impl core::ops::Neg for Quaternion {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self * -1f64
+    }
+}
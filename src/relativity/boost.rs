@@ -0,0 +1,201 @@
+//! # Lorentz Transformations
+//! A Lorentz transformation converts the coordinates of an event (or any other four-vector) from one
+//! inertial frame into another. Every proper Lorentz transformation is a combination of a rotation and a boost,
+//! a change into a frame that moves with a constant velocity.
+//!
+//! ## Boosts
+//! For a frame that moves with the velocity v⃗ = βc n⃗, the coordinates of an event transform as
+//!
+//! t' = γ(t - β n⃗·x⃗)
+//!
+//! x⃗' = x⃗ + (γ - 1)(n⃗·x⃗)n⃗ - γβt n⃗
+//!
+//! where t is the time component (ct) of the four-vector.
+//!
+//! ## Wigner rotation
+//! Two boosts in different directions do not combine into a single boost, but into a boost followed by a rotation.
+//! This rotation is called the (Thomas-)Wigner rotation and causes the Thomas precession of spinning particles.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Lorentz_transformation>
+//! <https://en.wikipedia.org/wiki/Wigner_rotation>
+
+use super::fourvector::{FourVector, MetricSignature};
+use super::lorentz::slorentz;
+use crate::consts::SPEED_OF_LIGHT;
+use crate::matrices::{mat3::Mat3, mat4::Mat4, GenericMatrix};
+use crate::quaternions::quaternion::Quaternion;
+use crate::vectors::{vec3::Vec3, vec4::Vec4, GenericVector};
+
+/// # Lorentz Transformation
+/// A linear transformation of four-vectors that keeps the Minkowski inner product.
+/// The matrix acts on the components in the order (t, x, y, z), which are the (x, y, z, w) of a [`Vec4`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LorentzTransform {
+    pub matrix: Mat4,
+}
+
+impl LorentzTransform {
+    /// # LorentzTransform.new
+    /// Creates a transformation from its matrix. The matrix is not checked to be a Lorentz transformation
+    pub fn new(matrix: Mat4) -> Self {
+        Self { matrix }
+    }
+
+    /// # LorentzTransform.identity
+    /// The transformation that keeps all four-vectors unchanged
+    pub fn identity() -> Self {
+        Self::new(Mat4::identity())
+    }
+
+    /// # LorentzTransform.boost
+    /// The transformation into a frame that moves with `velocity` (in m/s) relative to the current frame.
+    /// The speed has to be below the speed of light
+    ///
+    /// ## Example
+    /// ```rs
+    /// let boost = LorentzTransform::boost(Vec3::new(SPEED_OF_LIGHT / 2f64, 0f64, 0f64));
+    /// let event_in_moving_frame = boost * FourVector::from_event(1f64, Vec3::zero());
+    /// ```
+    pub fn boost(velocity: Vec3) -> Self {
+        let speed = velocity.length();
+        if speed == 0f64 {
+            return Self::identity();
+        }
+
+        let lorentz = slorentz(speed);
+        let beta = velocity / SPEED_OF_LIGHT;
+        let n = velocity / speed;
+
+        // The spatial rows are -γβᵢ followed by the row of I + (γ - 1) n⃗n⃗^T
+        let row = |beta: f64, n_component: f64, axis: Vec3| {
+            time_and_space(-lorentz * beta, axis + n * ((lorentz - 1f64) * n_component))
+        };
+        Self::new(Mat4::from_rows(
+            time_and_space(lorentz, beta * -lorentz),
+            row(beta.x, n.x, Vec3::xaxis()),
+            row(beta.y, n.y, Vec3::yaxis()),
+            row(beta.z, n.z, Vec3::zaxis()),
+        ))
+    }
+
+    /// # LorentzTransform.rotation
+    /// A pure rotation of the spatial part by a unit quaternion, the time component is unchanged
+    pub fn rotation(rotation: Quaternion) -> Self {
+        let [r0, r1, r2] = rotation.to_rotation_matrix().rows;
+        Self::new(Mat4::from_rows(
+            time_and_space(1f64, Vec3::zero()),
+            time_and_space(0f64, r0),
+            time_and_space(0f64, r1),
+            time_and_space(0f64, r2),
+        ))
+    }
+
+    /// # LorentzTransform.apply
+    /// Transforms a four-vector, same as `transform * vector`
+    pub fn apply(self, vector: FourVector) -> FourVector {
+        (self.matrix * Vec4::from(vector)).into()
+    }
+
+    /// # LorentzTransform.then
+    /// The transformation that applies `self` first and `next` afterwards, same as `next * self`
+    pub fn then(self, next: Self) -> Self {
+        next * self
+    }
+
+    /// # LorentzTransform.inverse
+    /// The inverse transformation, η Λ^T η. Unlike the inverse of a general matrix, this is always defined
+    pub fn inverse(self) -> Self {
+        let eta = Mat4::from_diagonal(Vec4::from(FourVector::from(
+            MetricSignature::MostlyMinus.diagonal(),
+        )));
+        Self::new(eta * self.matrix.transpose() * eta)
+    }
+
+    /// # LorentzTransform.decompose
+    /// Splits the transformation into a boost followed by a rotation, Λ = R·B(v⃗).
+    /// Returns the velocity (in m/s) of the boost and the rotation.
+    /// The velocity is the velocity of the new frame relative to the old one.
+    ///
+    /// Only works for proper, orthochronous transformations (no reflections or time reversal)
+    pub fn decompose(self) -> (Vec3, Quaternion) {
+        let time_row = FourVector::from(self.matrix.row(0));
+        let velocity = time_row.spatial() * (-SPEED_OF_LIGHT / time_row.t);
+
+        // R = Λ·B(v⃗)^-1, which keeps the time axis, so its spatial part is a rotation matrix
+        let rotation = (self * Self::boost(-velocity)).matrix;
+        let spatial = |row: usize| FourVector::from(rotation.row(row)).spatial();
+        let mat = Mat3::from_rows(spatial(1), spatial(2), spatial(3));
+
+        (velocity, Quaternion::from_rotation_matrix(mat))
+    }
+}
+
+/// A row of the matrix, with the time component first
+fn time_and_space(time: f64, spatial: Vec3) -> Vec4 {
+    FourVector::from_time_spatial(time, spatial).into()
+}
+
+impl core::ops::Mul<LorentzTransform> for LorentzTransform {
+    type Output = Self;
+    /// `a * b` is the transformation `b` followed by `a`
+    fn mul(self, rhs: LorentzTransform) -> Self {
+        Self::new(self.matrix * rhs.matrix)
+    }
+}
+
+impl core::ops::Mul<FourVector> for LorentzTransform {
+    type Output = FourVector;
+    fn mul(self, rhs: FourVector) -> FourVector {
+        self.apply(rhs)
+    }
+}
+
+/// # Simple Boost Composition
+/// Combines a boost into a frame moving with `first`, followed by a boost (from that frame) into a frame moving with
+/// `second`, into a single boost and a rotation
+///
+/// ## Variables
+/// ### Parameters
+/// - first (v⃗₁ :: m/s) = The velocity of the first boost
+/// - second (v⃗₂ :: m/s) = The velocity of the second boost, measured in the frame of the first
+/// ### Returns
+/// - Velocity (v⃗ :: m/s) = The velocity of the final frame relative to the original frame
+/// - Rotation (R :: quaternion) = The Wigner rotation
+///
+/// ## Examples
+/// ```rs
+/// let (velocity, rotation) = scompose_boosts(Vec3::new(0.6 * SPEED_OF_LIGHT, 0f64, 0f64), Vec3::new(0f64, 0.6 * SPEED_OF_LIGHT, 0f64));
+/// ```
+///
+/// ## Related Functions
+/// `swigner_rotation` => Only the rotation
+/// `svelocity_addition_vec3` => Only the velocity
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Wigner_rotation>
+pub fn scompose_boosts(first: Vec3, second: Vec3) -> (Vec3, Quaternion) {
+    LorentzTransform::boost(first)
+        .then(LorentzTransform::boost(second))
+        .decompose()
+}
+
+/// # Simple Wigner Rotation
+/// Calculates the rotation that remains when two boosts in different directions are combined into a single boost.
+/// Collinear boosts do not cause a rotation
+///
+/// ## Variables
+/// ### Parameters
+/// - first (v⃗₁ :: m/s) = The velocity of the first boost
+/// - second (v⃗₂ :: m/s) = The velocity of the second boost, measured in the frame of the first
+/// ### Returns
+/// - Rotation (R :: quaternion) = The Wigner rotation
+///
+/// ## Related Functions
+/// `scompose_boosts` => Also returns the combined velocity
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Wigner_rotation>
+pub fn swigner_rotation(first: Vec3, second: Vec3) -> Quaternion {
+    scompose_boosts(first, second).1
+}
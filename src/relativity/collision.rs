@@ -0,0 +1,316 @@
+//! # Collision Kinematics
+//! In particle collisions, energy and momentum are conserved, but mass is not: the kinetic energy of the colliding
+//! particles can be turned into new particles and vice versa. The bookkeeping is done with four-momenta
+//!
+//! P = (E / c, p⃗)
+//!
+//! stored in a [`FourVector`]. The square of a four-momentum is the same in every frame and gives the invariant
+//! mass, (mc)^2 = (E / c)^2 - p⃗^2. For a system of particles, the invariant mass of the summed four-momenta is the
+//! energy available in the center of mass frame, √s = Mc^2.
+//!
+//! ## Thresholds
+//! A reaction a + b -> 1 + 2 + ... is only possible if √s is at least the total rest energy of the products.
+//! For a projectile hitting a target at rest, a part of the projectile's energy is always lost as motion of the
+//! center of mass, which makes fixed-target experiments much less efficient than colliders.
+//!
+//! All masses are in kg, energies in J and momenta in kg·m/s.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Invariant_mass>
+//! <https://en.wikipedia.org/wiki/Threshold_energy>
+//! <https://en.wikipedia.org/wiki/Two-body_problem#Decay>
+
+use super::boost::LorentzTransform;
+use super::energy::{senergy_from_momentum, stotal_energy};
+use super::fourvector::FourVector;
+use super::momentum::smomentum_vec3;
+use crate::consts::{SPEED_OF_LIGHT, SPEED_OF_LIGHT_SQUARED};
+#[cfg_attr(any(feature = "std", test), allow(unused_imports))]
+use crate::prelude::*;
+use crate::vectors::{vec3::Vec3, GenericVector};
+
+/// # Simple Four-Momentum
+/// Calculates the four-momentum of a particle, P = (γmc, γmv⃗)
+///
+/// ## Variables
+/// ### Parameters
+/// - velocity (v⃗ :: m/s)
+/// - mass (m :: kg) = The (rest) mass of the particle
+/// ### Returns
+/// - Four-Momentum (P :: kg·m/s)
+///
+/// ## Related Functions
+/// `cfour_momentum` => Complex version, from the energy and the momentum
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Four-momentum>
+pub fn sfour_momentum(velocity: Vec3, mass: f64) -> FourVector {
+    FourVector::from_time_spatial(
+        stotal_energy(velocity.length(), mass) / SPEED_OF_LIGHT,
+        smomentum_vec3(velocity, mass),
+    )
+}
+
+/// # Complex Four-Momentum
+/// Calculates the four-momentum of a particle from its total energy and momentum, P = (E / c, p⃗).
+/// Works for massless particles as well
+///
+/// ## Variables
+/// ### Parameters
+/// - energy (E :: J) = The total energy, including the rest energy
+/// - momentum (p⃗ :: kg·m/s)
+/// ### Returns
+/// - Four-Momentum (P :: kg·m/s)
+///
+/// ## Examples
+/// ```rs
+/// // A photon with 1 J moving along x
+/// let photon = cfour_momentum(1f64, Vec3::new(1f64 / SPEED_OF_LIGHT, 0f64, 0f64));
+/// ```
+///
+/// ## Related Functions
+/// `sfour_momentum` => Simple version, from the velocity and the mass
+/// `senergy_from_four_momentum` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Four-momentum>
+pub fn cfour_momentum(energy: f64, momentum: Vec3) -> FourVector {
+    FourVector::from_time_spatial(energy / SPEED_OF_LIGHT, momentum)
+}
+
+/// # Simple Energy (Four-Momentum)
+/// Calculates the total energy from a four-momentum, E = P^0 c
+///
+/// ## Variables
+/// ### Parameters
+/// - four_momentum (P :: kg·m/s)
+/// ### Returns
+/// - Energy (E :: J)
+pub fn senergy_from_four_momentum(four_momentum: FourVector) -> f64 {
+    four_momentum.t * SPEED_OF_LIGHT
+}
+
+/// # Simple Invariant Mass
+/// Calculates the invariant mass of a system of particles, Mc^2 = SquareRoot((ΣE)^2 - (Σp⃗ c)^2).
+/// Evaluated as (ΣE - |Σp⃗| c)(ΣE + |Σp⃗| c), which stays accurate for light and fast particles
+///
+/// ## Variables
+/// ### Parameters
+/// - momenta (Pᵢ :: kg·m/s) = The four-momenta of the particles
+/// ### Returns
+/// - Invariant Mass (M :: kg)
+///
+/// ## Examples
+/// ```rs
+/// // Two photons flying apart
+/// let a = cfour_momentum(1f64, Vec3::new(1f64 / SPEED_OF_LIGHT, 0f64, 0f64));
+/// let b = cfour_momentum(1f64, Vec3::new(-1f64 / SPEED_OF_LIGHT, 0f64, 0f64));
+/// let mass = sinvariant_mass([a, b]); // -> 2 J / c^2
+/// ```
+///
+/// ## Related Functions
+/// `smass_from_energy_momentum` => The same for a single particle, from scalars
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Invariant_mass>
+pub fn sinvariant_mass(momenta: impl IntoIterator<Item = FourVector>) -> f64 {
+    let total = momenta
+        .into_iter()
+        .fold(FourVector::zero(), |sum, p| sum + p);
+    let momentum = total.spatial().length();
+    // Rounding can make light-like sums slightly space-like
+    ((total.t - momentum) * (total.t + momentum))
+        .max(0f64)
+        .sqrt()
+        / SPEED_OF_LIGHT
+}
+
+/// # Simple Threshold (Fixed Target)
+/// Calculates the smallest kinetic energy of a projectile hitting a target at rest that can create the products,
+/// T = ((Σmᵢ)^2 - (mₐ + m_b)^2) c^2 / (2m_b)
+///
+/// ## Variables
+/// ### Parameters
+/// - projectile_mass (mₐ :: kg)
+/// - target_mass (m_b :: kg)
+/// - product_masses (mᵢ :: kg) = The masses of all particles after the reaction
+/// ### Returns
+/// - Threshold Kinetic Energy (T :: J), 0 if the reaction releases energy
+///
+/// ## Examples
+/// ```rs
+/// // Antiproton production, p + p -> p + p + p + p̄
+/// let threshold = sthreshold_fixed_target(PROTON, PROTON, [PROTON; 4]); // -> 6 m_p c^2
+/// ```
+///
+/// ## Related Functions
+/// `sthreshold_collider` => The same for two beams colliding head-on
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Threshold_energy>
+pub fn sthreshold_fixed_target(
+    projectile_mass: f64,
+    target_mass: f64,
+    product_masses: impl IntoIterator<Item = f64>,
+) -> f64 {
+    let products: f64 = product_masses.into_iter().sum();
+    let initial = projectile_mass + target_mass;
+    ((products - initial) * (products + initial) * SPEED_OF_LIGHT_SQUARED / (2f64 * target_mass))
+        .max(0f64)
+}
+
+/// # Simple Threshold (Collider)
+/// Calculates the smallest kinetic energy per beam that can create the products, if two particles of the same
+/// mass collide head-on with the same speed, T = (Σmᵢ / 2 - m) c^2
+///
+/// ## Variables
+/// ### Parameters
+/// - beam_mass (m :: kg) = The mass of each colliding particle
+/// - product_masses (mᵢ :: kg) = The masses of all particles after the reaction
+/// ### Returns
+/// - Threshold Kinetic Energy (T :: J) per beam, 0 if the reaction releases energy
+///
+/// ## Related Functions
+/// `sthreshold_fixed_target` => The same for a target at rest
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Threshold_energy>
+pub fn sthreshold_collider(beam_mass: f64, product_masses: impl IntoIterator<Item = f64>) -> f64 {
+    let products: f64 = product_masses.into_iter().sum();
+    ((products / 2f64 - beam_mass) * SPEED_OF_LIGHT_SQUARED).max(0f64)
+}
+
+/// # Simple Two-Body Decay Momentum
+/// Calculates the momentum of both products of a decay M -> m₁ + m₂ in the rest frame of the parent,
+/// p = c SquareRoot((M^2 - (m₁ + m₂)^2)(M^2 - (m₁ - m₂)^2)) / (2M)
+///
+/// ## Variables
+/// ### Parameters
+/// - parent_mass (M :: kg)
+/// - first_mass (m₁ :: kg)
+/// - second_mass (m₂ :: kg)
+/// ### Returns
+/// - Momentum (p :: kg·m/s), `None` if the products are heavier than the parent
+///
+/// ## Examples
+/// ```rs
+/// // π⁺ -> μ⁺ + ν
+/// let momentum = stwo_body_decay_momentum(PION, MUON, 0f64); // -> 29.8 MeV/c
+/// ```
+///
+/// ## Related Functions
+/// `stwo_body_decay` => The four-momenta of the products
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Two-body_problem#Decay>
+pub fn stwo_body_decay_momentum(
+    parent_mass: f64,
+    first_mass: f64,
+    second_mass: f64,
+) -> Option<f64> {
+    let (sum, difference) = (first_mass + second_mass, first_mass - second_mass);
+    if parent_mass < sum {
+        return None;
+    }
+    let squared = (parent_mass - sum)
+        * (parent_mass + sum)
+        * (parent_mass - difference)
+        * (parent_mass + difference);
+    Some(SPEED_OF_LIGHT * squared.sqrt() / (2f64 * parent_mass))
+}
+
+/// # Simple Two-Body Decay
+/// Calculates the four-momenta of both products of a decay M -> m₁ + m₂ in the rest frame of the parent.
+/// The products fly apart back to back
+///
+/// ## Variables
+/// ### Parameters
+/// - parent_mass (M :: kg)
+/// - first_mass (m₁ :: kg)
+/// - second_mass (m₂ :: kg)
+/// - direction (n⃗ :: 1) = The direction of the first product, does not have to be normalized
+/// ### Returns
+/// - Four-Momenta (P₁, P₂ :: kg·m/s), `None` if the products are heavier than the parent or the direction
+///   has no length (or is not finite)
+///
+/// ## Related Functions
+/// `ctwo_body_decay` => Complex version, for a moving parent
+/// `stwo_body_decay_momentum` => Only the momentum
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Two-body_problem#Decay>
+pub fn stwo_body_decay(
+    parent_mass: f64,
+    first_mass: f64,
+    second_mass: f64,
+    direction: Vec3,
+) -> Option<(FourVector, FourVector)> {
+    let length = direction.length();
+    if length == 0f64 || !length.is_finite() {
+        return None;
+    }
+    let momentum = stwo_body_decay_momentum(parent_mass, first_mass, second_mass)?;
+    let first = direction * (momentum / length);
+    Some((
+        cfour_momentum(senergy_from_momentum(momentum, first_mass), first),
+        cfour_momentum(senergy_from_momentum(momentum, second_mass), -first),
+    ))
+}
+
+/// # Complex Two-Body Decay
+/// Calculates the four-momenta of both products of a decay in the lab frame, where the parent moves
+///
+/// ## Variables
+/// ### Parameters
+/// - parent (P :: kg·m/s) = The four-momentum of the parent in the lab frame
+/// - first_mass (m₁ :: kg)
+/// - second_mass (m₂ :: kg)
+/// - direction (n⃗ :: 1) = The direction of the first product in the rest frame of the parent
+/// ### Returns
+/// - Four-Momenta (P₁, P₂ :: kg·m/s) in the lab frame, `None` if the products are heavier than the parent
+///   or the direction has no length (or is not finite)
+///
+/// ## Related Functions
+/// `stwo_body_decay` => Simple version, in the rest frame of the parent
+/// `sboost_to_lab` => The transformation used for both products
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Two-body_problem#Decay>
+pub fn ctwo_body_decay(
+    parent: FourVector,
+    first_mass: f64,
+    second_mass: f64,
+    direction: Vec3,
+) -> Option<(FourVector, FourVector)> {
+    let velocity = parent.spatial() * (SPEED_OF_LIGHT / parent.t);
+    let (first, second) = stwo_body_decay(
+        sinvariant_mass([parent]),
+        first_mass,
+        second_mass,
+        direction,
+    )?;
+    Some((
+        sboost_to_lab(first, velocity),
+        sboost_to_lab(second, velocity),
+    ))
+}
+
+/// # Simple Boost to Lab
+/// Transforms a four-momentum from the rest frame of a moving system (e.g. a decaying particle or the center of
+/// mass of a collision) into the lab frame
+///
+/// ## Variables
+/// ### Parameters
+/// - momentum (P' :: kg·m/s) = The four-momentum in the moving frame
+/// - velocity (v⃗ :: m/s) = The velocity of the moving frame, measured in the lab
+/// ### Returns
+/// - Four-Momentum (P :: kg·m/s) in the lab frame
+///
+/// ## Related Functions
+/// `LorentzTransform::boost` => The general transformation into a moving frame
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_transformation>
+pub fn sboost_to_lab(momentum: FourVector, velocity: Vec3) -> FourVector {
+    LorentzTransform::boost(-velocity).apply(momentum)
+}
@@ -0,0 +1,205 @@
+//! # Curvature
+//! In General Relativity, gravity is the curvature of spacetime. Everything about it follows from the metric
+//! g_μν(x), a function of the coordinates:
+//!
+//! - The Christoffel symbols Γ^λ_μν = ½ g^λσ (∂_μ g_σν + ∂_ν g_σμ - ∂_σ g_μν) describe how the coordinate axes change
+//!   from point to point. They appear in the geodesic equation
+//! - The Riemann tensor R^ρ_σμν = ∂_μ Γ^ρ_νσ - ∂_ν Γ^ρ_μσ + Γ^ρ_μλ Γ^λ_νσ - Γ^ρ_νλ Γ^λ_μσ is the curvature itself.
+//!   It is 0 everywhere only for flat spacetime
+//! - The Ricci tensor R_σν = R^ρ_σρν and the Ricci scalar R = g^σν R_σν appear in Einstein's field equations.
+//!   Both are 0 in vacuum, e.g. outside of a star
+//! - The Kretschmann scalar K = R_ρσμν R^ρσμν measures the curvature independent of the coordinates.
+//!   For Schwarzschild, K = 48M^2 / r^6 (with M = GM / c^2)
+//!
+//! All functions take the metric as a function of the coordinates and calculate the derivatives numerically with
+//! fourth-order central differences. `step` is the distance between the sampled points. It should be small
+//! compared to the scale on which the metric changes, but not so small that rounding errors dominate; the Riemann
+//! tensor needs second derivatives, so its rounding error grows with 1 / step^2. For coordinates around 1 to 100,
+//! a step of 1e-3 is a good start.
+//!
+//! The inverse metric is needed at every sampled point, so all functions return `None` where the metric is singular,
+//! e.g. on the axis θ = 0 of spherical coordinates.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Christoffel_symbols>
+//! <https://en.wikipedia.org/wiki/Riemann_curvature_tensor>
+//! <https://en.wikipedia.org/wiki/Ricci_curvature>
+
+use crate::matrices::mat4::Mat4;
+use crate::tensors::{
+    IndexPosition::{Lower, Upper},
+    MetricTensor, Tensor2, Tensor3, Tensor4, DIMENSION,
+};
+
+/// # Christoffel Symbols
+/// Calculates the Christoffel symbols of the second kind Γ^λ_μν at a point
+///
+/// ## Variables
+/// ### Parameters
+/// - metric (g_μν(x)) = The covariant metric as a function of the coordinates
+/// - point (x) = The coordinates
+/// - step (h) = The step of the numerical derivatives
+/// ### Returns
+/// - Christoffel Symbols (Γ^λ_μν), indices [Upper, Lower, Lower]. `None` if the metric is singular at the point
+///
+/// ## Examples
+/// ```rs
+/// let schwarzschild = |[_, r, theta, _]: [f64; 4]| { /* g_μν */ };
+/// let gamma = christoffel(schwarzschild, [0f64, 10f64, FRAC_PI_2, 0f64], 1e-3).unwrap();
+/// let gamma_r_tt = gamma.get([1, 0, 0]);
+/// ```
+///
+/// ## Related Functions
+/// `riemann` => The curvature, calculated from the Christoffel symbols
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Christoffel_symbols>
+pub fn christoffel(
+    metric: impl Fn([f64; 4]) -> Mat4,
+    point: [f64; 4],
+    step: f64,
+) -> Option<Tensor3> {
+    let inverse = MetricTensor::new(metric(point))?.inverse_tensor();
+    let flat = |x: [f64; 4]| Some(Tensor2::from_matrix(metric(x), [Lower; 2]).components);
+    // dg[α][μ * 4 + ν] = ∂_α g_μν
+    let mut dg = [[0f64; 16]; DIMENSION];
+    for (axis, derivative) in dg.iter_mut().enumerate() {
+        *derivative = partial(&flat, point, axis, step)?;
+    }
+    let d = |alpha: usize, mu: usize, nu: usize| dg[alpha][mu * DIMENSION + nu];
+
+    Some(Tensor3::from_fn(
+        [Upper, Lower, Lower],
+        |[lambda, mu, nu]| {
+            (0..DIMENSION).fold(0f64, |sum, sigma| {
+                sum + inverse.get([lambda, sigma])
+                    * (d(mu, sigma, nu) + d(nu, sigma, mu) - d(sigma, mu, nu))
+            }) / 2f64
+        },
+    ))
+}
+
+/// # Riemann Tensor
+/// Calculates the Riemann curvature tensor R^ρ_σμν at a point
+///
+/// ## Variables
+/// ### Parameters
+/// - metric (g_μν(x)) = The covariant metric as a function of the coordinates
+/// - point (x) = The coordinates
+/// - step (h) = The step of the numerical derivatives
+/// ### Returns
+/// - Riemann Tensor (R^ρ_σμν), indices [Upper, Lower, Lower, Lower]. `None` if the metric is singular at or next
+///   to the point
+///
+/// ## Related Functions
+/// `ricci` => The contraction R_σν = R^ρ_σρν
+/// `kretschmann_scalar` => The square of the Riemann tensor
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Riemann_curvature_tensor>
+pub fn riemann(metric: impl Fn([f64; 4]) -> Mat4, point: [f64; 4], step: f64) -> Option<Tensor4> {
+    let gamma = christoffel(&metric, point, step)?;
+    let components = |x: [f64; 4]| Some(christoffel(&metric, x, step)?.components);
+    // dgamma[α] = ∂_α Γ
+    let mut dgamma = [[0f64; 64]; DIMENSION];
+    for (axis, derivative) in dgamma.iter_mut().enumerate() {
+        *derivative = partial(&components, point, axis, step)?;
+    }
+    let d = |alpha: usize, index: [usize; 3]| {
+        let [a, b, c] = index;
+        dgamma[alpha][(a * DIMENSION + b) * DIMENSION + c]
+    };
+
+    Some(Tensor4::from_fn(
+        [Upper, Lower, Lower, Lower],
+        |[rho, sigma, mu, nu]| {
+            let products = (0..DIMENSION).fold(0f64, |sum, lambda| {
+                sum + gamma.get([rho, mu, lambda]) * gamma.get([lambda, nu, sigma])
+                    - gamma.get([rho, nu, lambda]) * gamma.get([lambda, mu, sigma])
+            });
+            d(mu, [rho, nu, sigma]) - d(nu, [rho, mu, sigma]) + products
+        },
+    ))
+}
+
+/// # Ricci Tensor
+/// Calculates the Ricci tensor R_σν = R^ρ_σρν at a point. It is 0 in vacuum
+///
+/// ## Variables
+/// ### Parameters
+/// - metric (g_μν(x)) = The covariant metric as a function of the coordinates
+/// - point (x) = The coordinates
+/// - step (h) = The step of the numerical derivatives
+/// ### Returns
+/// - Ricci Tensor (R_σν), indices [Lower, Lower]. `None` if the metric is singular at or next to the point
+///
+/// ## Related Functions
+/// `ricci_scalar` => The trace R = g^σν R_σν
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Ricci_curvature>
+pub fn ricci(metric: impl Fn([f64; 4]) -> Mat4, point: [f64; 4], step: f64) -> Option<Tensor2> {
+    Some(riemann(metric, point, step)?.contract::<2, 16>(0, 2))
+}
+
+/// # Ricci Scalar
+/// Calculates the scalar curvature R = g^σν R_σν at a point
+///
+/// ## Variables
+/// ### Parameters
+/// - metric (g_μν(x)) = The covariant metric as a function of the coordinates
+/// - point (x) = The coordinates
+/// - step (h) = The step of the numerical derivatives
+/// ### Returns
+/// - Ricci Scalar (R :: 1/m^2), `None` if the metric is singular at or next to the point
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Scalar_curvature>
+pub fn ricci_scalar(metric: impl Fn([f64; 4]) -> Mat4, point: [f64; 4], step: f64) -> Option<f64> {
+    let g = MetricTensor::new(metric(point))?;
+    Some(ricci(metric, point, step)?.contract_all(g.inverse_tensor()))
+}
+
+/// # Kretschmann Scalar
+/// Calculates K = R_ρσμν R^ρσμν at a point, which does not depend on the coordinates.
+/// Unlike the Ricci scalar, it is not 0 in vacuum
+///
+/// ## Variables
+/// ### Parameters
+/// - metric (g_μν(x)) = The covariant metric as a function of the coordinates
+/// - point (x) = The coordinates
+/// - step (h) = The step of the numerical derivatives
+/// ### Returns
+/// - Kretschmann Scalar (K :: 1/m^4), `None` if the metric is singular at or next to the point
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kretschmann_scalar>
+pub fn kretschmann_scalar(
+    metric: impl Fn([f64; 4]) -> Mat4,
+    point: [f64; 4],
+    step: f64,
+) -> Option<f64> {
+    let g = MetricTensor::new(metric(point))?;
+    let riemann = riemann(metric, point, step)?;
+    let lower = riemann.lower(0, &g);
+    let upper = riemann.raise(1, &g).raise(2, &g).raise(3, &g);
+    Some(lower.contract_all(upper))
+}
+
+/// ∂f/∂x^axis with a fourth-order central difference, `None` if `f` fails at a sampled point
+fn partial<const N: usize>(
+    f: &impl Fn([f64; 4]) -> Option<[f64; N]>,
+    point: [f64; 4],
+    axis: usize,
+    step: f64,
+) -> Option<[f64; N]> {
+    let at = |offset: f64| {
+        let mut x = point;
+        x[axis] += offset * step;
+        f(x)
+    };
+    let (a, b, c, d) = (at(2f64)?, at(1f64)?, at(-1f64)?, at(-2f64)?);
+    Some(core::array::from_fn(|i| {
+        (-a[i] + 8f64 * b[i] - 8f64 * c[i] + d[i]) / (12f64 * step)
+    }))
+}
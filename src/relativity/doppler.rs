@@ -0,0 +1,184 @@
+//! # Relativistic Doppler Effect and Aberration
+//! Light from a moving source is shifted in frequency. Unlike the classical Doppler effect, the relativistic
+//! one also shifts light from a source that moves perpendicular to the line of sight, as the clock of the source
+//! runs slower (time dilation).
+//!
+//! ## Longitudinal Doppler effect
+//! For a source that moves away from the observer with β = v / c:
+//!
+//! fₒ = fₛ SquareRoot((1 - β) / (1 + β))
+//!
+//! ## Redshift
+//! Astronomers describe the shift with the redshift z = λₒ / λₛ - 1 = fₛ / fₒ - 1, which is positive for
+//! receding sources.
+//!
+//! ## Aberration
+//! A moving observer sees light coming from further ahead than an observer at rest. For a source at the angle θ
+//! to the direction of motion:
+//!
+//! cos(θ') = (cos(θ) + β) / (1 + β cos(θ))
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Relativistic_Doppler_effect>
+//! <https://en.wikipedia.org/wiki/Aberration_(astronomy)>
+
+use super::kinematics::{crapidity, svelocity_addition_vec3};
+use super::lorentz::slorentz;
+use crate::consts::SPEED_OF_LIGHT;
+use crate::prelude::*;
+use crate::vectors::{vec3::Vec3, GenericVector};
+
+/// # Simple Doppler
+/// Calculates the observed frequency of a source that moves along the line of sight
+///
+/// ## Variables
+/// ### Parameters
+/// - frequency (fₛ :: Hz) = The frequency in the rest frame of the source
+/// - speed (v :: m/s) = The radial speed, positive if the source moves away from the observer
+/// ### Returns
+/// - Observed Frequency (fₒ :: Hz)
+///
+/// ## Examples
+/// ```rs
+/// let observed = sdoppler(1f64, SPEED_OF_LIGHT * 0.6); // -> 0.5 Hz
+/// ```
+///
+/// ## Related Functions
+/// `cdoppler` => Complex version, for sources moving in any direction
+/// `sredshift` => The shift as a redshift
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_Doppler_effect#Relativistic_longitudinal_Doppler_effect>
+pub fn sdoppler<T: Real>(frequency: T, speed: T) -> T {
+    frequency / (T::ONE + sredshift(speed))
+}
+
+/// # Simple Transverse Doppler
+/// Calculates the observed frequency of a source that moves perpendicular to the line of sight, as seen by the observer.
+/// The light is always redshifted by time dilation, fₒ = fₛ / γ
+///
+/// ## Variables
+/// ### Parameters
+/// - frequency (fₛ :: Hz) = The frequency in the rest frame of the source
+/// - speed (v :: m/s) = The speed of the source
+/// ### Returns
+/// - Observed Frequency (fₒ :: Hz)
+///
+/// ## Related Functions
+/// `cdoppler` => Complex version, for sources moving in any direction
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_Doppler_effect#Transverse_Doppler_effect>
+pub fn sdoppler_transverse<T: Real>(frequency: T, speed: T) -> T {
+    frequency / slorentz(speed)
+}
+
+/// # Complex Doppler
+/// Calculates the observed frequency of a source moving in any direction, fₒ = fₛ / (γ(1 - β cos(θ)))
+///
+/// ## Variables
+/// ### Parameters
+/// - frequency (fₛ :: Hz) = The frequency in the rest frame of the source
+/// - speed (v :: m/s) = The speed of the source
+/// - angle (θ :: rad) = The angle between the velocity of the source and the direction from the source to the observer,
+///   measured by the observer when the light is received. 0 for an approaching source
+/// ### Returns
+/// - Observed Frequency (fₒ :: Hz)
+///
+/// ## Related Functions
+/// `sdoppler` => Simple version, for sources moving along the line of sight
+/// `sdoppler_transverse` => Simple version, for θ = 90°
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_Doppler_effect#Motion_in_an_arbitrary_direction>
+pub fn cdoppler<T: Real>(frequency: T, speed: T, angle: T) -> T {
+    frequency / (slorentz(speed) * (T::ONE - speed / T::from_f64(SPEED_OF_LIGHT) * angle.cos()))
+}
+
+/// # Simple Redshift
+/// Calculates the redshift of a source that moves along the line of sight, z = SquareRoot((1 + β) / (1 - β)) - 1.
+/// Evaluated as z = e^φ - 1 with the rapidity φ, which is accurate for slow sources as well
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s) = The radial speed, positive if the source moves away from the observer
+/// ### Returns
+/// - Redshift (z :: 1), negative for a blueshift
+///
+/// ## Related Functions
+/// `sspeed_from_redshift` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Redshift#Redshift_formulae>
+pub fn sredshift<T: Real>(speed: T) -> T {
+    crapidity(speed / T::from_f64(SPEED_OF_LIGHT)).exp_m1()
+}
+
+/// # Simple Speed (Redshift)
+/// Calculates the radial speed of a source from its redshift, β = ((1 + z)^2 - 1) / ((1 + z)^2 + 1)
+///
+/// Only kinematic redshifts are handled, the cosmological redshift of distant galaxies is caused by the expansion
+/// of space and needs a cosmological model
+///
+/// ## Variables
+/// ### Parameters
+/// - redshift (z :: 1)
+/// ### Returns
+/// - Speed (v :: m/s), positive if the source moves away from the observer
+///
+/// ## Related Functions
+/// `sredshift` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Redshift#Redshift_formulae>
+pub fn sspeed_from_redshift<T: Real>(redshift: T) -> T {
+    redshift.ln_1p().tanh() * T::from_f64(SPEED_OF_LIGHT)
+}
+
+/// # Simple Aberration
+/// Calculates the angle at which a moving observer sees a source, cos(θ') = (cos(θ) + β) / (1 + β cos(θ))
+///
+/// ## Variables
+/// ### Parameters
+/// - angle (θ :: rad) = The angle between the direction of motion and the direction to the source, for an observer at rest
+/// - speed (v :: m/s) = The speed of the observer
+/// ### Returns
+/// - Apparent Angle (θ' :: rad)
+///
+/// ## Related Functions
+/// `saberration_vec3` => Vector form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_aberration>
+pub fn saberration<T: Real>(angle: T, speed: T) -> T {
+    let beta = speed / T::from_f64(SPEED_OF_LIGHT);
+    let cos = angle.cos();
+    ((cos + beta) / (T::ONE + beta * cos)).acos()
+}
+
+/// # Simple Aberration (Vector)
+/// Calculates the direction in which a moving observer sees a source
+///
+/// ## Variables
+/// ### Parameters
+/// - direction (n⃗ :: 1) = The direction to the source for an observer at rest, does not have to be normalized
+/// - velocity (v⃗ :: m/s) = The velocity of the observer
+/// ### Returns
+/// - Apparent Direction (n⃗' :: 1) = The normalized direction to the source for the moving observer
+///
+/// ## Examples
+/// ```rs
+/// // Stars seem to move towards the direction of flight
+/// let apparent = saberration_vec3(Vec3::up(), Vec3::new(SPEED_OF_LIGHT * 0.5, 0f64, 0f64));
+/// ```
+///
+/// ## Related Functions
+/// `saberration` => Scalar form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_aberration>
+pub fn saberration_vec3<T: Real>(direction: Vec3<T>, velocity: Vec3<T>) -> Vec3<T> {
+    // The light travels towards the observer, -n⃗. In the frame of the observer, the rest frame moves with -v⃗
+    let light = direction.normalize() * -T::from_f64(SPEED_OF_LIGHT);
+    -svelocity_addition_vec3(-velocity, light).normalize()
+}
@@ -1,7 +1,8 @@
-use crate::consts::SPEED_OF_LIGHT_SQUARED;
-
-use super::lorentz::slorentz;
+use super::error::{check_finite, check_mass, check_speed, RelativityError};
+use super::lorentz::slorentz_in;
 use crate::prelude::*;
+use crate::units::{Si, UnitSystem};
+
 /// # Simple Kinetic Energy
 /// Calculates the relativistic kinetic energy
 ///
@@ -25,8 +26,33 @@ use crate::prelude::*;
 ///
 /// ## Read more
 /// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
-pub fn skinetic_energy(speed: f64, mass: f64) -> f64 {
-    ckinetic_energy(speed, mass, slorentz(speed))
+pub fn skinetic_energy<T: Real>(speed: T, mass: T) -> T {
+    skinetic_energy_in::<Si, T>(speed, mass)
+}
+
+/// # Simple Kinetic Energy (Unit System)
+/// Calculates the relativistic kinetic energy in any unit system
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U)
+/// - mass (m :: U) = The mass of the body
+/// ### Returns
+/// - Kinetic Energy (KE :: U)
+///
+/// ## Examples
+/// ```rs
+/// // A proton with β = 0.6 in natural units
+/// let ev = skinetic_energy_in::<Natural, _>(0.6, 938.272e6); // -> 234.6 MeV
+/// ```
+///
+/// ## Related Functions
+/// `skinetic_energy` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+pub fn skinetic_energy_in<U: UnitSystem, T: Real>(speed: T, mass: T) -> T {
+    ckinetic_energy(speed, mass, slorentz_in::<U, T>(speed))
 }
 
 /// # Simple Kinetic Energy
@@ -47,11 +73,380 @@ pub fn skinetic_energy(speed: f64, mass: f64) -> f64 {
 /// let joules = ckinetic_energy(SPEED_OF_LIGHT, 1, slorentz(SPEED_OF_LIGHT))
 /// ```
 ///
+/// ## Numerical stability
+/// (γ - 1)mc^2 is evaluated as γ^2 / (γ + 1) mv^2, so γ - 1 is never formed and slow speeds
+/// do not round to 0
+///
 /// ## Related Functions
 /// `skinetic_energy` => The simple version, lorentz factor is emitted
 ///
 /// ## Read more
 /// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
-pub fn ckinetic_energy(speed: f64, mass: f64, lorentz: f64) -> f64 {
-    (lorentz - 1f64) * mass * speed.powi(2)
+pub fn ckinetic_energy<T: Real>(speed: T, mass: T, lorentz: T) -> T {
+    // (γ - 1)mc^2 = γ^2 / (γ + 1) mv^2, which does not cancel for small speeds
+    lorentz / (T::ONE + T::ONE / lorentz) * mass * speed.powi(2)
+}
+
+/// # Complex Kinetic Energy (Unit System)
+/// Calculates the relativistic kinetic energy with a custom lorentz factor in any unit system.
+/// γ^2 / (γ + 1) mv^2 does not contain c, so the result is the same as the one of `ckinetic_energy` for every `U`
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U) = The speed, in the unit of speed of `U` (a fraction of c for natural units)
+/// - mass (m :: U) = The mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Kinetic Energy (KE :: U)
+///
+/// ## Related Functions
+/// `ckinetic_energy` => The SI version
+/// `skinetic_energy_in` => Simple version, lorentz factor is emitted
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+pub fn ckinetic_energy_in<U: UnitSystem, T: Real>(speed: T, mass: T, lorentz: T) -> T {
+    ckinetic_energy(speed, mass, lorentz)
+}
+
+/// # Simple Kinetic Energy (Checked)
+/// Calculates the relativistic kinetic energy, validating the inputs first
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Kinetic Energy (KE :: J)
+///
+/// ## Example
+/// ```rs
+/// let joules = skinetic_energy_checked(SPEED_OF_LIGHT / 2f64, 1f64)?;
+/// ```
+///
+/// ## Related Functions
+/// `skinetic_energy` => Unchecked, faster version
+/// `skinetic_energy_checked_in` => Checked version in any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if |v| >= c
+/// - `NegativeMass` if m < 0
+/// - `NonFiniteInput` if any input is NaN or infinite
+pub fn skinetic_energy_checked<T: Real>(speed: T, mass: T) -> Result<T, RelativityError> {
+    skinetic_energy_checked_in::<Si, T>(speed, mass)
+}
+
+/// # Simple Kinetic Energy (Checked, Unit System)
+/// Calculates the relativistic kinetic energy in any unit system, validating the inputs first
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U) = The speed, in the unit of speed of `U` (a fraction of c for natural units)
+/// - mass (m :: U) = The mass of the body
+/// ### Returns
+/// - Kinetic Energy (KE :: U)
+///
+/// ## Example
+/// ```rs
+/// let electron_volts = skinetic_energy_checked_in::<Natural, _>(0.6, 510998.95)?; // -> 127749.74 eV
+/// ```
+///
+/// ## Related Functions
+/// `skinetic_energy_checked` => The SI version
+/// `skinetic_energy_in` => Unchecked, faster version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if |v| >= c
+/// - `NegativeMass` if m < 0
+/// - `NonFiniteInput` if any input is NaN or infinite
+pub fn skinetic_energy_checked_in<U: UnitSystem, T: Real>(
+    speed: T,
+    mass: T,
+) -> Result<T, RelativityError> {
+    check_speed::<U, T>(speed)?;
+    check_mass(mass)?;
+    Ok(skinetic_energy_in::<U, T>(speed, mass))
+}
+
+/// # Complex Kinetic Energy (Checked)
+/// Calculates the relativistic kinetic energy with a custom lorentz factor, validating the inputs first
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Kinetic Energy (KE :: J)
+///
+/// ## Related Functions
+/// `ckinetic_energy` => Unchecked, faster version
+/// `ckinetic_energy_checked_in` => Checked version in any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if |v| >= c
+/// - `NegativeMass` if m < 0
+/// - `NonFiniteInput` if any input is NaN or infinite
+pub fn ckinetic_energy_checked<T: Real>(
+    speed: T,
+    mass: T,
+    lorentz: T,
+) -> Result<T, RelativityError> {
+    ckinetic_energy_checked_in::<Si, T>(speed, mass, lorentz)
+}
+
+/// # Complex Kinetic Energy (Checked, Unit System)
+/// Calculates the relativistic kinetic energy with a custom lorentz factor in any unit system,
+/// validating the inputs first
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U) = The speed, in the unit of speed of `U` (a fraction of c for natural units)
+/// - mass (m :: U) = The mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Kinetic Energy (KE :: U)
+///
+/// ## Related Functions
+/// `ckinetic_energy_checked` => The SI version
+/// `ckinetic_energy_in` => Unchecked, faster version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if |v| >= c
+/// - `NegativeMass` if m < 0
+/// - `NonFiniteInput` if any input is NaN or infinite
+pub fn ckinetic_energy_checked_in<U: UnitSystem, T: Real>(
+    speed: T,
+    mass: T,
+    lorentz: T,
+) -> Result<T, RelativityError> {
+    check_finite(&[lorentz])?;
+    check_speed::<U, T>(speed)?;
+    check_mass(mass)?;
+    Ok(ckinetic_energy_in::<U, T>(speed, mass, lorentz))
+}
+
+/// # Simple Rest Energy
+/// Calculates the energy of a body at rest, E = mc^2
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The (rest) mass of the body
+/// ### Returns
+/// - Rest Energy (E₀ :: J)
+///
+/// ## Examples
+/// ```rs
+/// let joules = srest_energy(1f64); // -> 8.99e16 J
+/// ```
+///
+/// ## Related Functions
+/// `stotal_energy` => Energy of a moving body
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Mass%E2%80%93energy_equivalence>
+pub fn srest_energy<T: Real>(mass: T) -> T {
+    srest_energy_in::<Si, T>(mass)
+}
+
+/// # Simple Rest Energy (Unit System)
+/// Calculates the energy of a body at rest in any unit system, E = mc^2
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: U) = The (rest) mass of the body
+/// ### Returns
+/// - Rest Energy (E₀ :: U)
+///
+/// ## Examples
+/// ```rs
+/// let ergs = srest_energy_in::<Gaussian, _>(1f64); // 1 g -> 8.99e20 erg
+/// ```
+///
+/// ## Related Functions
+/// `srest_energy` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Mass%E2%80%93energy_equivalence>
+pub fn srest_energy_in<U: UnitSystem, T: Real>(mass: T) -> T {
+    mass * T::from_f64(U::SPEED_OF_LIGHT_SQUARED)
+}
+
+/// # Simple Total Energy
+/// Calculates the total energy of a moving body, E = γmc^2, the sum of its rest and kinetic energy
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The (rest) mass of the body
+/// ### Returns
+/// - Total Energy (E :: J)
+///
+/// ## Related Functions
+/// `ctotal_energy` => Complex version, takes the lorentz factor
+/// `senergy_from_momentum` => Total energy from the momentum, also works for massless particles
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn stotal_energy<T: Real>(speed: T, mass: T) -> T {
+    stotal_energy_in::<Si, T>(speed, mass)
+}
+
+/// # Simple Total Energy (Unit System)
+/// Calculates the total energy of a moving body in any unit system, E = γmc^2
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U)
+/// - mass (m :: U) = The (rest) mass of the body
+/// ### Returns
+/// - Total Energy (E :: U)
+///
+/// ## Related Functions
+/// `stotal_energy` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn stotal_energy_in<U: UnitSystem, T: Real>(speed: T, mass: T) -> T {
+    slorentz_in::<U, T>(speed) * srest_energy_in::<U, T>(mass)
+}
+
+/// # Complex Total Energy
+/// Calculates the total energy of a moving body, E = γmc^2
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: kg) = The (rest) mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Total Energy (E :: J)
+///
+/// ## Related Functions
+/// `stotal_energy` => Simple version, takes the speed
+/// `ctotal_energy_in` => In any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn ctotal_energy<T: Real>(mass: T, lorentz: T) -> T {
+    ctotal_energy_in::<Si, T>(mass, lorentz)
+}
+
+/// # Complex Total Energy (Unit System)
+/// Calculates the total energy of a moving body in any unit system, E = γmc^2. In natural units, this is E = γm
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (m :: U) = The (rest) mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Total Energy (E :: U)
+///
+/// ## Examples
+/// ```rs
+/// let electron_volts = ctotal_energy_in::<Natural, _>(510998.95, 1.25); // -> 638748.69 eV
+/// ```
+///
+/// ## Related Functions
+/// `ctotal_energy` => The SI version
+/// `stotal_energy_in` => Simple version, takes the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn ctotal_energy_in<U: UnitSystem, T: Real>(mass: T, lorentz: T) -> T {
+    lorentz * srest_energy_in::<U, T>(mass)
+}
+
+/// # Simple Energy (Momentum)
+/// Calculates the total energy from the momentum with the energy–momentum relation, E^2 = (pc)^2 + (mc^2)^2.
+/// For massless particles (m = 0) like photons, this is E = pc
+///
+/// ## Variables
+/// ### Parameters
+/// - momentum (p :: kg·m/s) = The magnitude of the momentum
+/// - mass (m :: kg) = The (rest) mass, may be 0
+/// ### Returns
+/// - Total Energy (E :: J)
+///
+/// ## Related Functions
+/// `smass_from_energy_momentum` => The inverse, solved for the mass
+/// `momentum::smomentum_from_energy` => The inverse, solved for the momentum
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn senergy_from_momentum<T: Real>(momentum: T, mass: T) -> T {
+    senergy_from_momentum_in::<Si, T>(momentum, mass)
+}
+
+/// # Simple Energy (Momentum, Unit System)
+/// Calculates the total energy from the momentum in any unit system, E^2 = (pc)^2 + (mc^2)^2.
+/// In natural units, this is E^2 = p^2 + m^2
+///
+/// ## Variables
+/// ### Parameters
+/// - momentum (p :: U) = The magnitude of the momentum
+/// - mass (m :: U) = The (rest) mass, may be 0
+/// ### Returns
+/// - Total Energy (E :: U)
+///
+/// ## Related Functions
+/// `senergy_from_momentum` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn senergy_from_momentum_in<U: UnitSystem, T: Real>(momentum: T, mass: T) -> T {
+    (momentum * T::from_f64(U::SPEED_OF_LIGHT)).hypot(srest_energy_in::<U, T>(mass))
+}
+
+/// # Simple Mass (Energy & Momentum)
+/// Calculates the (rest) mass from the total energy and the momentum, m = SquareRoot(E^2 - (pc)^2) / c^2.
+/// This is the invariant mass, which is the same for all observers
+///
+/// ## Variables
+/// ### Parameters
+/// - energy (E :: J) = The total energy
+/// - momentum (p :: kg·m/s) = The magnitude of the momentum
+/// ### Returns
+/// - Mass (m :: kg)
+///
+/// ## Related Functions
+/// `senergy_from_momentum` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Invariant_mass>
+pub fn smass_from_energy_momentum<T: Real>(energy: T, momentum: T) -> T {
+    smass_from_energy_momentum_in::<Si, T>(energy, momentum)
+}
+
+/// # Simple Mass (Energy & Momentum, Unit System)
+/// Calculates the (rest) mass from the total energy and the momentum in any unit system,
+/// m = SquareRoot(E^2 - (pc)^2) / c^2
+///
+/// ## Variables
+/// ### Parameters
+/// - energy (E :: U) = The total energy
+/// - momentum (p :: U) = The magnitude of the momentum
+/// ### Returns
+/// - Mass (m :: U)
+///
+/// ## Related Functions
+/// `smass_from_energy_momentum` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Invariant_mass>
+pub fn smass_from_energy_momentum_in<U: UnitSystem, T: Real>(energy: T, momentum: T) -> T {
+    let pc = momentum * T::from_f64(U::SPEED_OF_LIGHT);
+    // Factored to avoid the cancellation of E^2 - (pc)^2 for light particles
+    ((energy - pc) * (energy + pc)).sqrt() / T::from_f64(U::SPEED_OF_LIGHT_SQUARED)
 }
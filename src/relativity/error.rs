@@ -0,0 +1,66 @@
+//! # Relativity Errors
+//! The functions of the relativity module are written for speed, so they do not validate their inputs.
+//! Invalid inputs silently produce NaN or ∞, e.g. the lorentz factor of a body faster than light is NaN,
+//! as `1 - v^2 / c^2` is negative.
+//!
+//! The `_checked` variants of those functions validate their inputs first and return a [`RelativityError`] instead.
+
+use crate::prelude::Real;
+use crate::units::UnitSystem;
+
+/// # Relativity Error
+/// Reasons why a checked relativity function could not calculate a result
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelativityError {
+    /// The speed is equal to or greater than the speed of light.
+    /// Bodies with mass can never reach the speed of light, see [`crate::consts::SPEED_OF_LIGHT`]
+    SuperluminalSpeed,
+    /// The mass is negative
+    NegativeMass,
+    /// One of the inputs is NaN or infinite
+    NonFiniteInput,
+    /// The spin of a Kerr black hole is greater than its mass (|a| > M), so there is no horizon
+    NakedSingularity,
+}
+
+impl core::fmt::Display for RelativityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            RelativityError::SuperluminalSpeed => {
+                "speed is equal to or greater than the speed of light"
+            }
+            RelativityError::NegativeMass => "mass is negative",
+            RelativityError::NonFiniteInput => "input is NaN or infinite",
+            RelativityError::NakedSingularity => {
+                "spin is greater than the mass, there is no horizon"
+            }
+        })
+    }
+}
+
+/// Fails if any of the values is NaN or infinite
+pub(crate) fn check_finite<T: Real>(values: &[T]) -> Result<(), RelativityError> {
+    if values.iter().all(|value| value.is_finite()) {
+        Ok(())
+    } else {
+        Err(RelativityError::NonFiniteInput)
+    }
+}
+
+/// Fails if the speed is not finite or |v| >= c, with c in the unit system `U`
+pub(crate) fn check_speed<U: UnitSystem, T: Real>(speed: T) -> Result<(), RelativityError> {
+    check_finite(&[speed])?;
+    if speed.abs() >= T::from_f64(U::SPEED_OF_LIGHT) {
+        return Err(RelativityError::SuperluminalSpeed);
+    }
+    Ok(())
+}
+
+/// Fails if the mass is not finite or negative
+pub(crate) fn check_mass<T: Real>(mass: T) -> Result<(), RelativityError> {
+    check_finite(&[mass])?;
+    if mass < T::ZERO {
+        return Err(RelativityError::NegativeMass);
+    }
+    Ok(())
+}
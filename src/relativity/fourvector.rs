@@ -0,0 +1,224 @@
+//! # Four-Vectors
+//! In special relativity, time and space are combined into a four-dimensional spacetime.
+//! A point in spacetime (an event) is described by a four-vector (ct, x, y, z). The time component is
+//! multiplied by c, so that all components have the same unit. Other physical quantities form four-vectors
+//! as well, for example the four-momentum (E/c, px, py, pz).
+//!
+//! ## Minkowski inner product
+//! Different observers disagree on the components of a four-vector, but they all agree on the Minkowski
+//! inner product of two four-vectors:
+//!
+//! a·b = a₀b₀ - a₁b₁ - a₂b₂ - a₃b₃
+//!
+//! The sign convention is not uniform in the literature. Particle physicists usually use (+,-,-,-), where
+//! time-like vectors have a positive square, while relativists usually use (-,+,+,+). See [`MetricSignature`].
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Four-vector>
+
+use crate::consts::SPEED_OF_LIGHT;
+#[cfg_attr(any(feature = "std", test), allow(unused_imports))]
+use crate::prelude::*;
+use crate::vectors::{vec3::Vec3, vec4::Vec4};
+
+/// # Metric Signature
+/// The sign convention of the Minkowski inner product
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricSignature {
+    /// (+,-,-,-), time-like vectors have a positive square. Common in particle physics
+    MostlyMinus,
+    /// (-,+,+,+), space-like vectors have a positive square. Common in general relativity
+    MostlyPlus,
+}
+
+impl MetricSignature {
+    /// # MetricSignature.diagonal
+    /// The diagonal of the metric tensor η in the order (t, x, y, z)
+    pub fn diagonal(self) -> [f64; 4] {
+        match self {
+            MetricSignature::MostlyMinus => [1f64, -1f64, -1f64, -1f64],
+            MetricSignature::MostlyPlus => [-1f64, 1f64, 1f64, 1f64],
+        }
+    }
+}
+
+/// # Interval Kind
+/// The causal character of a four-vector (or of the separation between two events)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalKind {
+    /// The events can be connected by a body slower than light, their order in time is the same for all observers
+    TimeLike,
+    /// The events can only be connected by light
+    LightLike,
+    /// The events cannot influence each other, observers disagree on their order in time
+    SpaceLike,
+}
+
+/// # Four-Vector
+/// A vector (t, x, y, z) in Minkowski spacetime. The time component has the same unit as the spatial ones,
+/// for events it is ct (in m)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FourVector {
+    pub t: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl FourVector {
+    /// # FourVector.new
+    /// Creates a four-vector (t, x, y, z)
+    pub fn new(t: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { t, x, y, z }
+    }
+
+    /// # FourVector.zero
+    /// The zero four-vector, e.g. the origin of spacetime
+    pub fn zero() -> Self {
+        Self::new(0f64, 0f64, 0f64, 0f64)
+    }
+
+    /// # FourVector.from_time_spatial
+    /// Creates a four-vector from its time component and its spatial part
+    pub fn from_time_spatial(t: f64, spatial: Vec3) -> Self {
+        Self::new(t, spatial.x, spatial.y, spatial.z)
+    }
+
+    /// # FourVector.from_event
+    /// Creates the four-vector (ct, x, y, z) of an event at a time (in s) and a position (in m)
+    ///
+    /// ## Example
+    /// ```rs
+    /// let event = FourVector::from_event(1f64, Vec3::new(SPEED_OF_LIGHT, 0f64, 0f64)); // -> (c, c, 0, 0)
+    /// ```
+    pub fn from_event(time: f64, position: Vec3) -> Self {
+        Self::from_time_spatial(time * SPEED_OF_LIGHT, position)
+    }
+
+    /// # FourVector.time
+    /// The time of an event (in s), the time component divided by c
+    pub fn time(self) -> f64 {
+        self.t / SPEED_OF_LIGHT
+    }
+
+    /// # FourVector.spatial
+    /// The spatial part (x, y, z)
+    pub fn spatial(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// # FourVector.components
+    /// The components in the order (t, x, y, z)
+    pub fn components(self) -> [f64; 4] {
+        [self.t, self.x, self.y, self.z]
+    }
+
+    /// # FourVector.minkowski_dot
+    /// The Minkowski inner product, which is the same for all inertial observers
+    ///
+    /// ## Example
+    /// ```rs
+    /// let squared_mass = p.minkowski_dot(p, MetricSignature::MostlyMinus); // (mc)^2 for a four-momentum
+    /// ```
+    pub fn minkowski_dot(self, other: Self, signature: MetricSignature) -> f64 {
+        let [eta_t, eta_s, _, _] = signature.diagonal();
+        eta_t * self.t * other.t + eta_s * (self.x * other.x + self.y * other.y + self.z * other.z)
+    }
+
+    /// # FourVector.interval
+    /// The spacetime interval s^2, the Minkowski inner product of the vector with itself
+    pub fn interval(self, signature: MetricSignature) -> f64 {
+        self.minkowski_dot(self, signature)
+    }
+
+    /// # FourVector.kind
+    /// Whether the vector is time-like, light-like or space-like. A vector counts as light-like
+    /// if its interval is within `tolerance` of 0, relative to the square of its largest component.
+    /// The result does not depend on the metric signature
+    pub fn kind(self, tolerance: f64) -> IntervalKind {
+        let interval = self.interval(MetricSignature::MostlyMinus);
+        let scale = self
+            .components()
+            .iter()
+            .fold(0f64, |max, component| max.max(component.abs()))
+            .powi(2);
+
+        if interval.abs() <= tolerance * scale {
+            IntervalKind::LightLike
+        } else if interval > 0f64 {
+            IntervalKind::TimeLike
+        } else {
+            IntervalKind::SpaceLike
+        }
+    }
+}
+
+impl From<[f64; 4]> for FourVector {
+    fn from([t, x, y, z]: [f64; 4]) -> Self {
+        Self::new(t, x, y, z)
+    }
+}
+
+impl From<FourVector> for [f64; 4] {
+    fn from(v: FourVector) -> Self {
+        v.components()
+    }
+}
+
+/// The components (t, x, y, z) become (x, y, z, w), so four-vectors can be multiplied with a [`crate::matrices::mat4::Mat4`]
+impl From<FourVector> for Vec4 {
+    fn from(v: FourVector) -> Self {
+        Vec4::new(v.t, v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec4> for FourVector {
+    fn from(v: Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl core::ops::Add<FourVector> for FourVector {
+    type Output = Self;
+    fn add(self, rhs: FourVector) -> Self {
+        Self::new(
+            self.t + rhs.t,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl core::ops::Sub<FourVector> for FourVector {
+    type Output = Self;
+    fn sub(self, rhs: FourVector) -> Self {
+        Self::new(
+            self.t - rhs.t,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+impl core::ops::Mul<f64> for FourVector {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.t * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl core::ops::Div<f64> for FourVector {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.t / rhs, self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl core::ops::Neg for FourVector {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self * -1f64
+    }
+}
@@ -0,0 +1,314 @@
+//! # Geodesics
+//! Without forces other than gravity, test particles and light rays follow geodesics, the straightest possible
+//! paths through curved spacetime. This file traces geodesics around non-rotating (Schwarzschild) and
+//! rotating (Kerr) black holes.
+//!
+//! ## Coordinates and units
+//! Both metrics use Boyer-Lindquist coordinates (ct, r, θ, φ), which are stored in the components (t, x, y, z) of a
+//! [`FourVector`]. The mass and the spin are given as lengths:
+//!
+//! - M = GM / c^2 (m), half the Schwarzschild radius
+//! - a = J / (Mc) (m), between -M and M. [`Geodesic::new`] rejects larger spins, which have no horizon
+//!
+//! For a massive particle, the four-velocity is dx^μ/dτ with the proper time τ measured in meters (cτ),
+//! so that g_μν u^μ u^ν = -1. For light, any affine parameter can be used.
+//!
+//! ## Integration
+//! The geodesics are integrated as a Hamiltonian system, H = ½ g^μν p_μ p_ν, with a fixed step
+//! fourth-order Runge-Kutta method. Since the metrics do not depend on t and φ, the energy E = -p_t and the
+//! angular momentum L = p_φ are conserved exactly. H (the mass shell) and the Carter constant Q are conserved
+//! up to the integration error, which makes them useful to check the step size.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Geodesics_in_general_relativity>
+//! <https://en.wikipedia.org/wiki/Kerr_metric>
+//! <https://en.wikipedia.org/wiki/Carter_constant>
+
+use super::error::{check_finite, check_mass, RelativityError};
+use super::fourvector::FourVector;
+use crate::matrices::mat4::Mat4;
+#[cfg_attr(any(feature = "std", test), allow(unused_imports))]
+use crate::prelude::*;
+use crate::vectors::{vec4::Vec4, vecn::VecN};
+
+/// The iteration stops once r is closer than this fraction to the outer horizon,
+/// where Boyer-Lindquist coordinates become singular
+const HORIZON_MARGIN: f64 = 1e-3;
+
+/// # Metric
+/// A black hole spacetime. All lengths are in meters, see the [module documentation](self)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric {
+    /// A non-rotating black hole with the mass M = GM / c^2
+    Schwarzschild { mass: f64 },
+    /// A rotating black hole with the mass M = GM / c^2 and the spin a = J / (Mc)
+    Kerr { mass: f64, spin: f64 },
+}
+
+impl Metric {
+    /// # Metric.mass
+    /// The mass M = GM / c^2 (m)
+    pub fn mass(self) -> f64 {
+        match self {
+            Metric::Schwarzschild { mass } | Metric::Kerr { mass, .. } => mass,
+        }
+    }
+
+    /// # Metric.spin
+    /// The spin a = J / (Mc) (m), 0 for Schwarzschild
+    pub fn spin(self) -> f64 {
+        match self {
+            Metric::Schwarzschild { .. } => 0f64,
+            Metric::Kerr { spin, .. } => spin,
+        }
+    }
+
+    /// # Metric.horizon_radius
+    /// The radius of the outer event horizon, r = M + SquareRoot(M^2 - a^2).
+    /// NaN for |a| > M, where the singularity has no horizon
+    pub fn horizon_radius(self) -> f64 {
+        let (m, a) = (self.mass(), self.spin());
+        m + (m * m - a * a).sqrt()
+    }
+
+    /// # Metric.covariant
+    /// The metric tensor g_μν at the radius r and the polar angle θ, in the order (t, r, θ, φ)
+    pub fn covariant(self, r: f64, theta: f64) -> Mat4 {
+        let (m, a) = (self.mass(), self.spin());
+        let sin2 = theta.sin().powi(2);
+        let sigma = r * r + a * a * theta.cos().powi(2);
+        let delta = r * r - 2f64 * m * r + a * a;
+
+        let g_tt = -(1f64 - 2f64 * m * r / sigma);
+        let g_tphi = -2f64 * m * a * r * sin2 / sigma;
+        let g_phiphi = (r * r + a * a + 2f64 * m * a * a * r * sin2 / sigma) * sin2;
+        Mat4::from_rows(
+            Vec4::new(g_tt, 0f64, 0f64, g_tphi),
+            Vec4::new(0f64, sigma / delta, 0f64, 0f64),
+            Vec4::new(0f64, 0f64, sigma, 0f64),
+            Vec4::new(g_tphi, 0f64, 0f64, g_phiphi),
+        )
+    }
+
+    /// # Metric.lower
+    /// Converts a four-velocity u^μ at a position into the covariant momentum p_μ = g_μν u^ν (per unit mass)
+    pub fn lower(self, position: FourVector, velocity: FourVector) -> FourVector {
+        FourVector::from(self.covariant(position.x, position.y) * Vec4::from(velocity))
+    }
+
+    /// # Metric.hamiltonian
+    /// H = ½ g^μν p_μ p_ν, which is -½ for massive particles (with the proper time as parameter) and 0 for light
+    pub fn hamiltonian(self, position: FourVector, momentum: FourVector) -> f64 {
+        let terms = self.terms(position.x, position.y, momentum);
+        (terms.radial + terms.polar) / (2f64 * terms.sigma)
+    }
+
+    /// # Metric.carter_constant
+    /// The Carter constant Q = p_θ^2 + cos^2(θ) (a^2 (μ^2 - E^2) + L^2 / sin^2(θ)), where μ^2 = -2H.
+    /// It is 0 for orbits in the equatorial plane
+    pub fn carter_constant(self, position: FourVector, momentum: FourVector) -> f64 {
+        let theta = position.y;
+        let (energy, angular_momentum) = (-momentum.t, momentum.z);
+        let mass_squared = -2f64 * self.hamiltonian(position, momentum);
+
+        momentum.y.powi(2)
+            + theta.cos().powi(2)
+                * (self.spin().powi(2) * (mass_squared - energy.powi(2))
+                    + angular_momentum.powi(2) / theta.sin().powi(2))
+    }
+
+    /// The separated parts of 2ΣH = A(r, p_r) + B(θ, p_θ) and their derivatives
+    fn terms(self, r: f64, theta: f64, p: FourVector) -> Terms {
+        let (m, a) = (self.mass(), self.spin());
+        let (energy, angular_momentum) = (-p.t, p.z);
+        let (sin, cos) = (theta.sin(), theta.cos());
+        let sin2 = sin * sin;
+
+        let sigma = r * r + a * a * cos * cos;
+        let delta = r * r - 2f64 * m * r + a * a;
+        let ddelta = 2f64 * r - 2f64 * m;
+        // P = (r^2 + a^2)E - aL and L - aE sin^2(θ)
+        let radial_potential = (r * r + a * a) * energy - a * angular_momentum;
+        let polar_potential = angular_momentum - a * energy * sin2;
+
+        Terms {
+            sigma,
+            delta,
+            radial_potential,
+            polar_potential,
+            radial: delta * p.x * p.x - radial_potential.powi(2) / delta,
+            polar: p.y * p.y + polar_potential.powi(2) / sin2,
+            dradial: ddelta * p.x * p.x
+                - (4f64 * r * energy * radial_potential * delta
+                    - radial_potential.powi(2) * ddelta)
+                    / delta.powi(2),
+            dpolar: -2f64 * cos * (angular_momentum.powi(2) - (a * energy).powi(2) * sin2 * sin2)
+                / (sin2 * sin),
+        }
+    }
+
+    /// The derivative of (t, r, θ, φ, p_t, p_r, p_θ, p_φ) by the affine parameter
+    fn derivative(self, y: VecN<8>) -> VecN<8> {
+        let [_, r, theta, _, p_t, p_r, p_theta, p_phi] = y.components;
+        let p = FourVector::new(p_t, p_r, p_theta, p_phi);
+        let a = self.spin();
+        let terms = self.terms(r, theta, p);
+        let (sigma, sin2) = (terms.sigma, theta.sin().powi(2));
+        let hamiltonian_sigma = (terms.radial + terms.polar) / (2f64 * sigma * sigma);
+
+        VecN::new([
+            ((r * r + a * a) * terms.radial_potential / terms.delta + a * terms.polar_potential)
+                / sigma,
+            terms.delta * p_r / sigma,
+            p_theta / sigma,
+            (a * terms.radial_potential / terms.delta + terms.polar_potential / sin2) / sigma,
+            0f64,
+            -terms.dradial / (2f64 * sigma) + hamiltonian_sigma * 2f64 * r,
+            -terms.dpolar / (2f64 * sigma)
+                - hamiltonian_sigma * 2f64 * a * a * theta.sin() * theta.cos(),
+            0f64,
+        ])
+    }
+}
+
+struct Terms {
+    sigma: f64,
+    delta: f64,
+    radial_potential: f64,
+    polar_potential: f64,
+    radial: f64,
+    polar: f64,
+    dradial: f64,
+    dpolar: f64,
+}
+
+/// # Geodesic State
+/// A point on a geodesic
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeodesicState {
+    /// λ :: m, the affine parameter (cτ for massive particles)
+    pub parameter: f64,
+    /// (ct, r, θ, φ) :: (m, m, rad, rad)
+    pub position: FourVector,
+    /// p_μ, the covariant momentum per unit mass
+    pub momentum: FourVector,
+}
+
+impl GeodesicState {
+    /// # GeodesicState.energy
+    /// The conserved energy per unit mass, E = -p_t (1 for a particle at rest far away)
+    pub fn energy(self) -> f64 {
+        -self.momentum.t
+    }
+
+    /// # GeodesicState.angular_momentum
+    /// The conserved angular momentum around the spin axis per unit mass, L = p_φ (m)
+    pub fn angular_momentum(self) -> f64 {
+        self.momentum.z
+    }
+}
+
+/// # Geodesic
+/// An iterator over the points of a geodesic, one per integration step. The iteration stops if the geodesic gets
+/// too close to (or would cross) the horizon, or if the integration breaks down
+///
+/// ## Example
+/// ```rs
+/// let metric = Metric::Kerr { mass: 1f64, spin: 0.9 };
+/// let position = FourVector::new(0f64, 10f64, FRAC_PI_2, 0f64);
+/// let velocity = FourVector::new(1.2, 0f64, 0.01, 0.03);
+/// for state in Geodesic::new(metric, position, velocity, 0.1)?.take(10_000) {
+///     // state.position ...
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Geodesic {
+    metric: Metric,
+    state: GeodesicState,
+    step: f64,
+}
+
+impl Geodesic {
+    /// # Geodesic.new
+    /// Starts a geodesic at a position (ct, r, θ, φ) with a four-velocity u^μ = dx^μ/dλ.
+    /// `step` is the step of the affine parameter, smaller steps are more accurate
+    ///
+    /// ## Errors
+    /// - [`RelativityError::NegativeMass`] if the mass is negative
+    /// - [`RelativityError::NonFiniteInput`] if the mass or the spin is NaN or infinite
+    /// - [`RelativityError::NakedSingularity`] if |a| > M. Without a horizon, nothing would stop the
+    ///   integration before the singularity
+    pub fn new(
+        metric: Metric,
+        position: FourVector,
+        velocity: FourVector,
+        step: f64,
+    ) -> Result<Self, RelativityError> {
+        check_mass(metric.mass())?;
+        check_finite(&[metric.spin()])?;
+        if metric.spin().abs() > metric.mass() {
+            return Err(RelativityError::NakedSingularity);
+        }
+
+        Ok(Self {
+            metric,
+            state: GeodesicState {
+                parameter: 0f64,
+                position,
+                momentum: metric.lower(position, velocity),
+            },
+            step,
+        })
+    }
+
+    /// # Geodesic.metric
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// # Geodesic.state
+    /// The current point of the geodesic
+    pub fn state(&self) -> GeodesicState {
+        self.state
+    }
+}
+
+impl Iterator for Geodesic {
+    type Item = GeodesicState;
+
+    fn next(&mut self) -> Option<GeodesicState> {
+        let GeodesicState {
+            parameter,
+            position,
+            momentum,
+        } = self.state;
+        if position.x <= self.metric.horizon_radius() * (1f64 + HORIZON_MARGIN) {
+            return None;
+        }
+
+        let mut y = VecN::new([0f64; 8]);
+        y.components[..4].copy_from_slice(&position.components());
+        y.components[4..].copy_from_slice(&momentum.components());
+
+        let h = self.step;
+        let k1 = self.metric.derivative(y);
+        let k2 = self.metric.derivative(y + k1 * (h / 2f64));
+        let k3 = self.metric.derivative(y + k2 * (h / 2f64));
+        let k4 = self.metric.derivative(y + k3 * h);
+        let y = y + (k1 + k2 * 2f64 + k3 * 2f64 + k4) * (h / 6f64);
+
+        if y.components.iter().any(|component| !component.is_finite())
+            || y.components[1] <= self.metric.horizon_radius()
+        {
+            return None;
+        }
+
+        let [t, r, theta, phi, p_t, p_r, p_theta, p_phi] = y.components;
+        self.state = GeodesicState {
+            parameter: parameter + h,
+            position: FourVector::new(t, r, theta, phi),
+            momentum: FourVector::new(p_t, p_r, p_theta, p_phi),
+        };
+        Some(self.state)
+    }
+}
@@ -0,0 +1,362 @@
+//! # Special Relativistic Kinematics
+//! The lorentz factor γ alone does not tell how the measurements of two observers differ.
+//! This file uses it to relate times, lengths, velocities and accelerations between inertial frames.
+//!
+//! ## Time dilation
+//! A moving clock ticks slower. If Δτ passes on the moving clock, an observer at rest measures
+//!
+//! Δt = γΔτ
+//!
+//! ## Length contraction
+//! A moving body is shorter along its direction of motion. A body with the proper length L₀ is measured as
+//!
+//! L = L₀ / γ
+//!
+//! ## Velocity addition
+//! Velocities do not simply add up, as the result could be faster than light. Two collinear velocities combine to
+//!
+//! w = (u + v) / (1 + uv / c^2)
+//!
+//! ## Rapidity
+//! The rapidity φ = artanh(β) is the hyperbolic angle of a boost. Unlike velocities,
+//! rapidities of collinear boosts simply add up. It is related to β and γ by
+//!
+//! β = tanh(φ), γ = cosh(φ), βγ = sinh(φ)
+//!
+//! ## Proper acceleration
+//! The acceleration felt by the body itself (e.g. measured with an accelerometer), in its momentary rest frame.
+//! For an acceleration parallel to the velocity, α = γ^3 a
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Special_relativity#Consequences_derived_from_the_Lorentz_transformation>
+
+use super::lorentz::slorentz;
+use crate::consts::{SPEED_OF_LIGHT, SPEED_OF_LIGHT_SQUARED};
+use crate::prelude::*;
+use crate::vectors::{vec3::Vec3, GenericVector};
+
+/// # Simple Time Dilation
+/// Calculates the time that passes for an observer at rest while a moving clock measures the proper time
+///
+/// ## Variables
+/// ### Parameters
+/// - ptime (Δτ :: s) = The proper time of the moving clock
+/// - speed (v :: m/s) = The speed of the clock
+/// ### Returns
+/// - Coordinate Time (Δt :: s)
+///
+/// ## Examples
+/// ```rs
+/// // A muon lives 2.2µs in its rest frame
+/// let lifetime = stime_dilation(2.2e-6, 0.998 * SPEED_OF_LIGHT);
+/// ```
+///
+/// ## Related Functions
+/// `ctime_dilation` => Complex version, takes the lorentz factor
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Time_dilation>
+pub fn stime_dilation<T: Real>(ptime: T, speed: T) -> T {
+    ctime_dilation(ptime, slorentz(speed))
+}
+
+/// # Complex Time Dilation
+/// Calculates the time that passes for an observer at rest while a moving clock measures the proper time
+///
+/// ## Variables
+/// ### Parameters
+/// - ptime (Δτ :: s) = The proper time of the moving clock
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Coordinate Time (Δt :: s)
+///
+/// ## Related Functions
+/// `stime_dilation` => Simple version, takes the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Time_dilation>
+pub fn ctime_dilation<T: Real>(ptime: T, lorentz: T) -> T {
+    ptime * lorentz
+}
+
+/// # Simple Length Contraction
+/// Calculates the length of a moving body along its direction of motion
+///
+/// ## Variables
+/// ### Parameters
+/// - plength (L₀ :: m) = The proper length, measured at rest
+/// - speed (v :: m/s) = The speed of the body
+/// ### Returns
+/// - Contracted Length (L :: m)
+///
+/// ## Examples
+/// ```rs
+/// let length = slength_contraction(100f64, SPEED_OF_LIGHT * 0.8); // -> 60m
+/// ```
+///
+/// ## Related Functions
+/// `clength_contraction` => Complex version, takes the lorentz factor
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Length_contraction>
+pub fn slength_contraction<T: Real>(plength: T, speed: T) -> T {
+    clength_contraction(plength, slorentz(speed))
+}
+
+/// # Complex Length Contraction
+/// Calculates the length of a moving body along its direction of motion
+///
+/// ## Variables
+/// ### Parameters
+/// - plength (L₀ :: m) = The proper length, measured at rest
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Contracted Length (L :: m)
+///
+/// ## Related Functions
+/// `slength_contraction` => Simple version, takes the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Length_contraction>
+pub fn clength_contraction<T: Real>(plength: T, lorentz: T) -> T {
+    plength / lorentz
+}
+
+/// # Simple Velocity Addition
+/// Combines two collinear velocities. If a frame moves with `frame` and a body moves with `speed`
+/// inside of that frame, this is the velocity of the body relative to the rest frame.
+/// Negative values point in the opposite direction
+///
+/// ## Variables
+/// ### Parameters
+/// - frame (v :: m/s) = The velocity of the moving frame
+/// - speed (u' :: m/s) = The velocity of the body in the moving frame
+/// ### Returns
+/// - Combined Velocity (u :: m/s)
+///
+/// ## Examples
+/// ```rs
+/// let combined = svelocity_addition(SPEED_OF_LIGHT / 2f64, SPEED_OF_LIGHT / 2f64); // -> 0.8c
+/// ```
+///
+/// ## Related Functions
+/// `svelocity_addition_vec3` => Vector form, for velocities in any direction
+/// `srapidity` => Rapidities of collinear velocities add up linearly
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Velocity-addition_formula>
+pub fn svelocity_addition<T: Real>(frame: T, speed: T) -> T {
+    (frame + speed) / (T::ONE + frame * speed / T::from_f64(SPEED_OF_LIGHT_SQUARED))
+}
+
+/// # Simple Velocity Addition (Vector)
+/// Combines two velocities in any direction. If a frame moves with `frame` and a body moves with
+/// `velocity` inside of that frame (whose axes are parallel to the rest frame), this is the velocity of
+/// the body relative to the rest frame.
+///
+/// u⃗ = (v⃗ + u⃗'/γ + (γ / (1 + γ)) (v⃗·u⃗' / c^2) v⃗) / (1 + v⃗·u⃗' / c^2)
+///
+/// The addition is not commutative for non-collinear velocities, the difference is a rotation (see Thomas-Wigner rotation)
+///
+/// ## Variables
+/// ### Parameters
+/// - frame (v⃗ :: m/s) = The velocity of the moving frame
+/// - velocity (u⃗' :: m/s) = The velocity of the body in the moving frame
+/// ### Returns
+/// - Combined Velocity (u⃗ :: m/s)
+///
+/// ## Related Functions
+/// `svelocity_addition` => Scalar form for collinear velocities
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Velocity-addition_formula#General_configuration>
+pub fn svelocity_addition_vec3<T: Real>(frame: Vec3<T>, velocity: Vec3<T>) -> Vec3<T> {
+    let lorentz = slorentz(frame.length());
+    let dot = (frame | velocity) / T::from_f64(SPEED_OF_LIGHT_SQUARED);
+
+    (frame + velocity / lorentz + frame * (lorentz / (T::ONE + lorentz) * dot)) / (T::ONE + dot)
+}
+
+/// # Simple Rapidity
+/// Calculates the rapidity (hyperbolic angle) of a speed
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// ### Returns
+/// - Rapidity (φ :: 1)
+///
+/// ## Examples
+/// ```rs
+/// // Collinear boosts add up
+/// let combined = sspeed_from_rapidity(srapidity(v1) + srapidity(v2));
+/// ```
+///
+/// ## Related Functions
+/// `crapidity` => Complex version, takes β
+/// `crapidity_from_lorentz` => Rapidity from the lorentz factor
+/// `sspeed_from_rapidity` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Rapidity>
+pub fn srapidity<T: Real>(speed: T) -> T {
+    crapidity(speed / T::from_f64(SPEED_OF_LIGHT))
+}
+
+/// # Complex Rapidity
+/// Calculates the rapidity (hyperbolic angle) from the ratio of v to c, φ = artanh(β)
+///
+/// ## Variables
+/// ### Parameters
+/// - beta (β :: 1) = The ratio of v to c
+/// ### Returns
+/// - Rapidity (φ :: 1)
+///
+/// ## Related Functions
+/// `srapidity` => Simple version, takes the speed
+/// `cbeta_from_rapidity` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Rapidity>
+pub fn crapidity<T: Real>(beta: T) -> T {
+    beta.atanh()
+}
+
+/// # Complex Rapidity (Lorentz)
+/// Calculates the (non-negative) rapidity from the lorentz factor, φ = arcosh(γ).
+/// The direction of motion is lost, as γ does not depend on it
+///
+/// ## Variables
+/// ### Parameters
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Rapidity (φ :: 1)
+///
+/// ## Related Functions
+/// `clorentz_from_rapidity` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Rapidity>
+pub fn crapidity_from_lorentz<T: Real>(lorentz: T) -> T {
+    lorentz.acosh()
+}
+
+/// # Simple Speed (Rapidity)
+/// Calculates the speed from a rapidity, v = c tanh(φ)
+///
+/// ## Variables
+/// ### Parameters
+/// - rapidity (φ :: 1)
+/// ### Returns
+/// - Speed (v :: m/s)
+///
+/// ## Related Functions
+/// `srapidity` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Rapidity>
+pub fn sspeed_from_rapidity<T: Real>(rapidity: T) -> T {
+    cbeta_from_rapidity(rapidity) * T::from_f64(SPEED_OF_LIGHT)
+}
+
+/// # Complex β (Rapidity)
+/// Calculates the ratio of v to c from a rapidity, β = tanh(φ)
+///
+/// ## Variables
+/// ### Parameters
+/// - rapidity (φ :: 1)
+/// ### Returns
+/// - Ratio of v to c (β :: 1)
+///
+/// ## Related Functions
+/// `crapidity` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Rapidity>
+pub fn cbeta_from_rapidity<T: Real>(rapidity: T) -> T {
+    rapidity.tanh()
+}
+
+/// # Complex Lorentz (Rapidity)
+/// Calculates the lorentz factor from a rapidity, γ = cosh(φ)
+///
+/// ## Variables
+/// ### Parameters
+/// - rapidity (φ :: 1)
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Related Functions
+/// `crapidity_from_lorentz` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Rapidity>
+pub fn clorentz_from_rapidity<T: Real>(rapidity: T) -> T {
+    rapidity.cosh()
+}
+
+/// # Simple Proper Acceleration
+/// Calculates the acceleration felt by a body that accelerates along its direction of motion, α = γ^3 a
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s) = The current speed of the body
+/// - accel (a :: m/s^2) = The coordinate acceleration, measured in the rest frame
+/// ### Returns
+/// - Proper Acceleration (α :: m/s^2)
+///
+/// ## Related Functions
+/// `cproper_acceleration` => Complex version, takes the lorentz factor
+/// `sproper_acceleration_vec3` => Vector form, for accelerations in any direction
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Proper_acceleration>
+pub fn sproper_acceleration<T: Real>(speed: T, accel: T) -> T {
+    cproper_acceleration(slorentz(speed), accel)
+}
+
+/// # Complex Proper Acceleration
+/// Calculates the acceleration felt by a body that accelerates along its direction of motion, α = γ^3 a
+///
+/// ## Variables
+/// ### Parameters
+/// - lorentz (γ :: γ) = The lorentz factor at the current speed
+/// - accel (a :: m/s^2) = The coordinate acceleration, measured in the rest frame
+/// ### Returns
+/// - Proper Acceleration (α :: m/s^2)
+///
+/// ## Related Functions
+/// `sproper_acceleration` => Simple version, takes the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Proper_acceleration>
+pub fn cproper_acceleration<T: Real>(lorentz: T, accel: T) -> T {
+    lorentz.powi(3) * accel
+}
+
+/// # Simple Proper Acceleration (Vector)
+/// Calculates the acceleration felt by a body in its momentary rest frame, for an acceleration in any direction
+///
+/// α⃗ = γ^2 (a⃗ + (γ^2 / (γ + 1)) (v⃗·a⃗ / c^2) v⃗)
+///
+/// The part parallel to the velocity is scaled by γ^3, the perpendicular part by γ^2
+///
+/// ## Variables
+/// ### Parameters
+/// - velocity (v⃗ :: m/s) = The current velocity of the body
+/// - accel (a⃗ :: m/s^2) = The coordinate acceleration, measured in the rest frame
+/// ### Returns
+/// - Proper Acceleration (α⃗ :: m/s^2)
+///
+/// ## Related Functions
+/// `sproper_acceleration` => Scalar form for accelerations along the velocity
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Proper_acceleration>
+pub fn sproper_acceleration_vec3<T: Real>(velocity: Vec3<T>, accel: Vec3<T>) -> Vec3<T> {
+    let lorentz = slorentz(velocity.length());
+    let lorentz_squared = lorentz.powi(2);
+    let dot = (velocity | accel) / T::from_f64(SPEED_OF_LIGHT_SQUARED);
+
+    (accel + velocity * (lorentz_squared / (lorentz + T::ONE) * dot)) * lorentz_squared
+}
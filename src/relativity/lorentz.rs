@@ -24,8 +24,15 @@
 //! Therefore,
 //!
 //! 1 / SquareRoot( 1 - (v^2 / c^2)) = γ
+//!
+//! ## Numerical stability
+//! Evaluated as written, 1 - v^2 / c^2 loses most of its digits close to c, and γ - 1 is rounded to 0
+//! for everyday speeds. The simple functions therefore use (c - v)(c + v) / c^2 for 1 - β^2, and
+//! [`slorentz_minus_one`] calculates γ - 1 without subtracting 1. Both stay accurate from 1 mm/s up to 0.999999c.
 
+use super::error::{check_finite, check_speed, RelativityError};
 use crate::prelude::*;
+use crate::units::{Si, UnitSystem};
 
 /// # Simple Lorentz
 /// Returns the lorentz factor at a given speed where deltatime = 1
@@ -48,8 +55,31 @@ use crate::prelude::*;
 ///
 /// ## Read more
 /// <https://en.wikipedia.org/wiki/Lorentz_factor>
-pub fn slorentz(speed: f64) -> f64 {
-    slorentzt(speed, 1f64)
+pub fn slorentz<T: Real>(speed: T) -> T {
+    slorentz_in::<Si, T>(speed)
+}
+
+/// # Simple Lorentz (Unit System)
+/// Returns the lorentz factor at a given speed in any unit system
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U) = The relative velocity, in the unit of speed of `U` (a fraction of c for natural units)
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Examples
+/// ```rs
+/// let lorentz = slorentz_in::<Natural, _>(0.6); // -> 1.25
+/// ```
+///
+/// ## Related Functions
+/// `slorentz` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+pub fn slorentz_in<U: UnitSystem, T: Real>(speed: T) -> T {
+    clorentz(T::ONE, inverse_lorentz::<U, T>(speed))
 }
 
 /// # Simple Lorentz
@@ -74,11 +104,65 @@ pub fn slorentz(speed: f64) -> f64 {
 ///
 /// ## Read more
 /// <https://en.wikipedia.org/wiki/Lorentz_factor>
-pub fn slorentzt(speed: f64, deltatime: f64) -> f64 {
-    slorentztr(
-        deltatime,
-        speed.powi(2) / crate::consts::SPEED_OF_LIGHT_SQUARED,
-    )
+pub fn slorentzt<T: Real>(speed: T, deltatime: T) -> T {
+    clorentz(deltatime, inverse_lorentz::<Si, T>(speed))
+}
+
+/// # Simple Lorentz Minus One
+/// Returns γ - 1 at a given speed, accurate even for speeds where γ rounds to 1
+///
+/// γ - 1 = β^2 / (SquareRoot(1 - β^2) (1 + SquareRoot(1 - β^2)))
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s) = The relative velocity between inertial reference frames
+/// ### Returns
+/// - Lorentz Factor minus one (γ - 1 :: γ)
+///
+/// ## Examples
+/// ```rs
+/// let tiny = slorentz_minus_one(1f64); // -> 5.56e-18, while slorentz(1f64) - 1f64 is 0
+/// ```
+///
+/// ## Related Functions
+/// `slorentz` => The lorentz factor itself
+/// `slorentz_minus_one_in` => γ - 1 in any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor#Numerical_values>
+pub fn slorentz_minus_one<T: Real>(speed: T) -> T {
+    slorentz_minus_one_in::<Si, T>(speed)
+}
+
+/// # Simple Lorentz Minus One (Unit System)
+/// Returns γ - 1 at a given speed in any unit system, accurate even for speeds where γ rounds to 1
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U) = The relative velocity, in the unit of speed of `U` (a fraction of c for natural units)
+/// ### Returns
+/// - Lorentz Factor minus one (γ - 1 :: γ)
+///
+/// ## Examples
+/// ```rs
+/// let tiny = slorentz_minus_one_in::<Natural, _>(1e-9); // -> 5e-19
+/// ```
+///
+/// ## Related Functions
+/// `slorentz_minus_one` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor#Numerical_values>
+pub fn slorentz_minus_one_in<U: UnitSystem, T: Real>(speed: T) -> T {
+    let inverse = inverse_lorentz::<U, T>(speed);
+    (speed / T::from_f64(U::SPEED_OF_LIGHT)).powi(2) / (inverse * (T::ONE + inverse))
+}
+
+/// 1 / γ = SquareRoot(1 - v^2 / c^2), with 1 - v^2 / c^2 factored as (c - v)(c + v) / c^2.
+/// c - v is exact close to c, which keeps the digits that 1 - v^2 / c^2 would lose
+fn inverse_lorentz<U: UnitSystem, T: Real>(speed: T) -> T {
+    let c = T::from_f64(U::SPEED_OF_LIGHT);
+    ((c - speed) * (c + speed)).sqrt() / c
 }
 
 /// # Complex Lorentz
@@ -103,8 +187,8 @@ pub fn slorentzt(speed: f64, deltatime: f64) -> f64 {
 ///
 /// ## Read more
 /// <https://en.wikipedia.org/wiki/Lorentz_factor>
-pub fn slorentztr(deltatime: f64, ratio: f64) -> f64 {
-    clorentz(deltatime, (1f64 - ratio).sqrt())
+pub fn slorentztr<T: Real>(deltatime: T, ratio: T) -> T {
+    clorentz(deltatime, (T::ONE - ratio).sqrt())
 }
 
 /// # Complex Lorentz
@@ -135,6 +219,165 @@ pub fn slorentztr(deltatime: f64, ratio: f64) -> f64 {
 ///
 /// ## Read more
 /// <https://en.wikipedia.org/wiki/Lorentz_factor>
-pub fn clorentz(deltatime: f64, ptime: f64) -> f64 {
+pub fn clorentz<T: Real>(deltatime: T, ptime: T) -> T {
     deltatime / ptime
 }
+
+/// # Complex Lorentz (Unit System)
+/// Returns the lorentz factor from the coordinate time and the proper time in any unit system.
+/// γ = Δt / Δτ does not contain c, so the result is the same as the one of `clorentz` for every `U`
+///
+/// ## Variables
+/// ### Parameters
+/// - deltatime (Δt :: U) = The coordinate time, in the unit of time of `U`
+/// - ptime (Δτ :: U) = The proper time for an observer
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Related Functions
+/// `clorentz` => The SI version
+/// `slorentz_in` => Simple version, takes the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+pub fn clorentz_in<U: UnitSystem, T: Real>(deltatime: T, ptime: T) -> T {
+    clorentz(deltatime, ptime)
+}
+
+/// # Simple Lorentz (Checked)
+/// Returns the lorentz factor at a given speed where deltatime = 1, validating the speed first
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s) = The relative velocity between inertial reference frames
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Examples
+/// ```rs
+/// let lorentz = slorentz_checked(SPEED_OF_LIGHT / 2f64)?;
+/// assert_eq!(slorentz_checked(SPEED_OF_LIGHT), Err(RelativityError::SuperluminalSpeed));
+/// ```
+///
+/// ## Related Functions
+/// `slorentz` => Unchecked, faster version
+/// `slorentz_checked_in` => Checked version in any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if |v| >= c
+/// - `NonFiniteInput` if v is NaN or infinite
+pub fn slorentz_checked<T: Real>(speed: T) -> Result<T, RelativityError> {
+    slorentz_checked_in::<Si, T>(speed)
+}
+
+/// # Simple Lorentz (Checked, Unit System)
+/// Returns the lorentz factor at a given speed in any unit system, validating the speed first
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U) = The relative velocity, in the unit of speed of `U` (a fraction of c for natural units)
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Examples
+/// ```rs
+/// let lorentz = slorentz_checked_in::<Natural, _>(0.6)?; // -> 1.25
+/// assert_eq!(slorentz_checked_in::<Natural, _>(1f64), Err(RelativityError::SuperluminalSpeed));
+/// ```
+///
+/// ## Related Functions
+/// `slorentz_checked` => The SI version
+/// `slorentz_in` => Unchecked, faster version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if |v| >= c
+/// - `NonFiniteInput` if v is NaN or infinite
+pub fn slorentz_checked_in<U: UnitSystem, T: Real>(speed: T) -> Result<T, RelativityError> {
+    check_speed::<U, T>(speed)?;
+    Ok(slorentz_in::<U, T>(speed))
+}
+
+/// # Simple Lorentz (Checked)
+/// Returns the lorentz factor at a given speed, validating the inputs first
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s) = The relative velocity between inertial reference frames
+/// - deltatime (Δt :: s) = The coordinate time
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Related Functions
+/// `slorentzt` => Unchecked, faster version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if |v| >= c
+/// - `NonFiniteInput` if any input is NaN or infinite
+pub fn slorentzt_checked<T: Real>(speed: T, deltatime: T) -> Result<T, RelativityError> {
+    check_finite(&[deltatime])?;
+    check_speed::<Si, T>(speed)?;
+    Ok(slorentzt(speed, deltatime))
+}
+
+/// # Simple Lorentz (Checked)
+/// Returns the lorentz factor from the ratio of v^2 to c^2, validating the inputs first
+///
+/// ## Variables
+/// ### Parameters
+/// - deltatime (Δt :: s) = The coordinate time
+/// - ratio (β :: int) = The ratio of v^2 to c^2
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Related Functions
+/// `slorentztr` => Unchecked, faster version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if the ratio is 1 or greater
+/// - `NonFiniteInput` if any input is NaN or infinite
+pub fn slorentztr_checked<T: Real>(deltatime: T, ratio: T) -> Result<T, RelativityError> {
+    check_finite(&[deltatime, ratio])?;
+    if ratio >= T::ONE {
+        return Err(RelativityError::SuperluminalSpeed);
+    }
+    Ok(slorentztr(deltatime, ratio))
+}
+
+/// # Complex Lorentz (Checked)
+/// Returns the lorentz factor from the coordinate and proper time, validating the inputs first
+///
+/// ## Variables
+/// ### Parameters
+/// - deltatime (Δt :: s) = The coordinate time
+/// - ptime (Δτ :: s) = The proper time for an observer
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Related Functions
+/// `clorentz` => Unchecked, faster version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+///
+/// ## Errors
+/// - `SuperluminalSpeed` if Δτ is 0 or less, which would require v >= c
+/// - `NonFiniteInput` if any input is NaN or infinite
+pub fn clorentz_checked<T: Real>(deltatime: T, ptime: T) -> Result<T, RelativityError> {
+    check_finite(&[deltatime, ptime])?;
+    if ptime <= T::ZERO {
+        return Err(RelativityError::SuperluminalSpeed);
+    }
+    Ok(clorentz(deltatime, ptime))
+}
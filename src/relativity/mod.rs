@@ -22,5 +22,17 @@
 //! to make this feature-complete while not completely ignoring all other areas of physics. If you believe that an important
 //! concept or equation is missing, implement it.
 
+pub mod boost;
+pub mod collision;
+pub mod curvature;
+pub mod doppler;
 pub mod energy;
+pub mod error;
+pub mod fourvector;
+pub mod geodesic;
+pub mod kinematics;
 pub mod lorentz;
+pub mod momentum;
+pub mod rocket;
+pub mod schwarzschild;
+pub mod typed;
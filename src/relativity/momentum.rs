@@ -0,0 +1,283 @@
+//! # Relativistic Momentum
+//! In special relativity, the momentum of a body is not simply mv, but
+//!
+//! p = γmv
+//!
+//! which grows without limit as v approaches c. Together with the total energy E = γmc^2, the momentum
+//! forms the energy–momentum relation
+//!
+//! E^2 = (pc)^2 + (mc^2)^2
+//!
+//! which also holds for massless particles like photons: they always move with c and carry the momentum p = E / c.
+//!
+//! This file converts between the speed, the momentum and the kinetic energy of a particle. Unless noted otherwise,
+//! the functions accept a mass of 0 for massless particles.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Momentum#Relativistic>
+//! <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+
+use super::energy::{senergy_from_momentum, senergy_from_momentum_in, srest_energy};
+use super::lorentz::{slorentz, slorentz_in};
+use crate::consts::{SPEED_OF_LIGHT, SPEED_OF_LIGHT_SQUARED};
+use crate::prelude::*;
+use crate::units::{Si, UnitSystem};
+use crate::vectors::{vec3::Vec3, GenericVector};
+
+/// # Simple Momentum
+/// Calculates the relativistic momentum of a body, p = γmv
+///
+/// ## Relativistic vs Newtonian
+/// Use this equation if v is bigger than 1% of c
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The (rest) mass of the body
+/// ### Returns
+/// - Momentum (p :: kg·m/s)
+///
+/// ## Examples
+/// ```rs
+/// let momentum = smomentum(SPEED_OF_LIGHT * 0.6, 1f64); // -> 0.75 kg·c
+/// ```
+///
+/// ## Related Functions
+/// `cmomentum` => Complex version, takes the lorentz factor
+/// `smomentum_vec3` => Vector form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Momentum#Relativistic>
+pub fn smomentum<T: Real>(speed: T, mass: T) -> T {
+    smomentum_in::<Si, T>(speed, mass)
+}
+
+/// # Simple Momentum (Unit System)
+/// Calculates the relativistic momentum of a body in any unit system, p = γmv
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U)
+/// - mass (m :: U) = The (rest) mass of the body
+/// ### Returns
+/// - Momentum (p :: U)
+///
+/// ## Related Functions
+/// `smomentum` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Momentum#Relativistic>
+pub fn smomentum_in<U: UnitSystem, T: Real>(speed: T, mass: T) -> T {
+    cmomentum(speed, mass, slorentz_in::<U, T>(speed))
+}
+
+/// # Complex Momentum
+/// Calculates the relativistic momentum of a body, p = γmv
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The (rest) mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Momentum (p :: kg·m/s)
+///
+/// ## Related Functions
+/// `smomentum` => Simple version, lorentz factor is calculated from the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Momentum#Relativistic>
+pub fn cmomentum<T: Real>(speed: T, mass: T, lorentz: T) -> T {
+    lorentz * mass * speed
+}
+
+/// # Complex Momentum (Unit System)
+/// Calculates the relativistic momentum of a body in any unit system, p = γmv.
+/// The formula does not contain c, so the result is the same as the one of `cmomentum` for every `U`
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: U) = The speed, in the unit of speed of `U` (a fraction of c for natural units)
+/// - mass (m :: U) = The (rest) mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Momentum (p :: U)
+///
+/// ## Related Functions
+/// `cmomentum` => The SI version
+/// `smomentum_in` => Simple version, lorentz factor is calculated from the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Momentum#Relativistic>
+pub fn cmomentum_in<U: UnitSystem, T: Real>(speed: T, mass: T, lorentz: T) -> T {
+    cmomentum(speed, mass, lorentz)
+}
+
+/// # Simple Momentum (Vector)
+/// Calculates the relativistic momentum of a body, p⃗ = γmv⃗
+///
+/// ## Variables
+/// ### Parameters
+/// - velocity (v⃗ :: m/s)
+/// - mass (m :: kg) = The (rest) mass of the body
+/// ### Returns
+/// - Momentum (p⃗ :: kg·m/s)
+///
+/// ## Related Functions
+/// `smomentum` => Scalar form
+/// `svelocity_from_momentum_vec3` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Momentum#Relativistic>
+pub fn smomentum_vec3<T: Real>(velocity: Vec3<T>, mass: T) -> Vec3<T> {
+    velocity * (slorentz(velocity.length()) * mass)
+}
+
+/// # Simple Speed (Momentum)
+/// Calculates the speed of a particle from its momentum, v = pc^2 / E.
+/// Massless particles always move with c. Without mass and momentum there is no particle, so the result is NaN
+///
+/// ## Variables
+/// ### Parameters
+/// - momentum (p :: kg·m/s)
+/// - mass (m :: kg) = The (rest) mass, may be 0 if the momentum is not 0
+/// ### Returns
+/// - Speed (v :: m/s)
+///
+/// ## Related Functions
+/// `smomentum` => The inverse
+/// `svelocity_from_momentum_vec3` => Vector form
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn sspeed_from_momentum<T: Real>(momentum: T, mass: T) -> T {
+    sspeed_from_momentum_in::<Si, T>(momentum, mass)
+}
+
+/// # Simple Speed (Momentum, Unit System)
+/// Calculates the speed of a particle from its momentum in any unit system, v = pc^2 / E
+///
+/// ## Variables
+/// ### Parameters
+/// - momentum (p :: U)
+/// - mass (m :: U) = The (rest) mass, may be 0 if the momentum is not 0
+/// ### Returns
+/// - Speed (v :: U)
+///
+/// ## Related Functions
+/// `sspeed_from_momentum` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn sspeed_from_momentum_in<U: UnitSystem, T: Real>(momentum: T, mass: T) -> T {
+    momentum * T::from_f64(U::SPEED_OF_LIGHT_SQUARED)
+        / senergy_from_momentum_in::<U, T>(momentum, mass)
+}
+
+/// # Simple Velocity (Momentum, Vector)
+/// Calculates the velocity of a particle from its momentum, v⃗ = p⃗c^2 / E
+///
+/// ## Variables
+/// ### Parameters
+/// - momentum (p⃗ :: kg·m/s)
+/// - mass (m :: kg) = The (rest) mass, may be 0 if the momentum is not 0
+/// ### Returns
+/// - Velocity (v⃗ :: m/s)
+///
+/// ## Related Functions
+/// `smomentum_vec3` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn svelocity_from_momentum_vec3<T: Real>(momentum: Vec3<T>, mass: T) -> Vec3<T> {
+    momentum
+        * (T::from_f64(SPEED_OF_LIGHT_SQUARED) / senergy_from_momentum(momentum.length(), mass))
+}
+
+/// # Simple Momentum (Energy)
+/// Calculates the momentum of a particle from its total energy, p = SquareRoot(E^2 - (mc^2)^2) / c.
+/// For massless particles, this is p = E / c
+///
+/// ## Variables
+/// ### Parameters
+/// - energy (E :: J) = The total energy
+/// - mass (m :: kg) = The (rest) mass, may be 0
+/// ### Returns
+/// - Momentum (p :: kg·m/s)
+///
+/// ## Related Functions
+/// `energy::senergy_from_momentum` => The inverse
+/// `smomentum_from_kinetic_energy` => From the kinetic energy instead
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn smomentum_from_energy<T: Real>(energy: T, mass: T) -> T {
+    let rest = srest_energy(mass);
+    ((energy - rest) * (energy + rest)).sqrt() / T::from_f64(SPEED_OF_LIGHT)
+}
+
+/// # Simple Momentum (Kinetic Energy)
+/// Calculates the momentum of a particle from its kinetic energy, pc = SquareRoot(KE^2 + 2KE·mc^2)
+///
+/// ## Variables
+/// ### Parameters
+/// - kinetic_energy (KE :: J)
+/// - mass (m :: kg) = The (rest) mass, may be 0
+/// ### Returns
+/// - Momentum (p :: kg·m/s)
+///
+/// ## Examples
+/// ```rs
+/// // An electron (9.109e-31 kg) accelerated by 1 kV
+/// let momentum = smomentum_from_kinetic_energy(1.602e-16, 9.109e-31);
+/// ```
+///
+/// ## Related Functions
+/// `skinetic_energy_from_momentum` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn smomentum_from_kinetic_energy<T: Real>(kinetic_energy: T, mass: T) -> T {
+    (kinetic_energy * (kinetic_energy + T::from_f64(2f64) * srest_energy(mass))).sqrt()
+        / T::from_f64(SPEED_OF_LIGHT)
+}
+
+/// # Simple Kinetic Energy (Momentum)
+/// Calculates the kinetic energy of a particle from its momentum, KE = E - mc^2 = (pc)^2 / (E + mc^2)
+///
+/// ## Variables
+/// ### Parameters
+/// - momentum (p :: kg·m/s)
+/// - mass (m :: kg) = The (rest) mass, may be 0
+/// ### Returns
+/// - Kinetic Energy (KE :: J)
+///
+/// ## Related Functions
+/// `smomentum_from_kinetic_energy` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Energy%E2%80%93momentum_relation>
+pub fn skinetic_energy_from_momentum<T: Real>(momentum: T, mass: T) -> T {
+    let pc = momentum * T::from_f64(SPEED_OF_LIGHT);
+    // Written without E - mc^2, which cancels for slow particles
+    pc.powi(2) / (senergy_from_momentum(momentum, mass) + srest_energy(mass))
+}
+
+/// # Simple Speed (Kinetic Energy)
+/// Calculates the speed of a particle from its kinetic energy. Massless particles always move with c
+///
+/// ## Variables
+/// ### Parameters
+/// - kinetic_energy (KE :: J)
+/// - mass (m :: kg) = The (rest) mass, may be 0 if the kinetic energy is not 0
+/// ### Returns
+/// - Speed (v :: m/s)
+///
+/// ## Related Functions
+/// `energy::skinetic_energy` => The inverse
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+pub fn sspeed_from_kinetic_energy<T: Real>(kinetic_energy: T, mass: T) -> T {
+    sspeed_from_momentum(smomentum_from_kinetic_energy(kinetic_energy, mass), mass)
+}
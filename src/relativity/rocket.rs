@@ -0,0 +1,170 @@
+//! # Relativistic Rocket
+//! A rocket that accelerates with a constant proper acceleration α (the crew always feels the same weight)
+//! never reaches the speed of light, but it can cross huge distances within a crew lifetime.
+//! Starting from rest, after the proper time τ of the crew:
+//!
+//! t = (c / α) sinh(ατ / c)
+//!
+//! d = (c^2 / α) (cosh(ατ / c) - 1)
+//!
+//! v = c tanh(ατ / c)
+//!
+//! γ = cosh(ατ / c)
+//!
+//! The rapidity of the rocket, ατ / c, grows linearly with the proper time.
+//!
+//! ## Fuel
+//! The relativistic version of the Tsiolkovsky rocket equation relates the speed of a rocket
+//! to the exhaust speed and the mass ratio:
+//!
+//! Δv = c tanh((vₑ / c) ln(m₀ / m₁))
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Relativistic_rocket>
+//! <https://math.ucr.edu/home/baez/physics/Relativity/SR/Rocket/rocket.html>
+
+use super::kinematics::{clorentz_from_rapidity, sspeed_from_rapidity};
+use crate::consts::{SPEED_OF_LIGHT, SPEED_OF_LIGHT_SQUARED};
+use crate::prelude::*;
+
+/// # Rocket State
+/// The state of a rocket with constant proper acceleration that started from rest
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RocketState<T = f64> {
+    /// τ :: s, the time that passed on board
+    pub proper_time: T,
+    /// t :: s, the time that passed in the rest frame of the start
+    pub coordinate_time: T,
+    /// d :: m, measured in the rest frame of the start
+    pub distance: T,
+    /// v :: m/s
+    pub speed: T,
+    /// γ :: γ
+    pub lorentz: T,
+    /// φ :: 1
+    pub rapidity: T,
+}
+
+/// # Simple Rocket
+/// Calculates the state of a rocket with constant proper acceleration after the proper time τ (on board)
+///
+/// ## Variables
+/// ### Parameters
+/// - accel (α :: m/s^2) = The proper acceleration
+/// - ptime (τ :: s) = The proper time since the start
+/// ### Returns
+/// - State (τ, t, d, v, γ, φ)
+///
+/// ## Examples
+/// ```rs
+/// // One year on board at 1g
+/// let state = srocket(STANDARD_GRAVITY, 365.25 * 86400f64);
+/// ```
+///
+/// ## Related Functions
+/// `srocket_coordinate_time` => After a coordinate time instead
+/// `crocket` => Complex version, takes the rapidity
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_rocket>
+pub fn srocket<T: Real>(accel: T, ptime: T) -> RocketState<T> {
+    crocket(accel, accel * ptime / T::from_f64(SPEED_OF_LIGHT))
+}
+
+/// # Simple Rocket (Coordinate Time)
+/// Calculates the state of a rocket with constant proper acceleration after the coordinate time t
+/// (in the rest frame of the start)
+///
+/// ## Variables
+/// ### Parameters
+/// - accel (α :: m/s^2) = The proper acceleration
+/// - deltatime (t :: s) = The coordinate time since the start
+/// ### Returns
+/// - State (τ, t, d, v, γ, φ)
+///
+/// ## Related Functions
+/// `srocket` => After a proper time instead
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_rocket>
+pub fn srocket_coordinate_time<T: Real>(accel: T, deltatime: T) -> RocketState<T> {
+    crocket(
+        accel,
+        (accel * deltatime / T::from_f64(SPEED_OF_LIGHT)).asinh(),
+    )
+}
+
+/// # Complex Rocket
+/// Calculates the state of a rocket with constant proper acceleration once it reached a rapidity
+///
+/// ## Variables
+/// ### Parameters
+/// - accel (α :: m/s^2) = The proper acceleration, must not be 0
+/// - rapidity (φ :: 1) = The rapidity of the rocket, ατ / c
+/// ### Returns
+/// - State (τ, t, d, v, γ, φ)
+///
+/// ## Related Functions
+/// `srocket` => Simple version, takes the proper time
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Relativistic_rocket>
+pub fn crocket<T: Real>(accel: T, rapidity: T) -> RocketState<T> {
+    let lorentz = clorentz_from_rapidity(rapidity);
+    RocketState {
+        proper_time: T::from_f64(SPEED_OF_LIGHT) / accel * rapidity,
+        coordinate_time: T::from_f64(SPEED_OF_LIGHT) / accel * rapidity.sinh(),
+        // cosh(φ) - 1 = 2sinh^2(φ / 2) does not cancel for small rapidities
+        distance: T::from_f64(SPEED_OF_LIGHT_SQUARED) / accel
+            * T::from_f64(2f64)
+            * (rapidity / T::from_f64(2f64)).sinh().powi(2),
+        speed: sspeed_from_rapidity(rapidity),
+        lorentz,
+        rapidity,
+    }
+}
+
+/// # Simple Rocket Δv
+/// Calculates the speed a rocket gains by burning fuel (relativistic Tsiolkovsky equation)
+///
+/// ## Variables
+/// ### Parameters
+/// - exhaust_speed (vₑ :: m/s) = The speed of the exhaust relative to the rocket
+/// - mass_ratio (m₀ / m₁ :: 1) = The initial mass divided by the final mass
+/// ### Returns
+/// - Change in Speed (Δv :: m/s)
+///
+/// ## Examples
+/// ```rs
+/// // A photon rocket that burns 90% of its mass
+/// let dv = srocket_delta_v(SPEED_OF_LIGHT, 10f64);
+/// ```
+///
+/// ## Related Functions
+/// `crocket_delta_v` => Complex version, takes both masses
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Tsiolkovsky_rocket_equation#Special_relativity>
+pub fn srocket_delta_v<T: Real>(exhaust_speed: T, mass_ratio: T) -> T {
+    sspeed_from_rapidity(exhaust_speed / T::from_f64(SPEED_OF_LIGHT) * mass_ratio.ln())
+}
+
+/// # Complex Rocket Δv
+/// Calculates the speed a rocket gains by burning fuel (relativistic Tsiolkovsky equation)
+///
+/// ## Variables
+/// ### Parameters
+/// - exhaust_speed (vₑ :: m/s) = The speed of the exhaust relative to the rocket
+/// - initial_mass (m₀ :: kg) = The rest mass before the burn
+/// - final_mass (m₁ :: kg) = The rest mass after the burn
+/// ### Returns
+/// - Change in Speed (Δv :: m/s)
+///
+/// ## Related Functions
+/// `srocket_delta_v` => Simple version, takes the mass ratio
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Tsiolkovsky_rocket_equation#Special_relativity>
+pub fn crocket_delta_v<T: Real>(exhaust_speed: T, initial_mass: T, final_mass: T) -> T {
+    srocket_delta_v(exhaust_speed, initial_mass / final_mass)
+}
@@ -0,0 +1,463 @@
+//! # Schwarzschild Spacetime
+//! The Schwarzschild metric describes the spacetime outside of a spherical, non-rotating and uncharged mass,
+//! such as a (slowly rotating) planet, star or black hole. It is the simplest solution of General Relativity:
+//!
+//! ds^2 = -(1 - rₛ/r) c^2 dt^2 + (1 - rₛ/r)^-1 dr^2 + r^2 dΩ^2
+//!
+//! where rₛ = 2GM / c^2 is the Schwarzschild radius, t is the time of a clock far away from the mass
+//! and r is the radius of a sphere with the area 4πr^2.
+//!
+//! Most functions exist twice: the simple functions take the mass M, while the complex functions
+//! take the Schwarzschild radius. For bodies in the solar system, GM is known much more precisely than G,
+//! so calculate rₛ = 2GM / c^2 from GM and use the complex functions if precision matters.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Schwarzschild_metric>
+
+use crate::prelude::*;
+use crate::units::{Si, UnitSystem};
+
+/// # Simple Schwarzschild Radius
+/// Calculates the radius of the event horizon of a (non-rotating) black hole with the given mass, rₛ = 2GM / c^2
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// ### Returns
+/// - Schwarzschild Radius (rₛ :: m)
+///
+/// ## Examples
+/// ```rs
+/// let radius = sschwarzschild_radius(5.972e24); // Earth -> 8.87mm
+/// ```
+///
+/// ## Related Functions
+/// `cschwarzschild_radius` => Complex version, takes GM
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Schwarzschild_radius>
+pub fn sschwarzschild_radius<T: Real>(mass: T) -> T {
+    sschwarzschild_radius_in::<Si, T>(mass)
+}
+
+/// # Simple Schwarzschild Radius (Unit System)
+/// Calculates the Schwarzschild radius in any unit system, rₛ = 2GM / c^2.
+/// In geometrized units, this is rₛ = 2M
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: U)
+/// ### Returns
+/// - Schwarzschild Radius (rₛ :: U)
+///
+/// ## Examples
+/// ```rs
+/// let radius = sschwarzschild_radius_in::<Gaussian, _>(1.989e33); // Sun -> 2.95e5 cm
+/// ```
+///
+/// ## Related Functions
+/// `sschwarzschild_radius` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Schwarzschild_radius>
+pub fn sschwarzschild_radius_in<U: UnitSystem, T: Real>(mass: T) -> T {
+    T::from_f64(2f64) * (T::from_f64(U::GRAVITATIONAL_CONSTANT) * mass)
+        / T::from_f64(U::SPEED_OF_LIGHT_SQUARED)
+}
+
+/// # Complex Schwarzschild Radius
+/// Calculates the Schwarzschild radius from the standard gravitational parameter, rₛ = 2μ / c^2
+///
+/// ## Variables
+/// ### Parameters
+/// - gm (μ :: m^3/s^2) = The standard gravitational parameter GM
+/// ### Returns
+/// - Schwarzschild Radius (rₛ :: m)
+///
+/// ## Related Functions
+/// `sschwarzschild_radius` => Simple version, takes the mass
+/// `cschwarzschild_radius_in` => In any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Schwarzschild_radius>
+pub fn cschwarzschild_radius<T: Real>(gm: T) -> T {
+    cschwarzschild_radius_in::<Si, T>(gm)
+}
+
+/// # Complex Schwarzschild Radius (Unit System)
+/// Calculates the Schwarzschild radius from the standard gravitational parameter in any unit system, rₛ = 2μ / c^2
+///
+/// ## Variables
+/// ### Parameters
+/// - gm (μ :: U) = The standard gravitational parameter GM, in the unit of length^3/time^2 of `U`
+/// ### Returns
+/// - Schwarzschild Radius (rₛ :: U)
+///
+/// ## Examples
+/// ```rs
+/// let radius = cschwarzschild_radius_in::<Gaussian, _>(1.32712440018e26); // Sun -> 2.95e5 cm
+/// ```
+///
+/// ## Related Functions
+/// `cschwarzschild_radius` => The SI version
+/// `sschwarzschild_radius_in` => Simple version, takes the mass
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Schwarzschild_radius>
+pub fn cschwarzschild_radius_in<U: UnitSystem, T: Real>(gm: T) -> T {
+    T::from_f64(2f64) * gm / T::from_f64(U::SPEED_OF_LIGHT_SQUARED)
+}
+
+/// # Simple Gravitational Time Dilation
+/// Calculates how fast a clock at rest at the radius r runs, compared to a clock far away from the mass,
+/// dτ/dt = SquareRoot(1 - rₛ/r)
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// - radius (r :: m) = The distance from the center, larger than rₛ
+/// ### Returns
+/// - Clock Rate (dτ/dt :: 1)
+///
+/// ## Related Functions
+/// `cgravitational_time_dilation` => Complex version, takes rₛ
+/// `sclock_rate` => Includes the time dilation of a moving clock
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_time_dilation>
+pub fn sgravitational_time_dilation<T: Real>(mass: T, radius: T) -> T {
+    cgravitational_time_dilation(sschwarzschild_radius(mass), radius)
+}
+
+/// # Complex Gravitational Time Dilation
+/// Calculates how fast a clock at rest at the radius r runs, compared to a clock far away from the mass,
+/// dτ/dt = SquareRoot(1 - rₛ/r)
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: m)
+/// - radius (r :: m) = The distance from the center, larger than rₛ
+/// ### Returns
+/// - Clock Rate (dτ/dt :: 1)
+///
+/// ## Related Functions
+/// `sgravitational_time_dilation` => Simple version, takes the mass
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_time_dilation>
+pub fn cgravitational_time_dilation<T: Real>(schwarzschild_radius: T, radius: T) -> T {
+    (T::ONE - schwarzschild_radius / radius).sqrt()
+}
+
+/// # Complex Gravitational Time Dilation (Unit System)
+/// Calculates the clock rate at the radius r in any unit system, dτ/dt = SquareRoot(1 - rₛ/r).
+/// Only the ratio of both lengths is used, so the result is the same as the one of `cgravitational_time_dilation`
+/// for every `U`
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: U)
+/// - radius (r :: U) = The distance from the center, larger than rₛ
+/// ### Returns
+/// - Clock Rate (dτ/dt :: 1)
+///
+/// ## Related Functions
+/// `cgravitational_time_dilation` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_time_dilation>
+pub fn cgravitational_time_dilation_in<U: UnitSystem, T: Real>(
+    schwarzschild_radius: T,
+    radius: T,
+) -> T {
+    cgravitational_time_dilation(schwarzschild_radius, radius)
+}
+
+/// # Simple Gravitational Redshift
+/// Calculates the redshift of light that is emitted at the radius r and received far away from the mass,
+/// z = 1 / SquareRoot(1 - rₛ/r) - 1
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// - radius (r :: m) = The radius of the emitter, larger than rₛ
+/// ### Returns
+/// - Redshift (z :: 1)
+///
+/// ## Examples
+/// ```rs
+/// let z = sgravitational_redshift(1.989e30, 6.957e8); // Light from the surface of the sun -> 2.1e-6
+/// ```
+///
+/// ## Related Functions
+/// `cgravitational_redshift` => Complex version, for a receiver at any radius
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_redshift>
+pub fn sgravitational_redshift<T: Real>(mass: T, radius: T) -> T {
+    cgravitational_redshift(
+        sschwarzschild_radius(mass),
+        radius,
+        T::from_f64(f64::INFINITY),
+    )
+}
+
+/// # Complex Gravitational Redshift
+/// Calculates the redshift of light that is emitted at one radius and received at another,
+/// z = SquareRoot((1 - rₛ/rᵣ) / (1 - rₛ/rₑ)) - 1. Negative (a blueshift) if the light falls towards the mass
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: m)
+/// - emitter (rₑ :: m) = The radius of the emitter
+/// - receiver (rᵣ :: m) = The radius of the receiver, `f64::INFINITY` for a receiver far away
+/// ### Returns
+/// - Redshift (z :: 1)
+///
+/// ## Related Functions
+/// `sgravitational_redshift` => Simple version, for a receiver far away
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_redshift>
+pub fn cgravitational_redshift<T: Real>(schwarzschild_radius: T, emitter: T, receiver: T) -> T {
+    // In logarithms, so that the tiny shifts in the solar system do not round to 0
+    let emitted = (-schwarzschild_radius / emitter).ln_1p();
+    let received = (-schwarzschild_radius / receiver).ln_1p();
+    ((received - emitted) / T::from_f64(2f64)).exp_m1()
+}
+
+/// # Complex Gravitational Redshift (Unit System)
+/// Calculates the redshift of light that is emitted at one radius and received at another in any unit system.
+/// Only ratios of lengths are used, so the result is the same as the one of `cgravitational_redshift` for every `U`
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: U)
+/// - emitter (rₑ :: U) = The radius of the emitter
+/// - receiver (rᵣ :: U) = The radius of the receiver, `f64::INFINITY` for a receiver far away
+/// ### Returns
+/// - Redshift (z :: 1)
+///
+/// ## Related Functions
+/// `cgravitational_redshift` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_redshift>
+pub fn cgravitational_redshift_in<U: UnitSystem, T: Real>(
+    schwarzschild_radius: T,
+    emitter: T,
+    receiver: T,
+) -> T {
+    cgravitational_redshift(schwarzschild_radius, emitter, receiver)
+}
+
+/// # Simple Photon Sphere Radius
+/// Calculates the radius at which light can orbit a (non-rotating) black hole, r = 1.5rₛ.
+/// The orbit is unstable
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// ### Returns
+/// - Photon Sphere Radius (r :: m)
+///
+/// ## Related Functions
+/// `sisco_radius` => The innermost stable orbit of massive bodies
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Photon_sphere>
+pub fn sphoton_sphere_radius<T: Real>(mass: T) -> T {
+    T::from_f64(1.5f64) * sschwarzschild_radius(mass)
+}
+
+/// # Simple ISCO Radius
+/// Calculates the radius of the innermost stable circular orbit of a (non-rotating) black hole, r = 3rₛ.
+/// Closer to the black hole, a massive body cannot orbit without falling in
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// ### Returns
+/// - ISCO Radius (r :: m)
+///
+/// ## Related Functions
+/// `sphoton_sphere_radius` => The (unstable) orbit of light
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Innermost_stable_circular_orbit>
+pub fn sisco_radius<T: Real>(mass: T) -> T {
+    T::from_f64(3f64) * sschwarzschild_radius(mass)
+}
+
+/// # Simple Escape Velocity
+/// Calculates the speed needed to escape from the radius r, v = SquareRoot(2GM / r) = c SquareRoot(rₛ/r).
+/// In General Relativity, this is the speed measured by an observer at rest at r, and the same as the Newtonian value
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// - radius (r :: m) = The distance from the center
+/// ### Returns
+/// - Escape Velocity (v :: m/s)
+///
+/// ## Related Functions
+/// `cescape_velocity` => Complex version, takes rₛ
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Escape_velocity>
+pub fn sescape_velocity<T: Real>(mass: T, radius: T) -> T {
+    cescape_velocity(sschwarzschild_radius(mass), radius)
+}
+
+/// # Complex Escape Velocity
+/// Calculates the speed needed to escape from the radius r, v = c SquareRoot(rₛ/r)
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: m)
+/// - radius (r :: m) = The distance from the center
+/// ### Returns
+/// - Escape Velocity (v :: m/s)
+///
+/// ## Related Functions
+/// `sescape_velocity` => Simple version, takes the mass
+/// `cescape_velocity_in` => In any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Escape_velocity>
+pub fn cescape_velocity<T: Real>(schwarzschild_radius: T, radius: T) -> T {
+    cescape_velocity_in::<Si, T>(schwarzschild_radius, radius)
+}
+
+/// # Complex Escape Velocity (Unit System)
+/// Calculates the speed needed to escape from the radius r in any unit system, v = c SquareRoot(rₛ/r)
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: U)
+/// - radius (r :: U) = The distance from the center
+/// ### Returns
+/// - Escape Velocity (v :: U)
+///
+/// ## Examples
+/// ```rs
+/// let beta = cescape_velocity_in::<Geometrized, _>(2f64, 8f64); // -> 0.5, half the speed of light
+/// ```
+///
+/// ## Related Functions
+/// `cescape_velocity` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Escape_velocity>
+pub fn cescape_velocity_in<U: UnitSystem, T: Real>(schwarzschild_radius: T, radius: T) -> T {
+    T::from_f64(U::SPEED_OF_LIGHT) * (schwarzschild_radius / radius).sqrt()
+}
+
+/// # Simple Clock Rate
+/// Calculates how fast a moving clock at the radius r runs, compared to a clock at rest far away from the mass.
+/// Combines gravitational and velocity time dilation, dτ/dt = SquareRoot(1 - rₛ/r) SquareRoot(1 - v^2/c^2)
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// - radius (r :: m) = The distance from the center
+/// - speed (v :: m/s) = The speed of the clock, measured by an observer at rest at r
+/// ### Returns
+/// - Clock Rate (dτ/dt :: 1)
+///
+/// ## Related Functions
+/// `cclock_rate` => Complex version, takes rₛ
+/// `sclock_drift` => Compares two clocks, e.g. a satellite and a ground station
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_time_dilation#Combined_effect_of_velocity_and_gravitational_time_dilation>
+pub fn sclock_rate<T: Real>(mass: T, radius: T, speed: T) -> T {
+    cclock_rate(sschwarzschild_radius(mass), radius, speed)
+}
+
+/// # Complex Clock Rate
+/// Calculates how fast a moving clock at the radius r runs, compared to a clock at rest far away from the mass,
+/// dτ/dt = SquareRoot(1 - rₛ/r) SquareRoot(1 - v^2/c^2)
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: m)
+/// - radius (r :: m) = The distance from the center
+/// - speed (v :: m/s) = The speed of the clock, measured by an observer at rest at r
+/// ### Returns
+/// - Clock Rate (dτ/dt :: 1)
+///
+/// ## Related Functions
+/// `sclock_rate` => Simple version, takes the mass
+/// `cclock_rate_in` => In any unit system
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_time_dilation#Combined_effect_of_velocity_and_gravitational_time_dilation>
+pub fn cclock_rate<T: Real>(schwarzschild_radius: T, radius: T, speed: T) -> T {
+    cclock_rate_in::<Si, T>(schwarzschild_radius, radius, speed)
+}
+
+/// # Complex Clock Rate (Unit System)
+/// Calculates how fast a moving clock at the radius r runs in any unit system, compared to a clock at rest far away
+/// from the mass, dτ/dt = SquareRoot(1 - rₛ/r) SquareRoot(1 - v^2/c^2)
+///
+/// ## Variables
+/// ### Parameters
+/// - schwarzschild_radius (rₛ :: U)
+/// - radius (r :: U) = The distance from the center
+/// - speed (v :: U) = The speed of the clock, in the unit of speed of `U` (a fraction of c for geometrized units)
+/// ### Returns
+/// - Clock Rate (dτ/dt :: 1)
+///
+/// ## Related Functions
+/// `cclock_rate` => The SI version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Gravitational_time_dilation#Combined_effect_of_velocity_and_gravitational_time_dilation>
+pub fn cclock_rate_in<U: UnitSystem, T: Real>(schwarzschild_radius: T, radius: T, speed: T) -> T {
+    log_clock_rate::<U, T>(schwarzschild_radius, radius, speed).exp()
+}
+
+/// # Simple Clock Drift
+/// Calculates how much faster a clock runs than a reference clock, as a fraction (rate / reference rate - 1).
+/// This is the correction applied to the clocks of navigation satellites, which run faster than clocks on the ground
+///
+/// ## Variables
+/// ### Parameters
+/// - mass (M :: kg)
+/// - radius (r :: m) = The distance of the clock from the center
+/// - speed (v :: m/s) = The speed of the clock
+/// - reference_radius (r₀ :: m) = The distance of the reference clock from the center
+/// - reference_speed (v₀ :: m/s) = The speed of the reference clock
+/// ### Returns
+/// - Fractional Drift (Δf/f :: 1)
+///
+/// ## Examples
+/// ```rs
+/// // A GPS satellite compared to a clock on the equator -> 4.46e-10, about 38µs per day
+/// let drift = sclock_drift(5.972e24, 26_561_750f64, 3874f64, 6_378_137f64, 465.1f64);
+/// ```
+///
+/// ## Related Functions
+/// `sclock_rate` => The rate of a single clock
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Error_analysis_for_the_Global_Positioning_System#Relativity>
+pub fn sclock_drift<T: Real>(
+    mass: T,
+    radius: T,
+    speed: T,
+    reference_radius: T,
+    reference_speed: T,
+) -> T {
+    let schwarzschild_radius = sschwarzschild_radius(mass);
+    (log_clock_rate::<Si, T>(schwarzschild_radius, radius, speed)
+        - log_clock_rate::<Si, T>(schwarzschild_radius, reference_radius, reference_speed))
+    .exp_m1()
+}
+
+/// ln(dτ/dt), which keeps the digits of rates that are very close to 1
+fn log_clock_rate<U: UnitSystem, T: Real>(schwarzschild_radius: T, radius: T, speed: T) -> T {
+    let beta = speed / T::from_f64(U::SPEED_OF_LIGHT);
+    ((-schwarzschild_radius / radius).ln_1p() + (-beta * beta).ln_1p()) / T::from_f64(2f64)
+}
@@ -0,0 +1,146 @@
+//! # Typed Relativity
+//! The functions of this file are the same as the ones in [`super::lorentz`] and [`super::energy`], but
+//! take and return [`crate::quantities`] instead of raw f64's. This makes it impossible to swap, for example,
+//! the speed and the mass of `skinetic_energy`.
+//!
+//! The wrappers have no runtime cost, the calculations are forwarded to the untyped functions.
+
+use crate::quantities::{Energy, Mass, Time, Velocity};
+
+use super::{energy, lorentz};
+
+/// # Simple Lorentz
+/// Returns the lorentz factor at a given speed where deltatime = 1
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s) = The relative velocity between inertial reference frames
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Examples
+/// ```rs
+/// let lorentz = slorentz(Velocity::new(SPEED_OF_LIGHT / 2f64));
+/// ```
+///
+/// ## Related Functions
+/// `lorentz::slorentz` => The untyped version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+pub fn slorentz(speed: Velocity) -> f64 {
+    lorentz::slorentz(speed.0)
+}
+
+/// # Simple Lorentz
+/// Returns the lorentz factor at a given speed, multiplied by deltatime
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s) = The relative velocity between inertial reference frames
+/// - deltatime (Δt :: s) = The coordinate time
+/// ### Returns
+/// - Dilated time (γΔt :: s)
+///
+/// ## Examples
+/// ```rs
+/// let dilated = slorentzt(Velocity::new(SPEED_OF_LIGHT * 0.6), Time::new(2f64)); // -> 2.5 s
+/// ```
+///
+/// ## Related Functions
+/// `lorentz::slorentzt` => The untyped version
+/// `slorentztr` => Passing in β^2 instead of the speed
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+pub fn slorentzt(speed: Velocity, deltatime: Time) -> Time {
+    Time(lorentz::slorentzt(speed.0, deltatime.0))
+}
+
+/// # Simple Lorentz
+/// Returns the lorentz factor for a given ratio of v^2 to c^2, multiplied by deltatime
+///
+/// ## Variables
+/// ### Parameters
+/// - deltatime (Δt :: s) = The coordinate time
+/// - ratio (β^2 :: 1) = The ratio of v^2 to c^2
+/// ### Returns
+/// - Dilated time (γΔt :: s)
+///
+/// ## Related Functions
+/// `lorentz::slorentztr` => The untyped version
+/// `slorentzt` => Passing in the speed instead of β^2
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+pub fn slorentztr(deltatime: Time, ratio: f64) -> Time {
+    Time(lorentz::slorentztr(deltatime.0, ratio))
+}
+
+/// # Complex Lorentz
+/// Returns the lorentz factor from the coordinate time and the proper time
+///
+/// ## Variables
+/// ### Parameters
+/// - deltatime (Δt :: s) = The coordinate time
+/// - ptime (Δτ :: s) = The proper time for an observer
+/// ### Returns
+/// - Lorentz Factor (γ :: γ)
+///
+/// ## Related Functions
+/// `lorentz::clorentz` => The untyped version
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Lorentz_factor>
+pub fn clorentz(deltatime: Time, ptime: Time) -> f64 {
+    lorentz::clorentz(deltatime.0, ptime.0)
+}
+
+/// # Simple Kinetic Energy
+/// Calculates the relativistic kinetic energy
+///
+/// ## Relativistic vs Newtonian
+/// Use this equation if `v` is bigger than 1% of `c`
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// ### Returns
+/// - Kinetic Energy (KE :: J)
+///
+/// ## Example
+/// ```rs
+/// let joules = skinetic_energy(Velocity::new(SPEED_OF_LIGHT / 2f64), Mass::new(1f64));
+/// ```
+///
+/// ## Related Functions
+/// `energy::skinetic_energy` => The untyped version
+/// `ckinetic_energy` => Complex Version, passing in a custom lorentz factor is required
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+pub fn skinetic_energy(speed: Velocity, mass: Mass) -> Energy {
+    Energy(energy::skinetic_energy(speed.0, mass.0))
+}
+
+/// # Complex Kinetic Energy
+/// Calculates the relativistic kinetic energy with a custom lorentz factor
+///
+/// ## Variables
+/// ### Parameters
+/// - speed (v :: m/s)
+/// - mass (m :: kg) = The mass of the body
+/// - lorentz (γ :: γ) = The lorentz factor
+/// ### Returns
+/// - Kinetic Energy (KE :: J)
+///
+/// ## Related Functions
+/// `energy::ckinetic_energy` => The untyped version
+/// `skinetic_energy` => The simple version, lorentz factor is emitted
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Kinetic_energy#Relativistic_kinetic_energy_of_rigid_bodies>
+pub fn ckinetic_energy(speed: Velocity, mass: Mass, lorentz: f64) -> Energy {
+    Energy(energy::ckinetic_energy(speed.0, mass.0, lorentz))
+}
@@ -0,0 +1,349 @@
+//! # Tensors
+//! Tensors over 4-dimensional spacetime, as used in General Relativity.
+//!
+//! A tensor of rank R has 4^R components. Every index is either upper (contravariant, like the components of a
+//! four-velocity u^μ) or lower (covariant, like the components of a gradient ∂_μ f). A metric tensor g_μν converts
+//! between both:
+//!
+//! u_μ = g_μν u^ν, u^μ = g^μν u_ν
+//!
+//! where repeated indices are summed over (Einstein notation). Summing over an upper and a lower index of the same
+//! tensor is called a contraction and lowers the rank by two.
+//!
+//! ## Storage
+//! [`Tensor`] stores its components in a flat array, the first index varies slowest. The size of the array is a
+//! second const parameter, as stable Rust cannot calculate 4^R in a type. Use the aliases [`Tensor1`] to [`Tensor4`].
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Tensor>
+//! <https://en.wikipedia.org/wiki/Raising_and_lowering_indices>
+
+use crate::matrices::{mat4::Mat4, GenericMatrix};
+use crate::vectors::{vec4::Vec4, vecn::VecN};
+
+/// The number of dimensions of spacetime
+pub const DIMENSION: usize = 4;
+
+/// # Index Position
+/// Whether an index of a tensor is upper (contravariant) or lower (covariant)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexPosition {
+    Upper,
+    Lower,
+}
+
+/// A scalar, e.g. the result of contracting a vector with a covector
+pub type Tensor0 = Tensor<0, 1>;
+/// A vector or covector
+pub type Tensor1 = Tensor<1, 4>;
+/// A rank 2 tensor, e.g. the metric or the Ricci tensor
+pub type Tensor2 = Tensor<2, 16>;
+/// A rank 3 tensor, e.g. the Christoffel symbols
+pub type Tensor3 = Tensor<3, 64>;
+/// A rank 4 tensor, e.g. the Riemann tensor
+pub type Tensor4 = Tensor<4, 256>;
+
+/// # Tensor
+/// A tensor of rank R over 4-dimensional spacetime with S = 4^R components
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tensor<const R: usize, const S: usize> {
+    pub components: [f64; S],
+    pub indices: [IndexPosition; R],
+}
+
+impl<const R: usize, const S: usize> Tensor<R, S> {
+    /// # Tensor.new
+    /// Creates a tensor from its components, the first index varies slowest
+    ///
+    /// ## Panics
+    /// Panics if S is not 4^R
+    pub fn new(components: [f64; S], indices: [IndexPosition; R]) -> Self {
+        assert_eq!(
+            S,
+            DIMENSION.pow(R as u32),
+            "a tensor of rank {R} needs 4^{R} components"
+        );
+        Self {
+            components,
+            indices,
+        }
+    }
+
+    /// # Tensor.zero
+    /// A tensor filled with 0
+    pub fn zero(indices: [IndexPosition; R]) -> Self {
+        Self::new([0f64; S], indices)
+    }
+
+    /// # Tensor.from_fn
+    /// Creates a tensor by calling `f` with the indices of every component
+    ///
+    /// ## Example
+    /// ```rs
+    /// // The Kronecker delta δ^μ_ν
+    /// let delta = Tensor2::from_fn([Upper, Lower], |[mu, nu]| if mu == nu { 1f64 } else { 0f64 });
+    /// ```
+    pub fn from_fn(indices: [IndexPosition; R], mut f: impl FnMut([usize; R]) -> f64) -> Self {
+        let mut tensor = Self::zero(indices);
+        for (flat, component) in tensor.components.iter_mut().enumerate() {
+            *component = f(unflatten(flat));
+        }
+        tensor
+    }
+
+    /// # Tensor.rank
+    /// The number of indices
+    pub const fn rank(&self) -> usize {
+        R
+    }
+
+    /// # Tensor.get
+    /// The component at the given indices
+    pub fn get(&self, index: [usize; R]) -> f64 {
+        self.components[flatten(index)]
+    }
+
+    /// # Tensor.set
+    /// Replaces the component at the given indices
+    pub fn set(&mut self, index: [usize; R], value: f64) {
+        self.components[flatten(index)] = value;
+    }
+
+    /// # Tensor.map
+    /// Applies `f` to every component
+    pub fn map(self, f: impl FnMut(f64) -> f64) -> Self {
+        Self {
+            components: self.components.map(f),
+            indices: self.indices,
+        }
+    }
+
+    /// # Tensor.contract
+    /// Sums over the indices `a` and `b`, which returns a tensor of rank R - 2.
+    /// The rank and size of the result have to be given, e.g. `riemann.contract::<2, 16>(0, 2)`
+    ///
+    /// ## Panics
+    /// Panics if one index is not upper and the other lower, or if the result does not have rank R - 2
+    pub fn contract<const R2: usize, const S2: usize>(self, a: usize, b: usize) -> Tensor<R2, S2> {
+        assert!(
+            R2 + 2 == R && a != b,
+            "a contraction removes two different indices"
+        );
+        assert_ne!(
+            self.indices[a], self.indices[b],
+            "only an upper and a lower index can be contracted"
+        );
+
+        let (first, second) = (a.min(b), a.max(b));
+        let mut kept = self
+            .indices
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != first && *i != second);
+        let indices = core::array::from_fn(|_| *kept.next().unwrap().1);
+
+        Tensor::<R2, S2>::from_fn(indices, |index| {
+            let mut full = [0usize; R];
+            let mut rest = index.iter();
+            for (i, slot) in full.iter_mut().enumerate() {
+                if i != first && i != second {
+                    *slot = *rest.next().unwrap();
+                }
+            }
+            (0..DIMENSION).fold(0f64, |sum, k| {
+                full[first] = k;
+                full[second] = k;
+                sum + self.get(full)
+            })
+        })
+    }
+
+    /// # Tensor.outer
+    /// The tensor product, (A ⊗ B)^(...)(...) = A^(...) B^(...), a tensor of rank R + R1.
+    /// The rank and size of the result have to be given
+    ///
+    /// ## Panics
+    /// Panics if the result does not have rank R + R1
+    pub fn outer<const R1: usize, const S1: usize, const R2: usize, const S2: usize>(
+        self,
+        other: Tensor<R1, S1>,
+    ) -> Tensor<R2, S2> {
+        assert_eq!(R2, R + R1, "the outer product has the sum of both ranks");
+        let indices = core::array::from_fn(|i| {
+            if i < R {
+                self.indices[i]
+            } else {
+                other.indices[i - R]
+            }
+        });
+
+        Tensor::<R2, S2>::from_fn(indices, |index| {
+            self.get(core::array::from_fn(|i| index[i]))
+                * other.get(core::array::from_fn(|i| index[R + i]))
+        })
+    }
+
+    /// # Tensor.contract_all
+    /// Sums over all indices of both tensors, e.g. T_μν S^μν. Every index of `self` has to be the opposite of the
+    /// same index of `other`
+    ///
+    /// ## Panics
+    /// Panics if two matching indices are both upper or both lower
+    pub fn contract_all(self, other: Self) -> f64 {
+        assert!(
+            self.indices
+                .iter()
+                .zip(other.indices.iter())
+                .all(|(a, b)| a != b),
+            "only upper and lower indices can be contracted"
+        );
+        self.components
+            .iter()
+            .zip(other.components.iter())
+            .fold(0f64, |sum, (a, b)| sum + a * b)
+    }
+
+    /// # Tensor.lower
+    /// Lowers an upper index with the metric, T_μ = g_μν T^ν. Lower indices are returned unchanged
+    pub fn lower(self, index: usize, metric: &MetricTensor) -> Self {
+        if self.indices[index] == IndexPosition::Lower {
+            return self;
+        }
+        self.transform_index(index, IndexPosition::Lower, metric.covariant)
+    }
+
+    /// # Tensor.raise
+    /// Raises a lower index with the inverse metric, T^μ = g^μν T_ν. Upper indices are returned unchanged
+    pub fn raise(self, index: usize, metric: &MetricTensor) -> Self {
+        if self.indices[index] == IndexPosition::Upper {
+            return self;
+        }
+        self.transform_index(index, IndexPosition::Upper, metric.contravariant)
+    }
+
+    /// Multiplies the matrix with the components along `index`, with all other indices fixed
+    fn transform_index(self, index: usize, position: IndexPosition, matrix: Mat4) -> Self {
+        let mut indices = self.indices;
+        indices[index] = position;
+
+        Self::from_fn(indices, |mut full| {
+            let mu = full[index];
+            let fibre = Vec4::from(VecN::new(core::array::from_fn(|nu| {
+                full[index] = nu;
+                self.get(full)
+            })));
+            matrix.row(mu) | fibre
+        })
+    }
+}
+
+impl Tensor2 {
+    /// # Tensor.from_matrix
+    /// Creates a rank 2 tensor from a matrix, the first index selects the row
+    pub fn from_matrix(matrix: Mat4, indices: [IndexPosition; 2]) -> Self {
+        Self::from_fn(indices, |[mu, nu]| VecN::from(matrix.row(mu))[nu])
+    }
+}
+
+impl Tensor0 {
+    /// # Tensor.scalar
+    /// The only component of a rank 0 tensor
+    pub fn scalar(self) -> f64 {
+        self.components[0]
+    }
+}
+
+/// # Metric Tensor
+/// The metric g_μν at a point, together with its inverse g^μν
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MetricTensor {
+    /// g_μν
+    pub covariant: Mat4,
+    /// g^μν
+    pub contravariant: Mat4,
+}
+
+impl MetricTensor {
+    /// # MetricTensor.new
+    /// Creates a metric from its covariant components g_μν. Returns `None` if the metric is singular
+    pub fn new(covariant: Mat4) -> Option<Self> {
+        Some(Self {
+            covariant,
+            contravariant: covariant.inverse()?,
+        })
+    }
+
+    /// # MetricTensor.minkowski
+    /// The flat metric of special relativity with the signature (-,+,+,+)
+    pub fn minkowski() -> Self {
+        let eta = Mat4::from_diagonal(Vec4::new(-1f64, 1f64, 1f64, 1f64));
+        Self {
+            covariant: eta,
+            contravariant: eta,
+        }
+    }
+
+    /// # MetricTensor.tensor
+    /// g_μν as a tensor
+    pub fn tensor(&self) -> Tensor2 {
+        Tensor2::from_matrix(self.covariant, [IndexPosition::Lower; 2])
+    }
+
+    /// # MetricTensor.inverse_tensor
+    /// g^μν as a tensor
+    pub fn inverse_tensor(&self) -> Tensor2 {
+        Tensor2::from_matrix(self.contravariant, [IndexPosition::Upper; 2])
+    }
+}
+
+fn flatten<const R: usize>(index: [usize; R]) -> usize {
+    index.iter().fold(0, |flat, &i| {
+        assert!(i < DIMENSION, "tensor indices go from 0 to 3");
+        flat * DIMENSION + i
+    })
+}
+
+fn unflatten<const R: usize>(mut flat: usize) -> [usize; R] {
+    let mut index = [0usize; R];
+    for slot in index.iter_mut().rev() {
+        *slot = flat % DIMENSION;
+        flat /= DIMENSION;
+    }
+    index
+}
+
+impl<const R: usize, const S: usize> core::ops::Add<Tensor<R, S>> for Tensor<R, S> {
+    type Output = Self;
+    fn add(self, rhs: Tensor<R, S>) -> Self {
+        debug_assert_eq!(self.indices, rhs.indices);
+        Self {
+            components: core::array::from_fn(|i| self.components[i] + rhs.components[i]),
+            indices: self.indices,
+        }
+    }
+}
+
+impl<const R: usize, const S: usize> core::ops::Sub<Tensor<R, S>> for Tensor<R, S> {
+    type Output = Self;
+    fn sub(self, rhs: Tensor<R, S>) -> Self {
+        debug_assert_eq!(self.indices, rhs.indices);
+        Self {
+            components: core::array::from_fn(|i| self.components[i] - rhs.components[i]),
+            indices: self.indices,
+        }
+    }
+}
+
+impl<const R: usize, const S: usize> core::ops::Mul<f64> for Tensor<R, S> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        self.map(|component| component * rhs)
+    }
+}
+
+impl<const R: usize, const S: usize> core::ops::Div<f64> for Tensor<R, S> {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        self.map(|component| component / rhs)
+    }
+}
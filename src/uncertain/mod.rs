@@ -0,0 +1,456 @@
+//! # Uncertain Values
+//! Measurements are never exact. [`Uncertain`] is a value with a standard uncertainty (mean ± σ) that propagates
+//! the uncertainty through every calculation with linear error propagation:
+//!
+//! σ_f^2 = Σ (∂f/∂x_i σ_i)^2
+//!
+//! The partial derivatives are calculated exactly with [`crate::dual`]. [`Uncertain`] implements [`Real`], so it
+//! works with the operators, the functions of [`NumericalExtensions`] and every generic formula of this crate.
+//!
+//! ## Correlations
+//! By default, the uncertainties of different values are independent, and every operation propagates them on its
+//! own. This is wrong if values share a source of error: x - x should be exactly 0, but two independent values
+//! with the same σ give 0 ± σ·SquareRoot(2). The same happens inside formulas that use an input more than once,
+//! such as the kinetic energy, which needs the speed for γ and for v^2.
+//!
+//! There are two ways to get correlations right:
+//!
+//! - [`spropagate`] evaluates a whole formula at once and propagates the uncertainties of its inputs
+//! - `Uncertain<N>` tracks N sources of error. A value created with [`Uncertain::tracked`] puts its uncertainty
+//!   into one of those sources, and every result remembers how much of its uncertainty comes from each source.
+//!   Contributions of the same source add up linearly instead of in quadrature
+//!
+//! ```rs
+//! let energy = spropagate(|[v, m]| skinetic_energy(v, m), [speed, mass]);
+//!
+//! let speed = Uncertain::<2>::tracked(1e8, 1e5, 0);
+//! let mass = Uncertain::<2>::tracked(2f64, 0.01, 1);
+//! let energy = skinetic_energy(speed, mass); // The same uncertainty
+//!
+//! let g = Uncertain::<1>::tracked(6.67430e-11, 1.5e-15, 0);
+//! let sun = Uncertain::exact(1.32712440041e20) / g; // The mass of the sun from GM, correlated with G
+//! let gm = g * sun; // -> 1.32712440041e20 ± 0, the uncertainty of G cancels out
+//! ```
+//!
+//! ## Constants
+//! The constants of [`crate::consts::codata`] and [`crate::consts::astro`] know their uncertainty,
+//! `ELECTRON_MASS.uncertain()` or `Uncertain::from(ELECTRON_MASS)` turn them into uncertain values.
+//!
+//! ## Limits
+//! Linear propagation is a first order approximation. It is accurate as long as the uncertainties are small
+//! compared to the scale on which the function curves, e.g. σ << x for 1 / x.
+//!
+//! Comparisons only look at the mean.
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Propagation_of_uncertainty>
+
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::consts::codata::PhysicalConstant;
+use crate::dual::Dual;
+use crate::prelude::*;
+
+/// # Uncertain
+/// A mean with a standard uncertainty, split into an independent part and N tracked sources
+#[derive(Clone, Copy, Debug)]
+pub struct Uncertain<const N: usize = 0> {
+    pub mean: f64,
+    /// The standard uncertainty that is not correlated with any other value
+    pub independent: f64,
+    /// The contribution of every tracked source to the standard uncertainty, with its sign
+    pub sources: [f64; N],
+}
+
+impl Uncertain {
+    /// # Uncertain.new
+    /// A value with an independent standard uncertainty, without tracked sources
+    ///
+    /// ## Example
+    /// ```rs
+    /// let speed = Uncertain::new(1000f64, 5f64); // -> 1000 ± 5 m/s
+    /// ```
+    ///
+    /// ## Related Functions
+    /// `Uncertain::independent` => The same for values with tracked sources
+    pub fn new(mean: f64, std_dev: f64) -> Self {
+        Self::independent(mean, std_dev)
+    }
+}
+
+impl<const N: usize> Uncertain<N> {
+    /// # Uncertain.independent
+    /// A value with an independent standard uncertainty
+    ///
+    /// ## Example
+    /// ```rs
+    /// let mass = Uncertain::<2>::independent(2f64, 0.01); // Can be combined with tracked values
+    /// ```
+    pub fn independent(mean: f64, std_dev: f64) -> Self {
+        Self {
+            mean,
+            independent: std_dev.abs(),
+            sources: [0f64; N],
+        }
+    }
+
+    /// # Uncertain.exact
+    /// A value without uncertainty
+    pub fn exact(mean: f64) -> Self {
+        Self::independent(mean, 0f64)
+    }
+
+    /// # Uncertain.tracked
+    /// A value whose uncertainty is the tracked source with the given index. Everything calculated from it
+    /// is correlated with it
+    ///
+    /// ## Panics
+    /// Panics if `source` is not smaller than N
+    pub fn tracked(mean: f64, std_dev: f64, source: usize) -> Self {
+        let mut sources = [0f64; N];
+        sources[source] = std_dev.abs();
+        Self {
+            mean,
+            independent: 0f64,
+            sources,
+        }
+    }
+
+    /// # Uncertain.std_dev
+    /// The standard uncertainty (σ)
+    pub fn std_dev(self) -> f64 {
+        self.sources
+            .iter()
+            .fold(self.independent, |sum, &source| sum.hypot(source))
+    }
+
+    /// # Uncertain.variance
+    /// The square of the standard uncertainty (σ^2)
+    pub fn variance(self) -> f64 {
+        self.std_dev().powi(2)
+    }
+
+    /// # Uncertain.relative_uncertainty
+    /// σ / |mean|
+    pub fn relative_uncertainty(self) -> f64 {
+        self.std_dev() / self.mean.abs()
+    }
+
+    /// # Uncertain.covariance
+    /// The covariance of two values, which only comes from the tracked sources they share
+    pub fn covariance(self, other: Self) -> f64 {
+        self.sources
+            .iter()
+            .zip(other.sources.iter())
+            .fold(0f64, |sum, (a, b)| sum + a * b)
+    }
+
+    /// # Uncertain.correlation
+    /// The correlation coefficient of two values, from -1 to 1
+    pub fn correlation(self, other: Self) -> f64 {
+        self.covariance(other) / (self.std_dev() * other.std_dev())
+    }
+
+    fn unary(self, f: impl Fn(Dual) -> Dual) -> Self {
+        spropagate(|[x]: [Dual<1>; 1]| f(x), [self])
+    }
+
+    fn binary(self, other: Self, f: impl Fn(Dual<2>, Dual<2>) -> Dual<2>) -> Self {
+        spropagate(|[x, y]| f(x, y), [self, other])
+    }
+}
+
+/// # Simple Propagate
+/// Evaluates `f` at the means of the inputs and propagates their uncertainties through it, using the exact
+/// partial derivatives of `f` at that point
+///
+/// σ_f^2 = Σ (∂f/∂x_i σ_i)^2
+///
+/// Unlike passing the values to `f` directly, this is correct for independent inputs that appear more than once
+/// in `f`
+///
+/// ## Variables
+/// ### Parameters
+/// - f = The function of M variables, evaluated with dual numbers
+/// - inputs = The uncertain values of the variables
+/// ### Returns
+/// - f(inputs) with the propagated uncertainty
+///
+/// ## Examples
+/// ```rs
+/// let speed = Uncertain::new(1e8, 1e5);
+/// let mass = Uncertain::new(2f64, 0.01);
+/// let energy = spropagate(|[v, m]| skinetic_energy(v, m), [speed, mass]); // -> 1.092e16 ± 5.96e13 J
+/// ```
+///
+/// ## Related Functions
+/// `sgradient` => The partial derivatives themselves
+///
+/// ## Read more
+/// <https://en.wikipedia.org/wiki/Propagation_of_uncertainty#Non-linear_combinations>
+pub fn spropagate<const M: usize, const N: usize>(
+    f: impl Fn([Dual<M>; M]) -> Dual<M>,
+    inputs: [Uncertain<N>; M],
+) -> Uncertain<N> {
+    let result = f(core::array::from_fn(|i| Dual::variable(inputs[i].mean, i)));
+    let derivatives = result.gradient;
+
+    Uncertain {
+        mean: result.value,
+        independent: (0..M).fold(0f64, |sum, i| {
+            sum.hypot(derivatives[i] * inputs[i].independent)
+        }),
+        // Contributions of the same source are correlated, so they add up linearly
+        sources: core::array::from_fn(|source| {
+            (0..M).fold(0f64, |sum, i| {
+                sum + derivatives[i] * inputs[i].sources[source]
+            })
+        }),
+    }
+}
+
+impl<const N: usize> From<f64> for Uncertain<N> {
+    fn from(mean: f64) -> Self {
+        Self::exact(mean)
+    }
+}
+
+impl<const N: usize> From<PhysicalConstant> for Uncertain<N> {
+    /// The value of the constant with its standard uncertainty, independent of all other values
+    fn from(constant: PhysicalConstant) -> Self {
+        Self::independent(constant.value, constant.uncertainty)
+    }
+}
+
+impl<const N: usize> PartialEq for Uncertain<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mean == other.mean
+    }
+}
+
+impl<const N: usize> PartialOrd for Uncertain<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.mean.partial_cmp(&other.mean)
+    }
+}
+
+impl<const N: usize> core::fmt::Display for Uncertain<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.*} ± {:.*}",
+                precision,
+                self.mean,
+                precision,
+                self.std_dev()
+            ),
+            None => write!(f, "{} ± {}", self.mean, self.std_dev()),
+        }
+    }
+}
+
+impl<const N: usize> Add for Uncertain<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.binary(rhs, |x, y| x + y)
+    }
+}
+
+impl<const N: usize> Sub for Uncertain<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.binary(rhs, |x, y| x - y)
+    }
+}
+
+impl<const N: usize> Mul for Uncertain<N> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.binary(rhs, |x, y| x * y)
+    }
+}
+
+impl<const N: usize> Div for Uncertain<N> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.binary(rhs, |x, y| x / y)
+    }
+}
+
+impl<const N: usize> Neg for Uncertain<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.unary(|x| -x)
+    }
+}
+
+impl<const N: usize> Mul<f64> for Uncertain<N> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        self.unary(|x| x * rhs)
+    }
+}
+
+impl<const N: usize> Div<f64> for Uncertain<N> {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        self.unary(|x| x / rhs)
+    }
+}
+
+impl<const N: usize> NumericalExtensions for Uncertain<N> {
+    fn powi(self, n: i32) -> Self {
+        self.unary(|x| x.powi(n))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        self.binary(n, NumericalExtensions::powf)
+    }
+
+    fn sqrt(self) -> Self {
+        self.unary(NumericalExtensions::sqrt)
+    }
+
+    fn cbrt(self) -> Self {
+        self.unary(NumericalExtensions::cbrt)
+    }
+
+    fn exp(self) -> Self {
+        self.unary(NumericalExtensions::exp)
+    }
+
+    fn exp_m1(self) -> Self {
+        self.unary(NumericalExtensions::exp_m1)
+    }
+
+    fn ln(self) -> Self {
+        self.unary(NumericalExtensions::ln)
+    }
+
+    fn ln_1p(self) -> Self {
+        self.unary(NumericalExtensions::ln_1p)
+    }
+
+    fn log10(self) -> Self {
+        self.unary(NumericalExtensions::log10)
+    }
+
+    fn log2(self) -> Self {
+        self.unary(NumericalExtensions::log2)
+    }
+
+    fn sin(self) -> Self {
+        self.unary(NumericalExtensions::sin)
+    }
+
+    fn cos(self) -> Self {
+        self.unary(NumericalExtensions::cos)
+    }
+
+    fn tan(self) -> Self {
+        self.unary(NumericalExtensions::tan)
+    }
+
+    fn asin(self) -> Self {
+        self.unary(NumericalExtensions::asin)
+    }
+
+    fn acos(self) -> Self {
+        self.unary(NumericalExtensions::acos)
+    }
+
+    fn atan(self) -> Self {
+        self.unary(NumericalExtensions::atan)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        self.binary(other, NumericalExtensions::atan2)
+    }
+
+    fn sinh(self) -> Self {
+        self.unary(NumericalExtensions::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        self.unary(NumericalExtensions::cosh)
+    }
+
+    fn tanh(self) -> Self {
+        self.unary(NumericalExtensions::tanh)
+    }
+
+    fn asinh(self) -> Self {
+        self.unary(NumericalExtensions::asinh)
+    }
+
+    fn acosh(self) -> Self {
+        self.unary(NumericalExtensions::acosh)
+    }
+
+    fn atanh(self) -> Self {
+        self.unary(NumericalExtensions::atanh)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        self.binary(other, NumericalExtensions::hypot)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        spropagate(|[x, a, b]| x.mul_add(a, b), [self, a, b])
+    }
+}
+
+impl<const N: usize> Real for Uncertain<N> {
+    const ZERO: Self = Self {
+        mean: 0f64,
+        independent: 0f64,
+        sources: [0f64; N],
+    };
+    const ONE: Self = Self {
+        mean: 1f64,
+        independent: 0f64,
+        sources: [0f64; N],
+    };
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        Self::exact(value)
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.mean
+    }
+
+    fn abs(self) -> Self {
+        if self.mean < 0f64 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other.mean < self.mean || self.mean.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.mean > self.mean || self.mean.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// True if the mean is finite, the uncertainty is not checked
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.mean.is_finite()
+    }
+}
@@ -0,0 +1,194 @@
+//! # Unit Systems
+//! The functions of this crate take and return SI units. Relativity and particle physics are usually done in
+//! systems where some constants are 1, which removes them from the formulas:
+//!
+//! - [`Si`]: meters, kilograms, seconds and joules
+//! - [`Natural`]: ħ = c = 1, everything is measured in powers of the electron volt. Masses and energies are in eV,
+//!   lengths and times in 1/eV
+//! - [`Geometrized`]: G = c = 1, everything is measured in meters. A mass of 1 m is the mass with
+//!   GM / c^2 = 1 m, which is how [`crate::relativity::geodesic`] measures masses
+//! - [`Gaussian`]: centimeters, grams, seconds and ergs (CGS)
+//!
+//! ## Formulas in other systems
+//! The core formulas of [`crate::relativity`] have a generic `_in` version that takes the unit system as the
+//! first type parameter, e.g. `srest_energy_in::<Natural, _>(0.511e6)`. The second one is the scalar type
+//! ([`crate::prelude::Real`]) and is inferred from the arguments. The SI functions forward to them with [`Si`].
+//!
+//! These formulas have an `_in` version:
+//!
+//! - `slorentz_in`, `slorentz_minus_one_in`, `clorentz_in` and `slorentz_checked_in` in [`crate::relativity::lorentz`]
+//! - `skinetic_energy_in`, `ckinetic_energy_in`, `skinetic_energy_checked_in`, `ckinetic_energy_checked_in`,
+//!   `srest_energy_in`, `stotal_energy_in`, `ctotal_energy_in`, `senergy_from_momentum_in` and
+//!   `smass_from_energy_momentum_in` in [`crate::relativity::energy`]
+//! - `smomentum_in`, `cmomentum_in` and `sspeed_from_momentum_in` in [`crate::relativity::momentum`]
+//! - `sschwarzschild_radius_in`, `cschwarzschild_radius_in`, `cgravitational_time_dilation_in`,
+//!   `cgravitational_redshift_in`, `cescape_velocity_in` and `cclock_rate_in` in [`crate::relativity::schwarzschild`]
+//!
+//! Some of them (e.g. `clorentz_in` or `cmomentum_in`) do not contain c and return the same result as the SI
+//! function. They exist so that code which is generic over the unit system can use the same functions.
+//!
+//! Everything else, including the kinematics, rocket, doppler and collision formulas, [`crate::relativity::fourvector`]
+//! and [`crate::relativity::boost`], uses the SI value of c. Convert the values to SI with the functions below first.
+//!
+//! ## Conversions
+//! Every system knows the SI value of its units of energy, mass, length and time. [`convert_energy`],
+//! [`convert_mass`], [`convert_length`] and [`convert_time`] convert between any two systems.
+//!
+//! ## Example
+//! ```rs
+//! let electron = convert_mass::<Si, Natural, _>(9.1093837015e-31); // -> 510998.95 eV
+//! let rest = srest_energy_in::<Natural, _>(electron); // -> 510998.95 eV
+//! let sun = convert_mass::<Si, Geometrized, _>(1.98841e30); // -> 1476.6 m
+//! ```
+//!
+//! ## Read more
+//! <https://en.wikipedia.org/wiki/Natural_units>
+//! <https://en.wikipedia.org/wiki/Geometrized_unit_system>
+//! <https://en.wikipedia.org/wiki/Gaussian_units>
+
+use crate::consts::codata::{ELECTRON_VOLT, REDUCED_PLANCK_CONSTANT};
+use crate::consts::{GRAVITATIONAL_CONSTANT, SPEED_OF_LIGHT, SPEED_OF_LIGHT_SQUARED};
+use crate::prelude::Real;
+
+/// # Unit System
+/// The values of the constants and the sizes of the units of a system of units.
+/// Implement it for a unit struct to add a system, e.g. natural units based on GeV
+pub trait UnitSystem: Copy {
+    /// The name of the system
+    const NAME: &'static str;
+
+    /// The speed of light (c) in this system
+    const SPEED_OF_LIGHT: f64;
+    /// c^2 in this system
+    const SPEED_OF_LIGHT_SQUARED: f64;
+    /// The reduced Planck constant (ħ) in this system
+    const REDUCED_PLANCK_CONSTANT: f64;
+    /// The gravitational constant (G) in this system
+    const GRAVITATIONAL_CONSTANT: f64;
+
+    /// The unit of energy in J
+    const ENERGY: f64;
+    /// The unit of mass in kg
+    const MASS: f64;
+    /// The unit of length in m
+    const LENGTH: f64;
+    /// The unit of time in s
+    const TIME: f64;
+}
+
+/// # SI
+/// The International System of Units: m, kg, s, J
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Si;
+
+impl UnitSystem for Si {
+    const NAME: &'static str = "SI";
+    const SPEED_OF_LIGHT: f64 = SPEED_OF_LIGHT;
+    const SPEED_OF_LIGHT_SQUARED: f64 = SPEED_OF_LIGHT_SQUARED;
+    const REDUCED_PLANCK_CONSTANT: f64 = REDUCED_PLANCK_CONSTANT.value;
+    const GRAVITATIONAL_CONSTANT: f64 = GRAVITATIONAL_CONSTANT;
+    const ENERGY: f64 = 1f64;
+    const MASS: f64 = 1f64;
+    const LENGTH: f64 = 1f64;
+    const TIME: f64 = 1f64;
+}
+
+/// # Natural Units
+/// ħ = c = 1, energies and masses in eV, lengths and times in 1/eV.
+/// G = 1 / (Planck energy)^2 is about 6.7e-57 / eV^2
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Natural;
+
+impl UnitSystem for Natural {
+    const NAME: &'static str = "natural (ħ = c = 1)";
+    const SPEED_OF_LIGHT: f64 = 1f64;
+    const SPEED_OF_LIGHT_SQUARED: f64 = 1f64;
+    const REDUCED_PLANCK_CONSTANT: f64 = 1f64;
+    const GRAVITATIONAL_CONSTANT: f64 =
+        GRAVITATIONAL_CONSTANT * Self::MASS * Self::MASS / (Self::ENERGY * Self::LENGTH);
+    const ENERGY: f64 = ELECTRON_VOLT.value;
+    const MASS: f64 = ELECTRON_VOLT.value / SPEED_OF_LIGHT_SQUARED;
+    const LENGTH: f64 = REDUCED_PLANCK_CONSTANT.value * SPEED_OF_LIGHT / ELECTRON_VOLT.value;
+    const TIME: f64 = REDUCED_PLANCK_CONSTANT.value / ELECTRON_VOLT.value;
+}
+
+/// # Geometrized Units
+/// G = c = 1, everything in meters. A time of 1 m is the time light needs for 1 m, a mass of 1 m is c^2 / G kg.
+/// ħ is the square of the Planck length, about 2.6e-70 m^2
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Geometrized;
+
+impl UnitSystem for Geometrized {
+    const NAME: &'static str = "geometrized (G = c = 1)";
+    const SPEED_OF_LIGHT: f64 = 1f64;
+    const SPEED_OF_LIGHT_SQUARED: f64 = 1f64;
+    const REDUCED_PLANCK_CONSTANT: f64 =
+        REDUCED_PLANCK_CONSTANT.value / (Self::ENERGY * Self::TIME);
+    const GRAVITATIONAL_CONSTANT: f64 = 1f64;
+    const ENERGY: f64 = SPEED_OF_LIGHT_SQUARED * SPEED_OF_LIGHT_SQUARED / GRAVITATIONAL_CONSTANT;
+    const MASS: f64 = SPEED_OF_LIGHT_SQUARED / GRAVITATIONAL_CONSTANT;
+    const LENGTH: f64 = 1f64;
+    const TIME: f64 = 1f64 / SPEED_OF_LIGHT;
+}
+
+/// # Gaussian Units
+/// The Gaussian centimetre–gram–second system: cm, g, s, erg
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Gaussian;
+
+impl UnitSystem for Gaussian {
+    const NAME: &'static str = "Gaussian (CGS)";
+    const SPEED_OF_LIGHT: f64 = 29_979_245_800f64;
+    const SPEED_OF_LIGHT_SQUARED: f64 = 29_979_245_800f64 * 29_979_245_800f64;
+    const REDUCED_PLANCK_CONSTANT: f64 = REDUCED_PLANCK_CONSTANT.value * 1e7;
+    const GRAVITATIONAL_CONSTANT: f64 = GRAVITATIONAL_CONSTANT * 1e3;
+    const ENERGY: f64 = 1e-7;
+    const MASS: f64 = 1e-3;
+    const LENGTH: f64 = 1e-2;
+    const TIME: f64 = 1f64;
+}
+
+/// # Convert Energy
+/// Converts an energy from the system `F` into the system `T`. `R` is the scalar type and is inferred from the argument
+///
+/// ## Examples
+/// ```rs
+/// let joules = convert_energy::<Natural, Si, _>(1f64); // -> 1.602e-19 J
+/// let ergs = convert_energy::<Si, Gaussian, _>(1f64); // -> 1e7 erg
+/// ```
+pub fn convert_energy<F: UnitSystem, T: UnitSystem, R: Real>(energy: R) -> R {
+    energy * R::from_f64(F::ENERGY / T::ENERGY)
+}
+
+/// # Convert Mass
+/// Converts a mass from the system `F` into the system `T`. `R` is the scalar type and is inferred from the argument
+///
+/// ## Examples
+/// ```rs
+/// let electron = convert_mass::<Si, Natural, _>(9.1093837015e-31); // -> 510998.95 eV
+/// ```
+pub fn convert_mass<F: UnitSystem, T: UnitSystem, R: Real>(mass: R) -> R {
+    mass * R::from_f64(F::MASS / T::MASS)
+}
+
+/// # Convert Length
+/// Converts a length from the system `F` into the system `T`. `R` is the scalar type and is inferred from the argument
+///
+/// ## Examples
+/// ```rs
+/// let inverse_ev = convert_length::<Si, Natural, _>(1f64); // -> 5.07e6 / eV
+/// ```
+pub fn convert_length<F: UnitSystem, T: UnitSystem, R: Real>(length: R) -> R {
+    length * R::from_f64(F::LENGTH / T::LENGTH)
+}
+
+/// # Convert Time
+/// Converts a time from the system `F` into the system `T`. `R` is the scalar type and is inferred from the argument
+///
+/// ## Examples
+/// ```rs
+/// let meters = convert_time::<Si, Geometrized, _>(1f64); // -> 299792458 m
+/// ```
+pub fn convert_time<F: UnitSystem, T: UnitSystem, R: Real>(time: R) -> R {
+    time * R::from_f64(F::TIME / T::TIME)
+}
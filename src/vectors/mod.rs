@@ -1,105 +1,328 @@
 pub mod vec2;
 pub mod vec3;
+pub mod vec4;
+pub mod vecn;
 
-pub trait GenericVector: crate::prelude::private::Sealed {
-    fn cross(self, other: Self) -> Self;
+use crate::prelude::*;
 
-    fn dot(self, other: Self) -> Self;
+/// # Generic Vector
+/// The operations shared by all vector types. `Scalar` is the type of the components, f64 by default.
+///
+/// The named constructors of `Vec2`, `Vec3` and `Vec4` (`zero`, `xaxis`, `up`, ...) are f64 only, so
+/// `Vec3::zero()` needs no type annotation. Code that is generic over the scalar uses the constructors
+/// of this trait instead
+///
+/// ## Example
+/// ```rs
+/// fn origin<T: Real>() -> Vec3<T> {
+///     GenericVector::zero()
+/// }
+/// let up = Vec3::<f32>::axis(2); // -> (0,0,1)
+/// ```
+pub trait GenericVector: crate::prelude::private::Sealed + Copy {
+    type Scalar: Real;
+
+    /// # Filled
+    /// A vector with all components set to `n`
+    fn filled(n: Self::Scalar) -> Self;
+
+    /// # Axis
+    /// The unit vector along the axis with the given index, e.g. 0 for x.
+    /// Panics if `index` is not smaller than the dimension
+    fn axis(index: usize) -> Self;
+
+    /// # Zero
+    /// A zero vector (0,...,0)
+    fn zero() -> Self {
+        Self::filled(Self::Scalar::ZERO)
+    }
+
+    /// # One
+    /// A one vector (1,...,1)
+    fn one() -> Self {
+        Self::filled(Self::Scalar::ONE)
+    }
+
+    fn dot(self, other: Self) -> Self::Scalar;
 
     fn vadd(self, other: Self) -> Self;
 
-    fn add_f64(self, by: f64) -> Self;
+    fn add_f64(self, by: Self::Scalar) -> Self;
 
     fn vsub(self, other: Self) -> Self;
 
-    fn sub_f64(self, by: f64) -> Self;
+    fn sub_f64(self, by: Self::Scalar) -> Self;
 
     fn vdiv(self, other: Self) -> Self;
 
-    fn div_f64(self, by: f64) -> Self;
+    fn div_f64(self, by: Self::Scalar) -> Self;
 
     fn vmul(self, other: Self) -> Self;
 
-    fn mul_f64(self, by: f64) -> Self;
+    fn mul_f64(self, by: Self::Scalar) -> Self;
 
     fn all_eq(self) -> bool;
+
+    /// # Length Squared
+    /// The squared euclidean length of the vector. Cheaper than `length`, as no square root is needed
+    fn length_squared(self) -> Self::Scalar {
+        self.dot(self)
+    }
+
+    /// # Length
+    /// The euclidean length (magnitude) of the vector
+    fn length(self) -> Self::Scalar {
+        self.length_squared().sqrt()
+    }
+
+    /// # Normalize
+    /// Returns a vector with the same direction and a length of 1.
+    /// The components of the result are NaN if the vector has a length of 0
+    fn normalize(self) -> Self {
+        self.div_f64(self.length())
+    }
+
+    /// # Distance
+    /// The euclidean distance between two points
+    fn distance(self, other: Self) -> Self::Scalar {
+        self.vsub(other).length()
+    }
+
+    /// # Angle Between
+    /// The (unsigned) angle between two vectors in radians, in the range 0..=π
+    fn angle_between(self, other: Self) -> Self::Scalar {
+        let cos = self.dot(other) / (self.length() * other.length());
+        cos.clamp(-Self::Scalar::ONE, Self::Scalar::ONE).acos()
+    }
+
+    /// # Project
+    /// The projection of this vector onto `onto`, i.e. the component of this vector parallel to `onto`
+    fn project(self, onto: Self) -> Self {
+        onto.mul_f64(self.dot(onto) / onto.length_squared())
+    }
+
+    /// # Reject
+    /// The rejection of this vector from `from`, i.e. the component of this vector perpendicular to `from`
+    fn reject(self, from: Self) -> Self {
+        self.vsub(self.project(from))
+    }
+
+    /// # Reflect
+    /// Reflects this vector on the plane (or line in 2D) with the given normal.
+    /// The normal does not have to be normalized
+    fn reflect(self, normal: Self) -> Self {
+        let two = Self::Scalar::from_f64(2f64);
+        self.vsub(normal.mul_f64(two * self.dot(normal) / normal.length_squared()))
+    }
+}
+
+/// # Cross Product
+/// Only implemented for vectors with a meaningful cross product. The result is a vector in 3D,
+/// but a scalar (the perp-dot product) in 2D
+pub trait CrossProduct: GenericVector {
+    type Output;
+
+    fn cross(self, other: Self) -> Self::Output;
 }
 
 pub(crate) mod vec_macros {
+    /// Implements `GenericVector` for a named-field vector type by converting it into a `VecN`
+    /// of the given dimension and the same scalar, which holds the actual implementation
+    #[macro_export]
+    macro_rules! impl_generic_vector_via_vecn {
+        ($n:ident, $dim:literal) => {
+            impl<T: $crate::prelude::Real> $crate::vectors::GenericVector for $n<T> {
+                type Scalar = T;
+
+                #[inline]
+                fn filled(n: T) -> Self {
+                    $crate::vectors::vecn::VecN::<$dim, T>::filled(n).into()
+                }
+
+                #[inline]
+                fn axis(index: usize) -> Self {
+                    $crate::vectors::vecn::VecN::<$dim, T>::axis(index).into()
+                }
+
+                #[inline]
+                fn dot(self, other: $n<T>) -> T {
+                    $crate::vectors::GenericVector::dot(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        other.into(),
+                    )
+                }
+
+                #[inline]
+                fn vadd(self, other: $n<T>) -> Self {
+                    $crate::vectors::GenericVector::vadd(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        other.into(),
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn add_f64(self, by: T) -> Self {
+                    $crate::vectors::GenericVector::add_f64(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        by,
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn vsub(self, other: $n<T>) -> Self {
+                    $crate::vectors::GenericVector::vsub(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        other.into(),
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn sub_f64(self, by: T) -> Self {
+                    $crate::vectors::GenericVector::sub_f64(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        by,
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn vdiv(self, other: $n<T>) -> Self {
+                    $crate::vectors::GenericVector::vdiv(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        other.into(),
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn div_f64(self, by: T) -> Self {
+                    $crate::vectors::GenericVector::div_f64(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        by,
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn vmul(self, other: $n<T>) -> Self {
+                    $crate::vectors::GenericVector::vmul(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        other.into(),
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn mul_f64(self, by: T) -> Self {
+                    $crate::vectors::GenericVector::mul_f64(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                        by,
+                    )
+                    .into()
+                }
+
+                #[inline]
+                fn all_eq(self) -> bool {
+                    $crate::vectors::GenericVector::all_eq(
+                        $crate::vectors::vecn::VecN::<$dim, T>::from(self),
+                    )
+                }
+            }
+        };
+    }
+
+    /// Implements the operators of a vector type, generic over `$($generics)*`.
+    /// `$s` is the scalar type of the vector
     #[macro_export]
     macro_rules! impl_common_ops_for_vec {
-        ($n:ident) => {
-            impl core::ops::BitXor<$n> for $n {
-                type Output = Self;
-                fn bitxor(self, rhs: $n) -> Self {
-                    self.cross(rhs)
+        ($n:ty, $s:ty; $($generics:tt)*) => {
+            impl<$($generics)*> core::ops::BitXor<$n> for $n
+            where
+                $n: $crate::vectors::CrossProduct,
+            {
+                type Output = <$n as $crate::vectors::CrossProduct>::Output;
+                fn bitxor(self, rhs: $n) -> Self::Output {
+                    $crate::vectors::CrossProduct::cross(self, rhs)
                 }
             }
 
-            impl core::ops::BitOr<$n> for $n {
-                type Output = Self;
-                fn bitor(self, rhs: $n) -> Self {
-                    self.dot(rhs)
+            impl<$($generics)*> core::ops::BitOr<$n> for $n {
+                type Output = $s;
+                fn bitor(self, rhs: $n) -> $s {
+                    $crate::vectors::GenericVector::dot(self, rhs)
                 }
             }
 
-            impl core::ops::Add<$n> for $n {
+            impl<$($generics)*> core::ops::Add<$n> for $n {
                 type Output = Self;
                 fn add(self, rhs: $n) -> Self {
-                    self.vadd(rhs)
+                    $crate::vectors::GenericVector::vadd(self, rhs)
                 }
             }
 
-            impl core::ops::Add<f64> for $n {
+            impl<$($generics)*> core::ops::Add<$s> for $n {
                 type Output = Self;
-                fn add(self, rhs: f64) -> Self {
-                    self.add_f64(rhs)
+                fn add(self, rhs: $s) -> Self {
+                    $crate::vectors::GenericVector::add_f64(self, rhs)
                 }
             }
 
-            impl core::ops::Sub<$n> for $n {
+            impl<$($generics)*> core::ops::Sub<$n> for $n {
                 type Output = Self;
                 fn sub(self, rhs: $n) -> Self {
-                    self.vsub(rhs)
+                    $crate::vectors::GenericVector::vsub(self, rhs)
                 }
             }
 
-            impl core::ops::Sub<f64> for $n {
+            impl<$($generics)*> core::ops::Sub<$s> for $n {
                 type Output = Self;
-                fn sub(self, rhs: f64) -> Self {
-                    self.sub_f64(rhs)
+                fn sub(self, rhs: $s) -> Self {
+                    $crate::vectors::GenericVector::sub_f64(self, rhs)
                 }
             }
 
-            impl core::ops::Mul<$n> for $n {
+            impl<$($generics)*> core::ops::Mul<$n> for $n {
                 type Output = Self;
                 fn mul(self, rhs: $n) -> Self {
-                    self.vmul(rhs)
+                    $crate::vectors::GenericVector::vmul(self, rhs)
                 }
             }
 
-            impl core::ops::Mul<f64> for $n {
+            impl<$($generics)*> core::ops::Mul<$s> for $n {
                 type Output = Self;
-                fn mul(self, rhs: f64) -> Self {
-                    self.mul_f64(rhs)
+                fn mul(self, rhs: $s) -> Self {
+                    $crate::vectors::GenericVector::mul_f64(self, rhs)
                 }
             }
 
-            impl core::ops::Div<$n> for $n {
+            impl<$($generics)*> core::ops::Div<$n> for $n {
                 type Output = Self;
                 fn div(self, rhs: $n) -> Self {
-                    self.vdiv(rhs)
+                    $crate::vectors::GenericVector::vdiv(self, rhs)
+                }
+            }
+
+            impl<$($generics)*> core::ops::Div<$s> for $n {
+                type Output = Self;
+                fn div(self, rhs: $s) -> Self {
+                    $crate::vectors::GenericVector::div_f64(self, rhs)
                 }
             }
 
-            impl core::ops::Div<f64> for $n {
+            impl<$($generics)*> core::ops::Neg for $n {
                 type Output = Self;
-                fn div(self, rhs: f64) -> Self {
-                    self.div_f64(rhs)
+                fn neg(self) -> Self {
+                    $crate::vectors::GenericVector::mul_f64(
+                        self,
+                        -<$s as $crate::prelude::Real>::ONE,
+                    )
                 }
             }
 
-            impl crate::prelude::private::Sealed for $n {}
+            impl<$($generics)*> $crate::prelude::private::Sealed for $n {}
         };
     }
 }
@@ -1,57 +1,62 @@
-use super::GenericVector;
+use super::{vecn::VecN, CrossProduct};
+use crate::prelude::Real;
 
-#[derive(PartialEq)]
-pub struct Vec2 {
-    pub x: f64,
-    pub y: f64,
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec2<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
-impl GenericVector for Vec2 {
-    fn cross(self, other: Vec2) -> Self {
-        other
-    }
-    fn dot(self, other: Vec2) -> Self {
-        other
-    }
-
-    fn vadd(self, other: Vec2) -> Self {
-        todo!()
-    }
-
-    fn add_f64(self, by: f64) -> Self {
-        todo!()
+impl<T: Real> Vec2<T> {
+    /// # Vec2.new
+    /// Creates a vector from its components
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
     }
 
-    fn vsub(self, other: Vec2) -> Self {
-        todo!()
+    /// # Vec2.filled
+    /// Creates a vector filled with `n`
+    pub fn filled(n: T) -> Self {
+        Self { x: n, y: n }
     }
+}
 
-    fn sub_f64(self, by: f64) -> Self {
-        todo!()
+impl Vec2 {
+    /// # Vec2.zero
+    /// A zero vector (0,0)
+    pub fn zero() -> Self {
+        Self { x: 0.0, y: 0.0 }
     }
 
-    fn vdiv(self, other: Vec2) -> Self {
-        todo!()
+    /// # Vec2.one
+    /// A one vector (1,1)
+    pub fn one() -> Self {
+        Self { x: 1.0, y: 1.0 }
     }
 
-    fn div_f64(self, by: f64) -> Self {
-        todo!()
+    /// # Vec2.xaxis
+    /// X axis vector (1,0)
+    pub fn xaxis() -> Self {
+        Self { x: 1.0, y: 0.0 }
     }
 
-    fn vmul(self, other: Vec2) -> Self {
-        todo!()
+    /// # Vec2.yaxis
+    /// Y axis vector (0,1)
+    pub fn yaxis() -> Self {
+        Self { x: 0.0, y: 1.0 }
     }
+}
 
-    fn all_eq(self) -> bool {
-        todo!()
-    }
+impl<T: Real> CrossProduct for Vec2<T> {
+    type Output = T;
 
-    fn mul_f64(self, by: f64) -> Self {
-        Self {
-            x: self.x * by,
-            y: self.y * by,
-        }
+    /// The perp-dot product, the z component of the cross product of both vectors in the xy-plane.
+    /// Positive if `other` is counter-clockwise from `self`
+    fn cross(self, other: Vec2<T>) -> T {
+        VecN::from(self).cross(other.into())
     }
 }
 
-crate::impl_common_ops_for_vec!(Vec2);
+crate::impl_generic_vector_via_vecn!(Vec2, 2);
+
+crate::impl_common_ops_for_vec!(Vec2<T>, T; T: Real);
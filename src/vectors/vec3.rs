@@ -1,13 +1,44 @@
-#[macro_use]
-use crate::vectors::vec_macros::*;
+use super::{vec2::Vec2, vecn::VecN, CrossProduct};
+use crate::prelude::Real;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Real> Vec3<T> {
+    /// # Vec3.new
+    /// Creates a vector from its components
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
 
-use super::{vec2::Vec2, GenericVector};
+    /// # Vec3.filled
+    /// Creates a vector filled with `n`
+    ///
+    /// ## Example
+    /// ```rs
+    /// let vec = Vec3::filed(1); // -> (1,1,1)
+    /// ```
+    pub fn filled(n: T) -> Self {
+        Self { x: n, y: n, z: n }
+    }
 
-#[derive(PartialEq)]
-pub struct Vec3 {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+    /// # Vec3.from_vec2
+    /// Creates a vector from a vec2 and a z coordinate
+    /// ```rs
+    /// let vec2 = Vec2::new(2,4);
+    /// let vec = Vec3::from_vec2(vec2, 6); // -> (2,4,6)
+    /// ```
+    pub fn from_vec2(vec2: Vec2<T>, z: T) -> Self {
+        Self {
+            x: vec2.x,
+            y: vec2.y,
+            z,
+        }
+    }
 }
 
 impl Vec3 {
@@ -42,7 +73,7 @@ impl Vec3 {
     }
 
     /// # Vec3.down
-    /// A down vector (0,0-1)
+    /// A down vector (0,0,-1)
     pub fn down() -> Self {
         Self {
             x: 0.0,
@@ -57,7 +88,7 @@ impl Vec3 {
         Self {
             x: 1.0,
             y: 0.0,
-            z: -1.0,
+            z: 0.0,
         }
     }
 
@@ -120,85 +151,21 @@ impl Vec3 {
             z: 1.0,
         }
     }
-
-    /// # Vec3.filled
-    /// Creates a vector filled with `n`
-    ///
-    /// ## Example
-    /// ```rs
-    /// let vec = Vec3::filed(1); // -> (1,1,1)
-    /// ```
-    pub fn filled(n: f64) -> Self {
-        Self { x: n, y: n, z: n }
-    }
-
-    /// # Vec3.from_vec2
-    /// Creates a vector from a vec2 and a z coordinate
-    /// ```rs
-    /// let vec2 = Vec2::new(2,4);
-    /// let vec = Vec3::from_vec2(vec2, 6); // -> (2,4,6)
-    /// ```
-    pub fn from_vec2(vec2: Vec2, z: f64) -> Self {
-        Self {
-            x: vec2.x,
-            y: vec2.y,
-            z,
-        }
-    }
 }
-impl GenericVector for Vec3 {
-    fn cross(self, other: Vec3) -> Self {
-        other
-    }
-    fn dot(self, other: Vec3) -> Self {
-        other
-    }
-
-    fn vadd(self, other: Vec3) -> Self {
-        todo!()
-    }
+impl<T: Real> CrossProduct for Vec3<T> {
+    type Output = Vec3<T>;
 
-    fn add_f64(self, by: f64) -> Self {
-        todo!()
-    }
-
-    fn vsub(self, other: Vec3) -> Self {
-        todo!()
-    }
-
-    fn sub_f64(self, by: f64) -> Self {
-        todo!()
-    }
-
-    fn vdiv(self, other: Vec3) -> Self {
-        todo!()
-    }
-
-    fn div_f64(self, by: f64) -> Self {
-        todo!()
-    }
-
-    fn vmul(self, other: Vec3) -> Self {
-        todo!()
-    }
-
-    fn all_eq(self) -> bool {
-        todo!()
-    }
-
-    fn mul_f64(self, by: f64) -> Self {
-        Self {
-            x: self.x * by,
-            y: self.y * by,
-            z: self.z * by,
-        }
+    fn cross(self, other: Vec3<T>) -> Self {
+        VecN::from(self).cross(other.into()).into()
     }
 }
 
-impl From<Vec2> for Vec3 {
-    fn from(v: Vec2) -> Self {
-        Vec3::from_vec2(v, 0f64)
+crate::impl_generic_vector_via_vecn!(Vec3, 3);
+
+impl<T: Real> From<Vec2<T>> for Vec3<T> {
+    fn from(v: Vec2<T>) -> Self {
+        Vec3::from_vec2(v, T::ZERO)
     }
 }
 
-crate::impl_common_ops_for_vec!(Vec3);
+crate::impl_common_ops_for_vec!(Vec3<T>, T; T: Real);
@@ -0,0 +1,242 @@
+//! # 4D Vectors
+//! 4-component vectors are mostly used for homogeneous coordinates, where a point or direction in 3D space
+//! is extended by a fourth `w` component:
+//!
+//! - w = 0 => A direction. Directions are not affected by translations
+//! - w = 1 => A point
+//!
+//! Any other `w` can be brought back to 3D with a perspective divide, which divides `x`, `y` and `z` by `w`.
+
+use super::{vec3::Vec3, CrossProduct, GenericVector};
+use crate::prelude::Real;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec4<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: Real> Vec4<T> {
+    /// # Vec4.new
+    /// Creates a vector from its components
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// # Vec4.filled
+    /// Creates a vector filled with `n`
+    ///
+    /// ## Example
+    /// ```rs
+    /// let vec = Vec4::filled(1); // -> (1,1,1,1)
+    /// ```
+    pub fn filled(n: T) -> Self {
+        Self {
+            x: n,
+            y: n,
+            z: n,
+            w: n,
+        }
+    }
+
+    /// # Vec4.from_vec3
+    /// Creates a vector from a vec3 and a w coordinate
+    /// ```rs
+    /// let vec3 = Vec3::new(2,4,6);
+    /// let vec = Vec4::from_vec3(vec3, 8); // -> (2,4,6,8)
+    /// ```
+    pub fn from_vec3(vec3: Vec3<T>, w: T) -> Self {
+        Self {
+            x: vec3.x,
+            y: vec3.y,
+            z: vec3.z,
+            w,
+        }
+    }
+
+    /// # Vec4.from_point
+    /// Creates the homogeneous coordinates of a point (w = 1)
+    pub fn from_point(point: Vec3<T>) -> Self {
+        Self::from_vec3(point, T::ONE)
+    }
+
+    /// # Vec4.from_direction
+    /// Creates the homogeneous coordinates of a direction (w = 0)
+    pub fn from_direction(direction: Vec3<T>) -> Self {
+        Self::from_vec3(direction, T::ZERO)
+    }
+
+    /// # Vec4.xyz
+    /// Drops the w component
+    pub fn xyz(self) -> Vec3<T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// # Vec4.perspective_divide
+    /// Converts homogeneous coordinates back into a 3D point by dividing by w.
+    /// The components of the result are infinite or NaN if w is 0 (a direction)
+    pub fn perspective_divide(self) -> Vec3<T> {
+        self.xyz().div_f64(self.w)
+    }
+}
+
+impl Vec4 {
+    /// # Vec4.zero
+    /// A zero vector (0,0,0,0)
+    pub fn zero() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.one
+    /// A one vector (1,1,1,1)
+    pub fn one() -> Self {
+        Self {
+            x: 1.0,
+            y: 1.0,
+            z: 1.0,
+            w: 1.0,
+        }
+    }
+
+    /// # Vec4.up
+    /// An up direction (0,0,1,0)
+    pub fn up() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.down
+    /// A down direction (0,0,-1,0)
+    pub fn down() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: -1.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.forward
+    /// A forward direction (1,0,0,0)
+    pub fn forward() -> Self {
+        Self {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.backward
+    /// A backward direction (-1,0,0,0)
+    pub fn backward() -> Self {
+        Self {
+            x: -1.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.right
+    /// A right direction (0,1,0,0)
+    pub fn right() -> Self {
+        Self {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.left
+    /// A left direction (0,-1,0,0)
+    pub fn left() -> Self {
+        Self {
+            x: 0.0,
+            y: -1.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.xaxis
+    /// X axis vector (1,0,0,0)
+    pub fn xaxis() -> Self {
+        Self {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.yaxis
+    /// Y axis vector (0,1,0,0)
+    pub fn yaxis() -> Self {
+        Self {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.zaxis
+    /// Z axis vector (0,0,1,0)
+    pub fn zaxis() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+            w: 0.0,
+        }
+    }
+
+    /// # Vec4.waxis
+    /// W axis vector (0,0,0,1)
+    pub fn waxis() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+}
+
+impl<T: Real> CrossProduct for Vec4<T> {
+    type Output = Vec4<T>;
+
+    /// The 3D cross product of the `xyz` components. The result is a direction (w = 0)
+    fn cross(self, other: Vec4<T>) -> Self {
+        Self::from_direction(self.xyz().cross(other.xyz()))
+    }
+}
+
+crate::impl_generic_vector_via_vecn!(Vec4, 4);
+
+impl<T: Real> From<Vec3<T>> for Vec4<T> {
+    fn from(v: Vec3<T>) -> Self {
+        Vec4::from_direction(v)
+    }
+}
+
+impl<T: Real> From<Vec4<T>> for Vec3<T> {
+    fn from(v: Vec4<T>) -> Self {
+        v.xyz()
+    }
+}
+
+crate::impl_common_ops_for_vec!(Vec4<T>, T; T: Real);
@@ -0,0 +1,263 @@
+//! # N-dimensional Vectors
+//! `VecN` is a vector with any number of components, backed by an array of scalars (f64 by default, see
+//! [`crate::prelude::Real`]).
+//! All vector operations are implemented once for `VecN`, the named-field types
+//! (`Vec2`, `Vec3` and `Vec4`) convert into a `VecN` of the same size and forward to it.
+//!
+//! Conversions between the named-field types and `VecN` are lossless, so larger state vectors
+//! (e.g. a 6-dimensional position & velocity vector) have the same API as `Vec3`.
+
+use core::ops::{Index, IndexMut};
+
+use super::{vec2::Vec2, vec3::Vec3, vec4::Vec4, CrossProduct, GenericVector};
+use crate::prelude::Real;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VecN<const N: usize, T = f64> {
+    pub components: [T; N],
+}
+
+impl<const N: usize, T: Real> VecN<N, T> {
+    /// # VecN.new
+    /// Creates a vector from its components
+    pub fn new(components: [T; N]) -> Self {
+        Self { components }
+    }
+
+    /// # VecN.zero
+    /// A zero vector (0,0,...,0)
+    pub fn zero() -> Self {
+        Self::filled(T::ZERO)
+    }
+
+    /// # VecN.one
+    /// A one vector (1,1,...,1)
+    pub fn one() -> Self {
+        Self::filled(T::ONE)
+    }
+
+    /// # VecN.filled
+    /// Creates a vector filled with `n`
+    pub fn filled(n: T) -> Self {
+        Self { components: [n; N] }
+    }
+
+    /// # VecN.axis
+    /// The unit vector along the axis with the given index
+    ///
+    /// ## Example
+    /// ```rs
+    /// let vec = VecN::<4>::axis(1); // -> (0,1,0,0)
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if `index` is not smaller than N
+    pub fn axis(index: usize) -> Self {
+        let mut vec = Self::zero();
+        vec.components[index] = T::ONE;
+        vec
+    }
+
+    /// # VecN.len
+    /// The number of components (the dimension) of the vector
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// # VecN.is_empty
+    /// True if the vector has no components (N = 0)
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// # VecN.map
+    /// Applies `f` to every component
+    pub fn map(self, mut f: impl FnMut(T) -> T) -> Self {
+        Self {
+            components: self.components.map(&mut f),
+        }
+    }
+
+    /// # VecN.zip_with
+    /// Combines the components of both vectors pairwise using `f`
+    pub fn zip_with(self, other: Self, mut f: impl FnMut(T, T) -> T) -> Self {
+        Self {
+            components: core::array::from_fn(|i| f(self.components[i], other.components[i])),
+        }
+    }
+}
+
+impl<const N: usize, T: Real> GenericVector for VecN<N, T> {
+    type Scalar = T;
+
+    #[inline]
+    fn filled(n: T) -> Self {
+        VecN::filled(n)
+    }
+
+    #[inline]
+    fn axis(index: usize) -> Self {
+        VecN::axis(index)
+    }
+
+    #[inline]
+    fn dot(self, other: Self) -> T {
+        self.components
+            .iter()
+            .zip(other.components.iter())
+            .fold(T::ZERO, |sum, (&a, &b)| sum + a * b)
+    }
+
+    #[inline]
+    fn vadd(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    #[inline]
+    fn add_f64(self, by: T) -> Self {
+        self.map(|a| a + by)
+    }
+
+    #[inline]
+    fn vsub(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    #[inline]
+    fn sub_f64(self, by: T) -> Self {
+        self.map(|a| a - by)
+    }
+
+    #[inline]
+    fn vdiv(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a / b)
+    }
+
+    #[inline]
+    fn div_f64(self, by: T) -> Self {
+        self.map(|a| a / by)
+    }
+
+    #[inline]
+    fn vmul(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    #[inline]
+    fn mul_f64(self, by: T) -> Self {
+        self.map(|a| a * by)
+    }
+
+    #[inline]
+    fn all_eq(self) -> bool {
+        self.components.windows(2).all(|pair| pair[0] == pair[1])
+    }
+}
+
+impl<T: Real> CrossProduct for VecN<2, T> {
+    type Output = T;
+
+    /// The perp-dot product, the z component of the cross product of both vectors in the xy-plane.
+    /// Positive if `other` is counter-clockwise from `self`
+    #[inline]
+    fn cross(self, other: Self) -> T {
+        let [ax, ay] = self.components;
+        let [bx, by] = other.components;
+        ax * by - ay * bx
+    }
+}
+
+impl<T: Real> CrossProduct for VecN<3, T> {
+    type Output = Self;
+
+    #[inline]
+    fn cross(self, other: Self) -> Self {
+        let [ax, ay, az] = self.components;
+        let [bx, by, bz] = other.components;
+        Self::new([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+impl<const N: usize, T: Real> Default for VecN<N, T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize, T> Index<usize> for VecN<N, T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        &self.components[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for VecN<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.components[index]
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for VecN<N, T> {
+    fn from(components: [T; N]) -> Self {
+        Self { components }
+    }
+}
+
+impl<const N: usize, T> From<VecN<N, T>> for [T; N] {
+    fn from(v: VecN<N, T>) -> Self {
+        v.components
+    }
+}
+
+impl<T> From<Vec2<T>> for VecN<2, T> {
+    #[inline]
+    fn from(v: Vec2<T>) -> Self {
+        Self {
+            components: [v.x, v.y],
+        }
+    }
+}
+
+impl<T> From<VecN<2, T>> for Vec2<T> {
+    #[inline]
+    fn from(v: VecN<2, T>) -> Self {
+        let [x, y] = v.components;
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec3<T>> for VecN<3, T> {
+    #[inline]
+    fn from(v: Vec3<T>) -> Self {
+        Self {
+            components: [v.x, v.y, v.z],
+        }
+    }
+}
+
+impl<T> From<VecN<3, T>> for Vec3<T> {
+    #[inline]
+    fn from(v: VecN<3, T>) -> Self {
+        let [x, y, z] = v.components;
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T> From<Vec4<T>> for VecN<4, T> {
+    #[inline]
+    fn from(v: Vec4<T>) -> Self {
+        Self {
+            components: [v.x, v.y, v.z, v.w],
+        }
+    }
+}
+
+impl<T> From<VecN<4, T>> for Vec4<T> {
+    #[inline]
+    fn from(v: VecN<4, T>) -> Self {
+        let [x, y, z, w] = v.components;
+        Vec4 { x, y, z, w }
+    }
+}
+
+crate::impl_common_ops_for_vec!(VecN<N, T>, T; const N: usize, T: Real);
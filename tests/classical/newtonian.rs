@@ -0,0 +1,298 @@
+use crate::assert_close;
+use mathonomy::classical::newtonian::typed;
+use mathonomy::classical::newtonian::{energy, kinematics::*, momentum, sforce, sforce_vec3};
+use mathonomy::consts::STANDARD_GRAVITY;
+use mathonomy::quantities::{Acceleration, Force, Mass};
+use mathonomy::vectors::vec3::Vec3;
+
+#[test]
+fn typed_second_law() {
+    let force = typed::sforce(Mass::new(2.0), Acceleration::new(9.0));
+
+    assert_eq!(force, Force::new(18.0));
+    assert_eq!(typed::smass(force, Acceleration::new(9.0)), Mass::new(2.0));
+    assert_eq!(typed::saccel(force, Mass::new(2.0)), Acceleration::new(9.0));
+}
+
+#[test]
+fn second_law() {
+    assert_eq!(sforce(2.0, 3.0), 6.0);
+    assert_eq!(
+        sforce_vec3(2.0, Vec3::new(1.0, 0.0, -1.0)),
+        Vec3::new(2.0, 0.0, -2.0)
+    );
+}
+
+#[test]
+fn suvat_equations() {
+    assert_eq!(sfinal_velocity(2.0, 3.0, 4.0), 14.0);
+    assert_eq!(sdisplacement(2.0, 3.0, 4.0), 32.0);
+    assert_eq!(
+        sdisplacement_vec3(Vec3::new(10.0, 0.0, 10.0), Vec3::new(0.0, 0.0, -10.0), 2.0),
+        Vec3::new(20.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        sfinal_velocity_vec3(Vec3::xaxis(), Vec3::yaxis(), 2.0),
+        Vec3::new(1.0, 2.0, 0.0)
+    );
+}
+
+#[test]
+fn suvat_solver() {
+    // u = 2, a = 3, t = 4 => v = 14, s = 32
+    let expected = SuvatSolution {
+        displacement: 32.0,
+        initial_velocity: 2.0,
+        final_velocity: 14.0,
+        acceleration: 3.0,
+        time: 4.0,
+    };
+    let all = [Some(32.0), Some(2.0), Some(14.0), Some(3.0), Some(4.0)];
+
+    // Every combination of three known variables
+    for unknown_a in 0..5 {
+        for unknown_b in (unknown_a + 1)..5 {
+            let mut known = all;
+            known[unknown_a] = None;
+            known[unknown_b] = None;
+
+            let solution = csuvat(Suvat {
+                displacement: known[0],
+                initial_velocity: known[1],
+                final_velocity: known[2],
+                acceleration: known[3],
+                time: known[4],
+            })
+            .unwrap_or_else(|| panic!("no solution for {:?}", known));
+
+            assert_close(solution.displacement, expected.displacement, 1e-9);
+            assert_close(solution.initial_velocity, expected.initial_velocity, 1e-9);
+            assert_close(solution.final_velocity, expected.final_velocity, 1e-9);
+            assert_close(solution.acceleration, expected.acceleration, 1e-9);
+            assert_close(solution.time, expected.time, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn suvat_edge_cases() {
+    // Too few known variables
+    assert_eq!(
+        csuvat(Suvat {
+            displacement: Some(1.0),
+            time: Some(1.0),
+            ..Default::default()
+        }),
+        None
+    );
+
+    // A ball thrown upwards with 10 m/s never reaches 10m
+    assert_eq!(
+        csuvat(Suvat {
+            displacement: Some(10.0),
+            initial_velocity: Some(10.0),
+            acceleration: Some(-STANDARD_GRAVITY),
+            ..Default::default()
+        }),
+        None
+    );
+
+    // It passes 3m twice, the earlier time is returned
+    let solution = csuvat(Suvat {
+        displacement: Some(3.0),
+        initial_velocity: Some(10.0),
+        acceleration: Some(-10.0),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_close(solution.final_velocity, 40f64.sqrt(), 1e-9);
+    assert_close(solution.time, (10.0 - 40f64.sqrt()) / 10.0, 1e-9);
+
+    // Constant velocity
+    let solution = csuvat(Suvat {
+        displacement: Some(10.0),
+        initial_velocity: Some(2.0),
+        acceleration: Some(0.0),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(solution.time, 5.0);
+    assert_eq!(solution.final_velocity, 2.0);
+
+    // A negative time is never a solution
+    let backwards = Suvat {
+        initial_velocity: Some(2.0),
+        acceleration: Some(3.0),
+        time: Some(-1.0),
+        ..Default::default()
+    };
+    assert_eq!(csuvat(backwards), None);
+
+    // At t = 0, u and a are known, but cannot be derived from the other variables
+    let start = Suvat {
+        initial_velocity: Some(2.0),
+        acceleration: Some(3.0),
+        time: Some(0.0),
+        ..Default::default()
+    };
+    assert_eq!(csuvat(start).unwrap().displacement, 0.0);
+    for known in [
+        Suvat {
+            initial_velocity: Some(2.0),
+            final_velocity: Some(3.0),
+            time: Some(0.0),
+            ..Default::default()
+        },
+        Suvat {
+            displacement: Some(0.0),
+            acceleration: Some(3.0),
+            time: Some(0.0),
+            ..Default::default()
+        },
+        Suvat {
+            displacement: Some(0.0),
+            final_velocity: Some(3.0),
+            time: Some(0.0),
+            ..Default::default()
+        },
+        Suvat {
+            displacement: Some(0.0),
+            initial_velocity: Some(2.0),
+            time: Some(0.0),
+            ..Default::default()
+        },
+        // t = 2s / (u + v) = 0
+        Suvat {
+            displacement: Some(0.0),
+            initial_velocity: Some(2.0),
+            final_velocity: Some(2.0),
+            ..Default::default()
+        },
+    ] {
+        assert_eq!(csuvat(known), None, "{known:?}");
+    }
+}
+
+#[test]
+fn suvat_extra_knowns() {
+    // A known final velocity that agrees with a = 0 does not prevent solving with s and u
+    let uniform = Suvat {
+        displacement: Some(10.0),
+        initial_velocity: Some(2.0),
+        final_velocity: Some(2.0),
+        acceleration: Some(0.0),
+        ..Default::default()
+    };
+    assert_eq!(csuvat(uniform).unwrap().time, 5.0);
+    assert_eq!(
+        csuvat(Suvat {
+            final_velocity: Some(3.0),
+            ..uniform
+        }),
+        None
+    );
+
+    // s = ut + at^2 / 2 = 32 and v = u + at = 14
+    let all = Suvat {
+        displacement: Some(32.0),
+        initial_velocity: Some(2.0),
+        final_velocity: Some(14.0),
+        acceleration: Some(3.0),
+        time: Some(4.0),
+    };
+    assert_eq!(csuvat(all).unwrap().final_velocity, 14.0);
+    for known in [
+        Suvat {
+            displacement: Some(999.0),
+            final_velocity: Some(-50.0),
+            ..all
+        },
+        Suvat {
+            displacement: Some(999.0),
+            ..all
+        },
+        Suvat {
+            final_velocity: Some(-50.0),
+            time: None,
+            ..all
+        },
+    ] {
+        assert_eq!(csuvat(known), None, "{known:?}");
+    }
+}
+
+#[test]
+fn momentum_and_impulse() {
+    // (speed, mass), like energy::skinetic_energy and relativity::momentum::smomentum
+    assert_eq!(momentum::smomentum(30.0, 1000.0), 30000.0);
+    assert_eq!(
+        momentum::smomentum_vec3(Vec3::new(1.0, 2.0, 3.0), 2.0),
+        Vec3::new(2.0, 4.0, 6.0)
+    );
+    assert_eq!(momentum::simpulse(10.0, 0.5), 5.0);
+    assert_eq!(
+        momentum::simpulse_vec3(Vec3::xaxis(), 2.0),
+        Vec3::new(2.0, 0.0, 0.0)
+    );
+    assert_eq!(momentum::cimpulse(2.0, 3.0, 8.0), 10.0);
+    assert_eq!(
+        momentum::cimpulse_vec3(2.0, Vec3::xaxis(), Vec3::yaxis()),
+        Vec3::new(-2.0, 2.0, 0.0)
+    );
+}
+
+#[test]
+fn energy_work_and_power() {
+    assert_eq!(energy::skinetic_energy(30.0, 1000.0), 450000.0);
+    assert_eq!(
+        energy::skinetic_energy_vec3(Vec3::new(3.0, 4.0, 0.0), 2.0),
+        25.0
+    );
+    assert_eq!(energy::spotential_energy(1.0, 1.0), STANDARD_GRAVITY);
+    assert_close(energy::cpotential_energy(80.0, 10.0, 1.62), 1296.0, 1e-9);
+    assert_eq!(energy::swork(10.0, 2.0), 20.0);
+    assert_close(
+        energy::cwork(10.0, 2.0, core::f64::consts::FRAC_PI_3),
+        10.0,
+        1e-9,
+    );
+    assert_eq!(
+        energy::swork_vec3(Vec3::new(1.0, 2.0, 0.0), Vec3::new(3.0, 0.0, 5.0)),
+        3.0
+    );
+    assert_eq!(energy::spower(100.0, 4.0), 25.0);
+    assert_eq!(energy::cpower(10.0, 3.0), 30.0);
+    assert_eq!(
+        energy::cpower_vec3(Vec3::xaxis() * 10.0, Vec3::new(3.0, 1.0, 0.0)),
+        30.0
+    );
+
+    // Work-energy theorem: the work done by a constant force equals the change in kinetic energy
+    let (mass, force, time) = (2.0, 4.0, 3.0);
+    let accel = force / mass;
+    let work = energy::swork(force, sdisplacement(0.0, accel, time));
+    assert_close(
+        work,
+        energy::skinetic_energy(sfinal_velocity(0.0, accel, time), mass),
+        1e-9,
+    );
+}
+
+#[test]
+fn single_precision() {
+    let solution = csuvat(Suvat {
+        displacement: Some(20f32),
+        initial_velocity: Some(0f32),
+        acceleration: Some(10f32),
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(solution.time, 2f32);
+    assert_eq!(solution.final_velocity, 20f32);
+    assert_eq!(energy::skinetic_energy(2f32, 4f32), 8f32);
+    assert_eq!(
+        sforce_vec3(2f32, Vec3::new(1f32, 0f32, 0f32)),
+        Vec3::new(2f32, 0f32, 0f32)
+    );
+}
@@ -0,0 +1,103 @@
+use core::f64::consts::PI;
+
+use crate::assert_relative;
+use mathonomy::consts::codata::*;
+use mathonomy::consts::SPEED_OF_LIGHT_SQUARED;
+
+#[test]
+fn lookup() {
+    assert_eq!(find("ħ"), Some(&REDUCED_PLANCK_CONSTANT));
+    assert_eq!(find("planck constant"), Some(&PLANCK_CONSTANT));
+    assert_eq!(find("ELECTRON MASS").unwrap().symbol, "mₑ");
+    assert_eq!(find("H"), None);
+
+    // Symbols are unique, so every constant can be found by its symbol
+    for constant in CONSTANTS.iter() {
+        assert_eq!(find(constant.symbol), Some(constant));
+        assert_eq!(find(constant.name), Some(constant));
+        assert_eq!(
+            constant.exact,
+            constant.uncertainty == 0f64,
+            "{}",
+            constant.name
+        );
+    }
+}
+
+#[test]
+fn consistency() {
+    assert_relative(
+        REDUCED_PLANCK_CONSTANT.value,
+        PLANCK_CONSTANT.value / (2f64 * PI),
+        1e-15,
+    );
+    assert_relative(
+        MOLAR_GAS_CONSTANT.value,
+        AVOGADRO_CONSTANT.value * BOLTZMANN_CONSTANT.value,
+        1e-15,
+    );
+    assert_relative(
+        VACUUM_ELECTRIC_PERMITTIVITY.value
+            * VACUUM_MAGNETIC_PERMEABILITY.value
+            * SPEED_OF_LIGHT_SQUARED,
+        1f64,
+        // Both are rounded to 11 digits
+        1e-10,
+    );
+    // α and 1/α agree within their uncertainties
+    let product = FINE_STRUCTURE_CONSTANT.value * INVERSE_FINE_STRUCTURE_CONSTANT.value;
+    assert!((product - 1f64).abs() < 2f64 * FINE_STRUCTURE_CONSTANT.relative_uncertainty());
+
+    assert_relative(
+        NEWTONIAN_CONSTANT_OF_GRAVITATION.relative_uncertainty(),
+        2.247e-5,
+        1e-2,
+    );
+    assert_relative(ELECTRON_MASS.relative_uncertainty(), 3.07e-10, 1e-2);
+}
+
+#[test]
+fn astronomical_constants() {
+    use mathonomy::consts::{astro, GRAVITATIONAL_CONSTANT, SPEED_OF_LIGHT};
+
+    assert_eq!(astro::find("pc"), Some(&astro::PARSEC));
+    assert_eq!(
+        astro::find("solar mass").unwrap().source,
+        "GM / G with G from CODATA 2018"
+    );
+    assert_eq!(PLANCK_CONSTANT.source, "CODATA 2018");
+    // Shared with CODATA, a single definition
+    assert_eq!(astro::find("g_n"), Some(&STANDARD_ACCELERATION_OF_GRAVITY));
+    assert_eq!(STANDARD_ACCELERATION_OF_GRAVITY.source, "3rd CGPM 1901");
+    for constant in astro::CONSTANTS.iter() {
+        assert_eq!(astro::find(constant.symbol), Some(constant));
+    }
+
+    // 1 pc is the distance at which 1 au spans one arcsecond (in the small angle approximation)
+    let arcsecond = PI / 648000f64;
+    assert_relative(
+        astro::ASTRONOMICAL_UNIT.value / astro::PARSEC.value,
+        arcsecond,
+        1e-15,
+    );
+    assert_eq!(astro::LIGHT_YEAR.value, 9460730472580800f64);
+    assert_eq!(
+        astro::LIGHT_YEAR.value,
+        SPEED_OF_LIGHT * astro::JULIAN_YEAR.value
+    );
+
+    // The masses are GM / G
+    for (gm, mass) in [
+        (astro::SOLAR_GM, astro::SOLAR_MASS),
+        (astro::EARTH_GM, astro::EARTH_MASS),
+        (astro::NOMINAL_JUPITER_GM, astro::JUPITER_MASS),
+    ] {
+        assert_relative(gm.value / GRAVITATIONAL_CONSTANT, mass.value, 1e-5);
+        assert_relative(
+            mass.relative_uncertainty(),
+            NEWTONIAN_CONSTANT_OF_GRAVITATION.relative_uncertainty(),
+            1e-2,
+        );
+        assert!(gm.relative_uncertainty() < 1e-8);
+    }
+}
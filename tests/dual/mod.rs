@@ -0,0 +1,112 @@
+use crate::assert_relative;
+use mathonomy::consts::SPEED_OF_LIGHT;
+use mathonomy::dual::*;
+use mathonomy::prelude::{NumericalExtensions, Real};
+use mathonomy::relativity::{energy::skinetic_energy, lorentz::slorentz};
+use mathonomy::vectors::{vec3::Vec3, GenericVector};
+
+#[test]
+fn arithmetic() {
+    let x = Dual::<2>::variable(3f64, 0);
+    let y = Dual::<2>::variable(4f64, 1);
+
+    assert_eq!((x * y).gradient, [4f64, 3f64]);
+    assert_eq!((x / y).gradient, [0.25f64, -3f64 / 16f64]);
+    assert_eq!((x - y * 2f64 + 1f64).gradient, [1f64, -2f64]);
+    assert_eq!((-x).gradient, [-1f64, 0f64]);
+    assert_eq!(NumericalExtensions::hypot(x, y), Dual::constant(5f64));
+    assert_eq!(NumericalExtensions::hypot(x, y).gradient, [0.6f64, 0.8f64]);
+    assert_eq!(NumericalExtensions::mul_add(x, y, x).gradient, [5f64, 3f64]);
+
+    // Constants and comparisons ignore the derivatives
+    assert_eq!(Dual::<2>::from_f64(2f64).gradient, [0f64; 2]);
+    assert_eq!(x, Dual::constant(3f64));
+    assert!(x < y);
+    assert_eq!(Real::max(x, y).gradient, [0f64, 1f64]);
+    assert_eq!(Real::abs(-x).gradient, [1f64, 0f64]);
+}
+
+#[test]
+fn elementary_functions() {
+    let x = 0.3f64;
+    let derivative = |f: fn(Dual) -> Dual| sderivative(f, x).1;
+
+    assert_relative(derivative(NumericalExtensions::sin), x.cos(), 1e-15);
+    assert_relative(derivative(NumericalExtensions::exp_m1), x.exp(), 1e-15);
+    assert_relative(derivative(NumericalExtensions::ln_1p), 1f64 / 1.3f64, 1e-15);
+    assert_relative(
+        derivative(NumericalExtensions::sqrt),
+        0.5f64 / x.sqrt(),
+        1e-15,
+    );
+    assert_relative(
+        derivative(NumericalExtensions::cbrt),
+        1f64 / (3f64 * x.cbrt().powi(2)),
+        1e-15,
+    );
+    assert_relative(
+        derivative(NumericalExtensions::asin),
+        1f64 / (1f64 - x * x).sqrt(),
+        1e-15,
+    );
+    assert_relative(
+        derivative(NumericalExtensions::atanh),
+        1f64 / (1f64 - x * x),
+        1e-15,
+    );
+    assert_relative(derivative(|d| d.powi(3)), 3f64 * x * x, 1e-15);
+    assert_relative(
+        derivative(|d| d.powf(Dual::constant(2.5))),
+        2.5f64 * x.powf(1.5),
+        1e-15,
+    );
+    // d/dx x^x = x^x (ln x + 1)
+    assert_relative(
+        derivative(|d| d.powf(d)),
+        x.powf(x) * (x.ln() + 1f64),
+        1e-15,
+    );
+    // d/dx atan2(1, x) = -1 / (1 + x^2)
+    assert_relative(
+        derivative(|d| Dual::constant(1f64).atan2(d)),
+        -1f64 / (1f64 + x * x),
+        1e-15,
+    );
+}
+
+#[test]
+fn relativity() {
+    // dγ/dv = γ^3 v / c^2
+    let (lorentz, slope) = sderivative(slorentz, 0.6 * SPEED_OF_LIGHT);
+    assert_relative(lorentz, 1.25, 1e-15);
+    assert_relative(slope, 3.908954240603344e-9, 1e-12);
+
+    // ∂E/∂v = γ^3 m v and ∂E/∂m = (γ - 1) c^2
+    let (energy, [by_speed, by_mass]) =
+        sgradient(|[v, m]| skinetic_energy(v, m), [0.6 * SPEED_OF_LIGHT, 2f64]);
+    assert_relative(energy, 4.493775893684088e16, 1e-12);
+    assert_relative(by_speed, 702638573.4375, 1e-12);
+    assert_relative(by_mass, 2.246887946842044e16, 1e-12);
+}
+
+#[test]
+fn classical_and_vectors() {
+    use mathonomy::classical::newtonian::kinematics::{sdisplacement, sfinal_velocity};
+
+    // The derivative of the displacement by the time is the velocity
+    let (_, velocity) = sderivative(
+        |t| sdisplacement(Dual::constant(3f64), Dual::constant(-9.81), t),
+        2f64,
+    );
+    assert_relative(velocity, sfinal_velocity(3f64, -9.81, 2f64), 1e-15);
+
+    // The gradient of the length of a vector is its direction
+    let position: Vec3<Dual<3>> = Vec3::new(
+        Dual::variable(1f64, 0),
+        Dual::variable(2f64, 1),
+        Dual::variable(2f64, 2),
+    );
+    assert_eq!(position.length().value, 3f64);
+    let [x, y, z] = position.length().gradient;
+    assert_eq!(Vec3::new(x, y, z), Vec3::new(1f64, 2f64, 2f64) / 3f64);
+}
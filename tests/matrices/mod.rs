@@ -0,0 +1,109 @@
+use mathonomy::matrices::{mat2::Mat2, mat3::Mat3, mat4::Mat4, GenericMatrix};
+use mathonomy::vectors::{vec2::Vec2, vec3::Vec3, vec4::Vec4, GenericVector};
+
+fn assert_close_mat4(a: Mat4, b: Mat4) {
+    for i in 0..4 {
+        assert!((a.row(i) - b.row(i)).length() < 1e-12, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+fn mat2() {
+    let mat = Mat2::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(mat.determinant(), -2.0);
+    assert_eq!(mat.trace(), 5.0);
+    assert_eq!(mat.transpose(), Mat2::new(1.0, 3.0, 2.0, 4.0));
+    assert_eq!(mat * Vec2::new(1.0, 1.0), Vec2::new(3.0, 7.0));
+    assert_eq!(mat * mat, Mat2::new(7.0, 10.0, 15.0, 22.0));
+    assert_eq!(mat.inverse().unwrap(), Mat2::new(-2.0, 1.0, 1.5, -0.5));
+    assert_eq!(mat * mat.inverse().unwrap(), Mat2::identity());
+    assert_eq!(Mat2::new(1.0, 2.0, 2.0, 4.0).inverse(), None);
+    assert_eq!(Mat2::new(f64::NAN, 2.0, 3.0, 4.0).inverse(), None);
+    assert_eq!(mat.col(1), Vec2::new(2.0, 4.0));
+    assert_eq!(Mat2::from_cols(mat.col(0), mat.col(1)), mat);
+
+    let rotated = Mat2::rotation(core::f64::consts::FRAC_PI_2) * Vec2::xaxis();
+    assert!((rotated - Vec2::yaxis()).length() < 1e-15);
+}
+
+#[test]
+fn mat3() {
+    let mat = Mat3::new(
+        1.0, 2.0, 3.0, //
+        0.0, 1.0, 4.0, //
+        5.0, 6.0, 0.0,
+    );
+
+    assert_eq!(mat.determinant(), 1.0);
+    assert_eq!(mat.trace(), 2.0);
+    assert_eq!(mat * Vec3::one(), Vec3::new(6.0, 5.0, 11.0));
+    assert_eq!(mat.transpose().row(0), mat.col(0));
+    assert_eq!(
+        mat.inverse().unwrap(),
+        Mat3::new(
+            -24.0, 18.0, 5.0, //
+            20.0, -15.0, -4.0, //
+            -5.0, 4.0, 1.0,
+        )
+    );
+    assert_eq!(mat * mat.inverse().unwrap(), Mat3::identity());
+    assert_eq!(
+        Mat3::from_diagonal(Vec3::new(1.0, 0.0, 1.0)).inverse(),
+        None
+    );
+    assert_eq!(mat - mat, Mat3::zero());
+    assert_eq!(-mat + mat * 2.0, mat);
+    assert_eq!((mat * 2.0) / 2.0, mat);
+}
+
+#[test]
+fn mat4() {
+    let mat = Mat4::new(
+        1.0, 1.0, 1.0, -1.0, //
+        1.0, 1.0, -1.0, 1.0, //
+        1.0, -1.0, 1.0, 1.0, //
+        -1.0, 1.0, 1.0, 1.0,
+    );
+
+    assert_eq!(mat.determinant(), -16.0);
+    assert_eq!(mat.trace(), 4.0);
+    assert_close_mat4(mat * mat.inverse().unwrap(), Mat4::identity());
+    assert_close_mat4(mat.inverse().unwrap() * mat, Mat4::identity());
+    assert_eq!(Mat4::zero().inverse(), None);
+    assert_eq!(mat * Vec4::one(), Vec4::filled(2.0));
+    assert_eq!(
+        Mat4::from_diagonal(Vec4::filled(1e100)).inverse(),
+        None,
+        "the determinant overflows"
+    );
+
+    // (AB)^T = B^T A^T for two different non-symmetric matrices
+    let a = Mat4::new(
+        1.0, 2.0, 0.0, -1.0, //
+        0.0, 3.0, 1.0, 2.0, //
+        4.0, 0.0, 1.0, 0.0, //
+        -2.0, 1.0, 0.0, 5.0,
+    );
+    let b = Mat4::new(
+        2.0, 0.0, 1.0, 3.0, //
+        -1.0, 1.0, 0.0, 0.0, //
+        0.0, 4.0, 2.0, 1.0, //
+        1.0, 0.0, -3.0, 1.0,
+    );
+    assert_ne!(a * b, b * a);
+    assert_eq!((a * b).transpose(), b.transpose() * a.transpose());
+
+    let transform = Mat4::translation(Vec3::new(1.0, 2.0, 3.0)) * Mat4::scaling(Vec3::filled(2.0));
+    assert_eq!(
+        transform.transform_point(Vec3::one()),
+        Vec3::new(3.0, 4.0, 5.0)
+    );
+    assert_eq!(
+        transform.transform_direction(Vec3::one()),
+        Vec3::filled(2.0)
+    );
+    assert_eq!(transform.determinant(), 8.0);
+    assert_close_mat4(transform * transform.inverse().unwrap(), Mat4::identity());
+    assert_eq!(Mat4::from_mat3(Mat3::identity()), Mat4::identity());
+}
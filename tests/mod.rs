@@ -1,2 +1,26 @@
+mod classical;
+mod consts;
+mod dual;
+mod matrices;
+mod prefixes;
+mod prelude;
+mod quantities;
+mod quaternions;
 mod relativity;
+mod tensors;
+mod uncertain;
+mod units;
 mod vectors;
+
+/// Asserts that `a` and `b` differ by at most `tolerance` times `b`
+fn assert_relative(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance * b.abs(), "{a} != {b}");
+}
+
+/// Asserts that `a` and `b` differ by at most `tolerance` times the larger of both, or `tolerance` itself near 0
+fn assert_close(a: f64, b: f64, tolerance: f64) {
+    assert!(
+        (a - b).abs() <= tolerance * a.abs().max(b.abs()).max(1f64),
+        "{a} != {b}"
+    );
+}
@@ -0,0 +1,41 @@
+use mathonomy::prelude::{BinaryPrefixes, SiPrefixes};
+
+#[test]
+fn si_prefixes() {
+    assert_eq!(5000f64.to_kilo(), 5f64);
+    assert_eq!(5f64.from_kilo(), 5000f64);
+    assert_eq!(1500f64.to_kilo(), 1.5f64);
+    assert_eq!(1.5f64.to_milli(), 1500f64);
+    assert_eq!(1500f64.from_milli(), 1.5f64);
+    assert_eq!(0.003f64.to_milli(), 3f64);
+    assert_eq!(2e9f64.to_giga(), 2f64);
+    assert_eq!(1e30f64.from_quecto(), 1f64);
+    assert_eq!(1e-30f64.to_quecto(), 1f64);
+    assert_eq!(1f64.from_quetta(), 1e30);
+    assert_eq!(1f64.to_centi(), 100f64);
+    assert_eq!(1f64.to_deca(), 0.1f64);
+}
+
+#[test]
+fn round_trips() {
+    // Decimal prefixes round twice, so a round trip may be off by 1 ULP
+    let assert_ulp =
+        |a: f64, b: f64| assert!((a - b).abs() <= f64::EPSILON * b.abs(), "{a} != {b}");
+
+    for value in [1f64, 0.1f64, 299_792_458f64, 6.62607015e-34] {
+        assert_ulp(value.to_micro().from_micro(), value);
+        assert_ulp(value.to_tera().from_tera(), value);
+        assert_ulp(value.to_yotta().from_yotta(), value);
+        assert_ulp(value.to_quecto().from_quecto(), value);
+        // Binary prefixes are exact
+        assert_eq!(value.to_gibi().from_gibi(), value);
+    }
+}
+
+#[test]
+fn binary_prefixes() {
+    assert_eq!(2048f64.to_kibi(), 2f64);
+    assert_eq!(1f64.from_mebi(), 1_048_576f64);
+    assert_eq!(1f64.from_yobi(), 2f64.powi(80));
+    assert_eq!(1f64.from_gibi().to_kibi(), 1_048_576f64);
+}
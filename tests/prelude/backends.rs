@@ -0,0 +1,102 @@
+//! Compares the `std` backend with the `libm` functions the `libm` backend forwards to.
+//! Only compiled with `std`, as the `libm` backend would be compared with itself
+#![cfg(feature = "std")]
+
+use mathonomy::prelude::NumericalExtensions;
+
+/// A function in both backends and the range they are compared on
+type Comparison<T> = (fn(T) -> T, fn(T) -> T, f64, f64);
+
+/// 2001 evenly spaced values in start..=end
+fn sweep(start: f64, end: f64) -> impl Iterator<Item = f64> + Clone {
+    (0..=2000).map(move |i| start + (end - start) * i as f64 / 2000f64)
+}
+
+#[test]
+fn correctly_rounded_functions() {
+    // The `std` backend uses the inherent methods for these
+    for x in sweep(0f64, 1e6) {
+        assert_eq!(NumericalExtensions::sqrt(x), libm::sqrt(x));
+        assert_eq!(NumericalExtensions::sqrt(x as f32), libm::sqrtf(x as f32));
+        for y in sweep(-10f64, 10f64).step_by(100) {
+            assert_eq!(
+                NumericalExtensions::mul_add(x, y, 0.1),
+                libm::fma(x, y, 0.1)
+            );
+            assert_eq!(
+                NumericalExtensions::mul_add(x as f32, y as f32, 0.1),
+                libm::fmaf(x as f32, y as f32, 0.1)
+            );
+        }
+    }
+}
+
+#[test]
+fn elementary_functions() {
+    // Including the ill-conditioned ends of acosh and atanh, where the platform's math library and libm disagree most
+    let functions: [Comparison<f64>; 19] = [
+        (NumericalExtensions::cbrt, libm::cbrt, -100.0, 100.0),
+        (NumericalExtensions::exp, libm::exp, -50.0, 50.0),
+        (NumericalExtensions::exp_m1, libm::expm1, -50.0, 50.0),
+        (NumericalExtensions::ln, libm::log, 0.0, 100.0),
+        (NumericalExtensions::ln_1p, libm::log1p, -1.0, 100.0),
+        (NumericalExtensions::log10, libm::log10, 0.0, 100.0),
+        (NumericalExtensions::log2, libm::log2, 0.0, 100.0),
+        (NumericalExtensions::sin, libm::sin, -100.0, 100.0),
+        (NumericalExtensions::cos, libm::cos, -100.0, 100.0),
+        (NumericalExtensions::tan, libm::tan, -100.0, 100.0),
+        (NumericalExtensions::asin, libm::asin, -1.0, 1.0),
+        (NumericalExtensions::acos, libm::acos, -1.0, 1.0),
+        (NumericalExtensions::atan, libm::atan, -100.0, 100.0),
+        (NumericalExtensions::sinh, libm::sinh, -50.0, 50.0),
+        (NumericalExtensions::cosh, libm::cosh, -50.0, 50.0),
+        (NumericalExtensions::tanh, libm::tanh, -20.0, 20.0),
+        (NumericalExtensions::asinh, libm::asinh, -100.0, 100.0),
+        (NumericalExtensions::acosh, libm::acosh, 1.0, 100.0),
+        (NumericalExtensions::atanh, libm::atanh, -1.0, 1.0),
+    ];
+    for (index, (a, b, start, end)) in functions.into_iter().enumerate() {
+        for x in sweep(start, end) {
+            assert_eq!(a(x).to_bits(), b(x).to_bits(), "function {index} at {x}");
+        }
+    }
+
+    let functions_f32: [Comparison<f32>; 19] = [
+        (NumericalExtensions::cbrt, libm::cbrtf, -100.0, 100.0),
+        (NumericalExtensions::exp, libm::expf, -50.0, 50.0),
+        (NumericalExtensions::exp_m1, libm::expm1f, -50.0, 50.0),
+        (NumericalExtensions::ln, libm::logf, 0.0, 100.0),
+        (NumericalExtensions::ln_1p, libm::log1pf, -1.0, 100.0),
+        (NumericalExtensions::log10, libm::log10f, 0.0, 100.0),
+        (NumericalExtensions::log2, libm::log2f, 0.0, 100.0),
+        (NumericalExtensions::sin, libm::sinf, -100.0, 100.0),
+        (NumericalExtensions::cos, libm::cosf, -100.0, 100.0),
+        (NumericalExtensions::tan, libm::tanf, -100.0, 100.0),
+        (NumericalExtensions::asin, libm::asinf, -1.0, 1.0),
+        (NumericalExtensions::acos, libm::acosf, -1.0, 1.0),
+        (NumericalExtensions::atan, libm::atanf, -100.0, 100.0),
+        (NumericalExtensions::sinh, libm::sinhf, -50.0, 50.0),
+        (NumericalExtensions::cosh, libm::coshf, -50.0, 50.0),
+        (NumericalExtensions::tanh, libm::tanhf, -20.0, 20.0),
+        (NumericalExtensions::asinh, libm::asinhf, -100.0, 100.0),
+        (NumericalExtensions::acosh, libm::acoshf, 1.0, 100.0),
+        (NumericalExtensions::atanh, libm::atanhf, -1.0, 1.0),
+    ];
+    for (index, (a, b, start, end)) in functions_f32.into_iter().enumerate() {
+        for x in sweep(start, end).map(|x| x as f32) {
+            assert_eq!(
+                a(x).to_bits(),
+                b(x).to_bits(),
+                "f32 function {index} at {x}"
+            );
+        }
+    }
+
+    for x in sweep(-10f64, 10f64) {
+        for y in sweep(-10f64, 10f64).step_by(20) {
+            assert_eq!(NumericalExtensions::atan2(x, y), libm::atan2(x, y));
+            assert_eq!(NumericalExtensions::hypot(x, y), libm::hypot(x, y));
+            assert_eq!(NumericalExtensions::powf(x.abs(), y), libm::pow(x.abs(), y));
+        }
+    }
+}
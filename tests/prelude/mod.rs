@@ -0,0 +1,141 @@
+mod backends;
+
+use crate::{assert_close, assert_relative};
+use mathonomy::prelude::NumericalExtensions;
+
+// The trait is called explicitly, as the inherent f64 methods would take precedence with `std`
+
+#[test]
+fn powers_and_roots() {
+    assert_eq!(NumericalExtensions::powi(3f64, 4), 81f64);
+    assert_eq!(NumericalExtensions::powi(2f64, -2), 0.25f64);
+    assert_eq!(NumericalExtensions::powi(5f64, 0), 1f64);
+    assert_eq!(NumericalExtensions::sqrt(16f64), 4f64);
+    assert_eq!(NumericalExtensions::cbrt(27f64), 3f64);
+    assert_eq!(NumericalExtensions::hypot(3f64, 4f64), 5f64);
+    assert_close(
+        NumericalExtensions::powf(2f64, 0.5),
+        core::f64::consts::SQRT_2,
+        1e-15,
+    );
+    assert!(NumericalExtensions::sqrt(-1f64).is_nan());
+}
+
+#[test]
+fn binary_exponentiation() {
+    // Both backends use binary exponentiation, whose rounding differs from a correctly rounded power
+    let f64_results = [
+        (1.1f64, 10, 0x4004bffc0c03023d),
+        (1.1, -7, 0x3fe06bca92ef4a05),
+        (0.1, 23, 0x3b282db34012b262),
+        (-3.7, 13, 0xc1773a83a6bee82d),
+        (1.0000001, 1000, 0x3ff00068dce2f143),
+    ];
+    for (x, n, bits) in f64_results {
+        assert_eq!(NumericalExtensions::powi(x, n).to_bits(), bits, "{x}^{n}");
+    }
+
+    let f32_results = [
+        (1.1f32, 10, 0x4025ffe3),
+        (0.1, -9, 0x4e6e6b23),
+        (-3.7, 13, 0xcbb9d41e),
+    ];
+    for (x, n, bits) in f32_results {
+        assert_eq!(NumericalExtensions::powi(x, n).to_bits(), bits, "{x}^{n}");
+    }
+}
+
+#[test]
+fn exponentials_and_logarithms() {
+    assert_close(NumericalExtensions::exp(1f64), core::f64::consts::E, 1e-15);
+    assert_close(NumericalExtensions::ln(core::f64::consts::E), 1f64, 1e-15);
+    assert_close(NumericalExtensions::log10(1000f64), 3f64, 1e-15);
+    assert_close(NumericalExtensions::log2(1024f64), 10f64, 1e-15);
+    assert_relative(NumericalExtensions::exp_m1(1e-20), 1e-20, 1e-15);
+    assert_relative(NumericalExtensions::ln_1p(1e-20), 1e-20, 1e-15);
+}
+
+#[test]
+fn trigonometry() {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    assert_close(NumericalExtensions::sin(FRAC_PI_2), 1f64, 1e-15);
+    assert_close(NumericalExtensions::cos(PI), -1f64, 1e-15);
+    assert_close(NumericalExtensions::tan(FRAC_PI_4), 1f64, 1e-15);
+    assert_close(NumericalExtensions::asin(1f64), FRAC_PI_2, 1e-15);
+    assert_close(NumericalExtensions::acos(-1f64), PI, 1e-15);
+    assert_close(NumericalExtensions::atan(1f64), FRAC_PI_4, 1e-15);
+    assert_close(
+        NumericalExtensions::atan2(1f64, -1f64),
+        3f64 * FRAC_PI_4,
+        1e-15,
+    );
+    assert_close(
+        NumericalExtensions::tanh(NumericalExtensions::atanh(0.5)),
+        0.5,
+        1e-15,
+    );
+    assert_close(
+        NumericalExtensions::sinh(NumericalExtensions::asinh(2f64)),
+        2f64,
+        1e-15,
+    );
+    assert_close(
+        NumericalExtensions::cosh(NumericalExtensions::acosh(2f64)),
+        2f64,
+        1e-15,
+    );
+}
+
+#[test]
+fn fused_multiply_add() {
+    // 0.1 * 10 - 1 is not 0 with a single rounding step
+    assert_eq!(
+        NumericalExtensions::mul_add(0.1f64, 10f64, -1f64),
+        5.551115123125783e-17
+    );
+}
+
+#[test]
+fn single_precision() {
+    assert_eq!(NumericalExtensions::powi(3f32, 4), 81f32);
+    assert_eq!(NumericalExtensions::powi(2f32, -2), 0.25f32);
+    assert_eq!(NumericalExtensions::sqrt(16f32), 4f32);
+    assert_eq!(NumericalExtensions::hypot(3f32, 4f32), 5f32);
+    // 0.1f32 * 10 - 1 is exactly 2^-26
+    assert_eq!(
+        NumericalExtensions::mul_add(0.1f32, 10f32, -1f32),
+        1.4901161e-8f32
+    );
+    assert!((NumericalExtensions::exp(1f32) - core::f32::consts::E).abs() <= f32::EPSILON * 3f32);
+    assert!(
+        (NumericalExtensions::atan2(1f32, -1f32) - 3f32 * core::f32::consts::FRAC_PI_4).abs()
+            <= 1e-6
+    );
+}
+
+#[test]
+fn real() {
+    use mathonomy::prelude::Real;
+
+    fn hypotenuse<T: Real>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    assert_eq!(hypotenuse(3f64, 4f64), 5f64);
+    assert_eq!(hypotenuse(3f32, 4f32), 5f32);
+
+    assert_eq!(<f64 as Real>::from_f64(0.1), 0.1f64);
+    assert_eq!(<f32 as Real>::from_f64(0.1), 0.1f32);
+    assert_eq!(Real::to_f64(0.5f32), 0.5f64);
+    assert_eq!(<f32 as Real>::ZERO + <f32 as Real>::ONE, 1f32);
+    assert_eq!(Real::clamp(2f32, -1f32, 1f32), 1f32);
+    assert!(Real::clamp(f32::NAN, -1f32, 1f32).is_nan());
+    // The same rules as the other implementations instead of the panics of f64::clamp
+    assert_eq!(Real::clamp(2f64, f64::NAN, 1f64), 1f64);
+    assert_eq!(Real::clamp(-2f64, -1f64, f64::NAN), -1f64);
+    assert_eq!(Real::clamp(0f64, 1f64, -1f64), 1f64);
+    assert_eq!(Real::clamp(2f64, 1f64, -1f64), -1f64);
+    assert_eq!(Real::clamp(-2f32, 1f32, -1f32), 1f32);
+    assert!(!Real::is_finite(f32::INFINITY));
+}
@@ -0,0 +1,48 @@
+use mathonomy::quantities::*;
+
+#[test]
+fn same_dimension() {
+    let a = Length::new(3.0);
+    let b = Length::new(4.0);
+
+    assert_eq!(a + b, Length::new(7.0));
+    assert_eq!(b - a, Length::new(1.0));
+    assert_eq!(-a, Length::new(-3.0));
+    assert_eq!(a * 2.0, Length::new(6.0));
+    assert_eq!(2.0 * a, Length::new(6.0));
+    assert_eq!(b / 2.0, Length::new(2.0));
+    assert_eq!(b / a, 4.0 / 3.0);
+    assert!(a < b);
+    assert_eq!(Energy::default().value(), 0.0);
+}
+
+#[test]
+fn derived_dimensions() {
+    let speed: Velocity = Length::new(100.0) / Time::new(10.0);
+    assert_eq!(speed, Velocity::new(10.0));
+    assert_eq!(speed * Time::new(2.0), Length::new(20.0));
+    assert_eq!(Length::new(100.0) / speed, Time::new(10.0));
+
+    let accel: Acceleration = speed / Time::new(5.0);
+    let force: Force = Mass::new(3.0) * accel;
+    assert_eq!(force, Force::new(6.0));
+    assert_eq!(force / accel, Mass::new(3.0));
+
+    let momentum: Momentum = Mass::new(3.0) * speed;
+    assert_eq!(momentum, force * Time::new(5.0));
+
+    let energy: Energy = force * Length::new(2.0);
+    assert_eq!(energy, Energy::new(12.0));
+    assert_eq!(momentum * speed, Energy::new(300.0));
+    assert_eq!(energy / Time::new(4.0), Power::new(3.0));
+    assert_eq!(force * speed, Power::new(60.0));
+
+    let area: Area = Length::new(3.0) * Length::new(2.0);
+    assert_eq!(area / Length::new(2.0), Length::new(3.0));
+}
+
+#[test]
+fn units() {
+    assert_eq!(Velocity::UNIT, "m/s");
+    assert_eq!(Energy::UNIT, "J");
+}
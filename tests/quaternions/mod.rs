@@ -0,0 +1,128 @@
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use mathonomy::matrices::{mat3::Mat3, GenericMatrix};
+use mathonomy::quaternions::euler::{EulerFrame, EulerOrder};
+use mathonomy::quaternions::quaternion::Quaternion;
+use mathonomy::vectors::{vec3::Vec3, GenericVector};
+
+fn assert_close(a: Vec3, b: Vec3) {
+    assert!((a - b).length() < 1e-12, "{:?} != {:?}", a, b);
+}
+
+fn assert_same_rotation(a: Quaternion, b: Quaternion) {
+    assert!(a.dot(b).abs() > 1f64 - 1e-12, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn axis_angle() {
+    let q = Quaternion::from_axis_angle(Vec3::zaxis(), FRAC_PI_2);
+    assert_close(q * Vec3::xaxis(), Vec3::yaxis());
+    assert_close(q * Vec3::zaxis(), Vec3::zaxis());
+
+    let (axis, angle) = q.to_axis_angle();
+    assert_close(axis, Vec3::zaxis());
+    assert!((angle - FRAC_PI_2).abs() < 1e-12);
+
+    assert_eq!(Quaternion::identity().to_axis_angle(), (Vec3::xaxis(), 0.0));
+}
+
+#[test]
+fn composition_and_inverse() {
+    let a = Quaternion::from_axis_angle(Vec3::zaxis(), FRAC_PI_2);
+    let b = Quaternion::from_axis_angle(Vec3::xaxis(), FRAC_PI_2);
+    let v = Vec3::new(1.0, 2.0, 3.0);
+
+    assert_close((a * b) * v, a * (b * v));
+    assert_close(a.conjugate() * (a * v), v);
+    assert_same_rotation(a * a.inverse().unwrap(), Quaternion::identity());
+    assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+    assert!((Quaternion::new(1.0, 2.0, 3.0, 4.0).normalize().norm() - 1.0).abs() < 1e-15);
+    assert_eq!(
+        Quaternion::new(2.0, 0.0, 0.0, 0.0).inverse(),
+        Some(Quaternion::new(0.5, 0.0, 0.0, 0.0))
+    );
+}
+
+#[test]
+fn euler_angles() {
+    let (yaw, pitch, roll) = (0.3, -0.7, 1.1);
+    let expected = Quaternion::from_axis_angle(Vec3::zaxis(), yaw)
+        * Quaternion::from_axis_angle(Vec3::yaxis(), pitch)
+        * Quaternion::from_axis_angle(Vec3::xaxis(), roll);
+
+    let intrinsic =
+        Quaternion::from_euler(EulerOrder::ZYX, EulerFrame::Intrinsic, [yaw, pitch, roll]);
+    let extrinsic =
+        Quaternion::from_euler(EulerOrder::XYZ, EulerFrame::Extrinsic, [roll, pitch, yaw]);
+
+    assert_same_rotation(intrinsic, expected);
+    assert_same_rotation(extrinsic, expected);
+
+    let proper = Quaternion::from_euler(
+        EulerOrder::ZXZ,
+        EulerFrame::Intrinsic,
+        [FRAC_PI_2, 0.0, FRAC_PI_2],
+    );
+    assert_same_rotation(proper, Quaternion::from_axis_angle(Vec3::zaxis(), PI));
+}
+
+#[test]
+fn rotation_matrices() {
+    let q = Quaternion::from_axis_angle(Vec3::new(1.0, -2.0, 0.5), 2.5);
+    let mat = q.to_rotation_matrix();
+    let v = Vec3::new(-1.0, 0.5, 4.0);
+
+    assert_close(mat * v, q * v);
+    assert!((mat.determinant() - 1.0).abs() < 1e-12);
+    assert_same_rotation(Quaternion::from_rotation_matrix(mat), q);
+
+    // A half turn has a trace of -1, which uses a different branch
+    let half_turn = Mat3::from_diagonal(Vec3::new(-1.0, -1.0, 1.0));
+    assert_same_rotation(
+        Quaternion::from_rotation_matrix(half_turn),
+        Quaternion::from_axis_angle(Vec3::zaxis(), PI),
+    );
+    assert_same_rotation(
+        Quaternion::from_rotation_matrix(Mat3::identity()),
+        Quaternion::identity(),
+    );
+}
+
+#[test]
+fn two_vectors() {
+    let from = Vec3::new(1.0, 1.0, 0.0);
+    let to = Vec3::new(0.0, 0.0, 3.0);
+
+    assert_close(
+        Quaternion::from_two_vectors(from, to) * from.normalize(),
+        to.normalize(),
+    );
+    assert_close(
+        Quaternion::from_two_vectors(Vec3::xaxis(), -Vec3::xaxis()) * Vec3::xaxis(),
+        -Vec3::xaxis(),
+    );
+    assert_same_rotation(
+        Quaternion::from_two_vectors(from, from * 2.0),
+        Quaternion::identity(),
+    );
+}
+
+#[test]
+fn interpolation() {
+    let start = Quaternion::identity();
+    let end = Quaternion::from_axis_angle(Vec3::zaxis(), FRAC_PI_2);
+
+    assert_same_rotation(start.slerp(end, 0.0), start);
+    assert_same_rotation(start.slerp(end, 1.0), end);
+    assert_same_rotation(
+        start.slerp(end, 0.5),
+        Quaternion::from_axis_angle(Vec3::zaxis(), FRAC_PI_2 / 2.0),
+    );
+    // q and -q are the same rotation, slerp takes the short path
+    assert_same_rotation(
+        start.slerp(-end, 0.5),
+        Quaternion::from_axis_angle(Vec3::zaxis(), FRAC_PI_2 / 2.0),
+    );
+    assert_same_rotation(start.nlerp(end, 0.5), start.slerp(end, 0.5));
+    assert!((start.nlerp(end, 0.3).norm() - 1.0).abs() < 1e-15);
+}
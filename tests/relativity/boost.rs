@@ -0,0 +1,102 @@
+use crate::assert_close;
+use mathonomy::consts::SPEED_OF_LIGHT;
+use mathonomy::relativity::boost::*;
+use mathonomy::relativity::fourvector::*;
+use mathonomy::relativity::kinematics::svelocity_addition_vec3;
+use mathonomy::relativity::lorentz::slorentz;
+use mathonomy::vectors::{vec3::Vec3, GenericVector};
+
+fn assert_close_4(a: FourVector, b: FourVector) {
+    for (a, b) in a.components().iter().zip(b.components().iter()) {
+        assert_close(*a, *b, 1e-9);
+    }
+}
+
+#[test]
+fn minkowski_product() {
+    let v = FourVector::new(5f64, 1f64, 2f64, 3f64);
+    assert_eq!(v.interval(MetricSignature::MostlyMinus), 11f64);
+    assert_eq!(v.interval(MetricSignature::MostlyPlus), -11f64);
+    assert_eq!(v.kind(1e-12), IntervalKind::TimeLike);
+    assert_eq!(
+        FourVector::new(1f64, 2f64, 0f64, 0f64).kind(1e-12),
+        IntervalKind::SpaceLike
+    );
+
+    let light = FourVector::from_event(2f64, Vec3::new(0f64, 2f64 * SPEED_OF_LIGHT, 0f64));
+    assert_eq!(light.kind(1e-12), IntervalKind::LightLike);
+    assert_close(light.time(), 2f64, 1e-9);
+}
+
+#[test]
+fn boosts() {
+    let c = SPEED_OF_LIGHT;
+    let boost = LorentzTransform::boost(Vec3::new(0.6 * c, 0f64, 0f64));
+
+    // A clock at rest at the origin, seen from a frame moving with 0.6c
+    let event = boost * FourVector::new(c, 0f64, 0f64, 0f64);
+    assert_close_4(event, FourVector::new(1.25 * c, -0.75 * c, 0f64, 0f64));
+
+    // Boosts along any direction keep the interval
+    let boost = LorentzTransform::boost(Vec3::new(0.3 * c, -0.4 * c, 0.5 * c));
+    let event = FourVector::new(3f64, 1f64, -2f64, 7f64);
+    let boosted = boost.apply(event);
+    assert_close(
+        boosted.interval(MetricSignature::MostlyPlus),
+        event.interval(MetricSignature::MostlyPlus),
+        1e-9,
+    );
+    assert_close_4(boost.inverse() * boosted, event);
+
+    // The origin of the moving frame moves with the boost velocity
+    let velocity = Vec3::new(0.3 * c, -0.4 * c, 0.5 * c);
+    let origin = LorentzTransform::boost(velocity).inverse() * FourVector::new(c, 0f64, 0f64, 0f64);
+    assert_close(origin.t, slorentz(velocity.length()) * c, 1e-9);
+    let moving = origin.spatial() / origin.time();
+    assert_close(moving.x, velocity.x, 1e-9);
+    assert_close(moving.y, velocity.y, 1e-9);
+    assert_close(moving.z, velocity.z, 1e-9);
+}
+
+#[test]
+fn wigner_rotation() {
+    let c = SPEED_OF_LIGHT;
+
+    // Collinear boosts do not rotate
+    let (velocity, rotation) = scompose_boosts(
+        Vec3::new(0.5 * c, 0f64, 0f64),
+        Vec3::new(0.5 * c, 0f64, 0f64),
+    );
+    assert_close(velocity.x, 0.8 * c, 1e-9);
+    assert_close(rotation.to_axis_angle().1, 0f64, 1e-9);
+
+    // Perpendicular boosts: cos(θ) = (γ1 + γ2) / (1 + γ1γ2)
+    let (first, second) = (
+        Vec3::new(0.6 * c, 0f64, 0f64),
+        Vec3::new(0f64, 0.8 * c, 0f64),
+    );
+    let (velocity, rotation) = scompose_boosts(first, second);
+    let expected = svelocity_addition_vec3(first, second);
+    assert_close(velocity.x, expected.x, 1e-9);
+    assert_close(velocity.y, expected.y, 1e-9);
+
+    let (g1, g2) = (1.25, 5f64 / 3f64);
+    let (axis, angle) = rotation.to_axis_angle();
+    assert_close(angle.cos(), (g1 + g2) / (1f64 + g1 * g2), 1e-9);
+    assert_close(axis.z.abs(), 1f64, 1e-9);
+    assert_eq!(swigner_rotation(first, second), rotation);
+
+    // Recomposing the boost and the rotation gives back the original transformation
+    let composed = LorentzTransform::boost(first).then(LorentzTransform::boost(second));
+    let recomposed = LorentzTransform::rotation(rotation) * LorentzTransform::boost(velocity);
+    for (a, b) in composed
+        .matrix
+        .rows
+        .iter()
+        .zip(recomposed.matrix.rows.iter())
+    {
+        for (a, b) in [(a.x, b.x), (a.y, b.y), (a.z, b.z), (a.w, b.w)] {
+            assert_close(a, b, 1e-9);
+        }
+    }
+}
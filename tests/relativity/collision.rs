@@ -0,0 +1,87 @@
+use crate::assert_relative;
+use mathonomy::consts::{SPEED_OF_LIGHT, SPEED_OF_LIGHT_SQUARED};
+use mathonomy::relativity::collision::*;
+use mathonomy::relativity::energy::srest_energy;
+use mathonomy::vectors::{vec3::Vec3, GenericVector};
+
+const MEV: f64 = 1.602176634e-13;
+const PROTON: f64 = 938.27208816 * MEV / SPEED_OF_LIGHT_SQUARED;
+const PION: f64 = 139.57039 * MEV / SPEED_OF_LIGHT_SQUARED;
+const MUON: f64 = 105.6583755 * MEV / SPEED_OF_LIGHT_SQUARED;
+
+#[test]
+fn invariant_mass() {
+    let velocity = Vec3::new(0.3, -0.5, 0.6) * SPEED_OF_LIGHT;
+    assert_relative(
+        sinvariant_mass([sfour_momentum(velocity, PROTON)]),
+        PROTON,
+        1e-14,
+    );
+
+    // Two photons flying apart have the mass 2E / c^2, collinear photons have none
+    let a = cfour_momentum(1f64, Vec3::new(1f64 / SPEED_OF_LIGHT, 0f64, 0f64));
+    let b = cfour_momentum(1f64, Vec3::new(-1f64 / SPEED_OF_LIGHT, 0f64, 0f64));
+    assert_relative(
+        sinvariant_mass([a, b]),
+        2f64 / SPEED_OF_LIGHT_SQUARED,
+        1e-15,
+    );
+    assert_eq!(sinvariant_mass([a, a]), 0f64);
+    assert_eq!(senergy_from_four_momentum(a + b), 2f64);
+}
+
+#[test]
+fn thresholds() {
+    // p + p -> p + p + p + p̄
+    let fixed = sthreshold_fixed_target(PROTON, PROTON, [PROTON; 4]);
+    assert_relative(fixed, 6f64 * srest_energy(PROTON), 1e-14);
+    let collider = sthreshold_collider(PROTON, [PROTON; 4]);
+    assert_relative(collider, srest_energy(PROTON), 1e-14);
+
+    // γ = 7.09 is above the threshold (γ = 7), γ = 5.03 below
+    let target = sfour_momentum(Vec3::zero(), PROTON);
+    let fast = sfour_momentum(Vec3::new(0.99 * SPEED_OF_LIGHT, 0f64, 0f64), PROTON);
+    let slow = sfour_momentum(Vec3::new(0.98 * SPEED_OF_LIGHT, 0f64, 0f64), PROTON);
+    assert!(sinvariant_mass([fast, target]) > 4f64 * PROTON);
+    assert!(sinvariant_mass([slow, target]) < 4f64 * PROTON);
+
+    // π + μ -> μ + μ releases energy, as the products are lighter than the pion and the muon
+    assert_eq!(sthreshold_fixed_target(PION, MUON, [MUON, MUON]), 0f64);
+}
+
+#[test]
+fn two_body_decay() {
+    // π⁺ -> μ⁺ + ν
+    let momentum = stwo_body_decay_momentum(PION, MUON, 0f64).unwrap();
+    assert_relative(momentum * SPEED_OF_LIGHT / MEV, 29.792140909877446, 1e-12);
+    assert_eq!(stwo_body_decay_momentum(MUON, PION, 0f64), None);
+    assert_eq!(stwo_body_decay_momentum(PION, MUON, MUON), None);
+
+    let (muon, neutrino) = stwo_body_decay(PION, MUON, 0f64, Vec3::new(1f64, 1f64, 0f64)).unwrap();
+    assert!((muon + neutrino).spatial().length() < 1e-35);
+    assert_relative(
+        senergy_from_four_momentum(muon + neutrino),
+        srest_energy(PION),
+        1e-14,
+    );
+    assert_relative(sinvariant_mass([muon]), MUON, 1e-12);
+
+    // Without a direction, there are no four-momenta
+    assert_eq!(stwo_body_decay(PION, MUON, 0f64, Vec3::zero()), None);
+    assert_eq!(
+        stwo_body_decay(PION, MUON, 0f64, Vec3::new(f64::NAN, 0f64, 0f64)),
+        None
+    );
+
+    // In flight, energy and momentum of the parent are shared by the products
+    let pion = sfour_momentum(Vec3::new(0f64, 0f64, 0.9 * SPEED_OF_LIGHT), PION);
+    let (muon, neutrino) = ctwo_body_decay(pion, MUON, 0f64, Vec3::new(0f64, 1f64, 0f64)).unwrap();
+    let total = muon + neutrino;
+    for (a, b) in total.components().iter().zip(pion.components()) {
+        assert_relative(*a, b, 1e-12);
+    }
+    assert_relative(sinvariant_mass([muon]), MUON, 1e-10);
+    assert_relative(sinvariant_mass([muon, neutrino]), PION, 1e-12);
+    // Emitted sideways in the rest frame, but forward in the lab
+    assert!(muon.z > 0f64 && neutrino.z > 0f64);
+}
@@ -0,0 +1,108 @@
+use core::f64::consts::FRAC_PI_3;
+
+use crate::assert_close;
+use mathonomy::matrices::mat4::Mat4;
+use mathonomy::relativity::curvature::*;
+use mathonomy::relativity::geodesic::Metric;
+use mathonomy::vectors::vec4::Vec4;
+
+const MASS: f64 = 1f64;
+const POINT: [f64; 4] = [0f64, 7f64, FRAC_PI_3, 0.4];
+const STEP: f64 = 1e-3;
+
+fn schwarzschild(x: [f64; 4]) -> Mat4 {
+    Metric::Schwarzschild { mass: MASS }.covariant(x[1], x[2])
+}
+
+#[test]
+fn schwarzschild_christoffel_symbols() {
+    let gamma = christoffel(schwarzschild, POINT, STEP).unwrap();
+    let [_, r, theta, _] = POINT;
+    let m = MASS;
+
+    let expected = [
+        ([0, 0, 1], m / (r * (r - 2f64 * m))),
+        ([0, 1, 0], m / (r * (r - 2f64 * m))),
+        ([1, 0, 0], m * (r - 2f64 * m) / r.powi(3)),
+        ([1, 1, 1], -m / (r * (r - 2f64 * m))),
+        ([1, 2, 2], -(r - 2f64 * m)),
+        ([1, 3, 3], -(r - 2f64 * m) * theta.sin().powi(2)),
+        ([2, 1, 2], 1f64 / r),
+        ([2, 3, 3], -theta.sin() * theta.cos()),
+        ([3, 1, 3], 1f64 / r),
+        ([3, 2, 3], theta.cos() / theta.sin()),
+        ([0, 0, 0], 0f64),
+        ([2, 2, 2], 0f64),
+    ];
+    for (index, value) in expected {
+        assert_close(gamma.get(index), value, 1e-10);
+    }
+}
+
+#[test]
+fn schwarzschild_curvature() {
+    let [_, r, theta, _] = POINT;
+    let m = MASS;
+
+    let riemann = riemann(schwarzschild, POINT, STEP).unwrap();
+    assert_close(
+        riemann.get([2, 3, 2, 3]),
+        2f64 * m * theta.sin().powi(2) / r,
+        1e-7,
+    );
+    assert_close(
+        riemann.get([1, 0, 1, 0]),
+        -2f64 * m * (r - 2f64 * m) / r.powi(4),
+        1e-7,
+    );
+    // Antisymmetric in the last two indices
+    assert_close(riemann.get([2, 3, 3, 2]), -riemann.get([2, 3, 2, 3]), 1e-7);
+
+    // Vacuum
+    for component in ricci(schwarzschild, POINT, STEP).unwrap().components {
+        assert_close(component, 0f64, 1e-7);
+    }
+    assert_close(
+        ricci_scalar(schwarzschild, POINT, STEP).unwrap(),
+        0f64,
+        1e-7,
+    );
+
+    let kretschmann = kretschmann_scalar(schwarzschild, POINT, STEP).unwrap();
+    let expected = 48f64 * m * m / r.powi(6);
+    assert!(
+        (kretschmann - expected).abs() < 1e-6 * expected,
+        "{kretschmann} != {expected}"
+    );
+}
+
+#[test]
+fn flat_spacetime() {
+    // Minkowski in spherical coordinates has Christoffel symbols, but no curvature
+    let spherical = |[_, r, theta, _]: [f64; 4]| {
+        Mat4::from_diagonal(Vec4::new(-1f64, 1f64, r * r, (r * theta.sin()).powi(2)))
+    };
+    assert_close(
+        christoffel(spherical, POINT, STEP).unwrap().get([2, 1, 2]),
+        1f64 / POINT[1],
+        1e-10,
+    );
+    for component in riemann(spherical, POINT, STEP).unwrap().components {
+        assert_close(component, 0f64, 1e-7);
+    }
+}
+
+#[test]
+fn singular_metric() {
+    // g_φφ = r^2 sin^2(θ) is 0 on the axis
+    let axis = [0f64, 7f64, 0f64, 0f64];
+    assert!(christoffel(schwarzschild, axis, STEP).is_none());
+    assert!(ricci(schwarzschild, axis, STEP).is_none());
+    assert!(ricci_scalar(schwarzschild, axis, STEP).is_none());
+    assert!(kretschmann_scalar(schwarzschild, axis, STEP).is_none());
+
+    // The metric is regular at the point, but not at the points sampled for the derivatives of Γ
+    let near_axis = [0f64, 7f64, STEP, 0f64];
+    assert!(christoffel(schwarzschild, near_axis, STEP).is_some());
+    assert!(riemann(schwarzschild, near_axis, STEP).is_none());
+}
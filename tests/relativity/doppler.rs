@@ -0,0 +1,49 @@
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use crate::assert_close;
+use mathonomy::consts::SPEED_OF_LIGHT;
+use mathonomy::relativity::doppler::*;
+use mathonomy::vectors::{vec3::Vec3, GenericVector};
+
+#[test]
+fn doppler_shift() {
+    let speed = 0.6 * SPEED_OF_LIGHT;
+    assert_close(sdoppler(1f64, speed), 0.5, 1e-12);
+    assert_close(sdoppler(1f64, -speed), 2f64, 1e-12);
+    assert_close(sdoppler_transverse(1f64, speed), 0.8, 1e-12);
+
+    // Approaching, receding and transverse sources
+    assert_close(cdoppler(1f64, speed, 0f64), 2f64, 1e-12);
+    assert_close(cdoppler(1f64, speed, PI), 0.5, 1e-12);
+    assert_close(cdoppler(1f64, speed, FRAC_PI_2), 0.8, 1e-12);
+}
+
+#[test]
+fn redshift() {
+    assert_close(sredshift(0.6 * SPEED_OF_LIGHT), 1f64, 1e-12);
+    assert_close(sspeed_from_redshift(1f64), 0.6 * SPEED_OF_LIGHT, 1e-12);
+    assert_close(sspeed_from_redshift(sredshift(-1e4)), -1e4, 1e-12);
+
+    // Slow sources follow the classical z = v / c, with a second order correction of β^2 / 2
+    let (z, beta) = (sredshift(1f64), 1f64 / SPEED_OF_LIGHT);
+    assert!((z - beta - beta.powi(2) / 2f64).abs() < 1e-15 * z);
+}
+
+#[test]
+fn aberration() {
+    let speed = 0.5 * SPEED_OF_LIGHT;
+
+    // A source perpendicular to the motion appears at cos(θ') = β
+    assert_close(saberration(FRAC_PI_2, speed), 0.5f64.acos(), 1e-12);
+    assert_close(saberration(0f64, speed), 0f64, 1e-12);
+
+    let apparent = saberration_vec3(Vec3::new(0f64, 0f64, 2f64), Vec3::new(speed, 0f64, 0f64));
+    assert_close(apparent.length(), 1f64, 1e-12);
+    assert_close(apparent.x, 0.5, 1e-12);
+    assert_close(apparent.z, 0.75f64.sqrt(), 1e-12);
+
+    let angle = 2f64;
+    let direction = Vec3::new(angle.cos(), angle.sin(), 0f64);
+    let apparent = saberration_vec3(direction, Vec3::new(speed, 0f64, 0f64));
+    assert_close(apparent.x.acos(), saberration(angle, speed), 1e-12);
+}
@@ -6,3 +6,35 @@ use mathonomy::consts::*;
 fn relativistic_energy() {
     assert_eq!(skinetic_energy(SPEED_OF_LIGHT, 1f64), INFINITY)
 }
+
+#[test]
+fn relativistic_energy_checked() {
+    use mathonomy::relativity::energy::*;
+    use mathonomy::relativity::error::RelativityError;
+
+    let speed = SPEED_OF_LIGHT / 2f64;
+    assert_eq!(
+        skinetic_energy_checked(speed, 2f64),
+        Ok(skinetic_energy(speed, 2f64))
+    );
+    assert_eq!(
+        skinetic_energy_checked(SPEED_OF_LIGHT, 1f64),
+        Err(RelativityError::SuperluminalSpeed)
+    );
+    assert_eq!(
+        skinetic_energy_checked(speed, -1f64),
+        Err(RelativityError::NegativeMass)
+    );
+    assert_eq!(
+        skinetic_energy_checked(speed, f64::NAN),
+        Err(RelativityError::NonFiniteInput)
+    );
+    assert_eq!(
+        ckinetic_energy_checked(speed, 1f64, f64::INFINITY),
+        Err(RelativityError::NonFiniteInput)
+    );
+    assert_eq!(
+        ckinetic_energy_checked(speed, 1f64, 2f64),
+        Ok(ckinetic_energy(speed, 1f64, 2f64))
+    );
+}
@@ -0,0 +1,154 @@
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use crate::assert_relative;
+use mathonomy::relativity::error::RelativityError;
+use mathonomy::relativity::fourvector::FourVector;
+use mathonomy::relativity::geodesic::*;
+
+/// Solves g_μν u^μ u^ν = norm for u^t
+fn timelike(
+    metric: Metric,
+    position: FourVector,
+    r: f64,
+    theta: f64,
+    phi: f64,
+    norm: f64,
+) -> FourVector {
+    let g = metric.covariant(position.x, position.y);
+    let [g_t, g_r, g_theta, g_phi] = g.rows;
+    let spatial = g_r.y * r * r + g_theta.z * theta * theta + g_phi.w * phi * phi;
+    // g_tt t^2 + 2 g_tφ φ t + spatial - norm = 0, taking the future pointing root
+    let (a, b, c) = (g_t.x, 2f64 * g_t.w * phi, spatial - norm);
+    let t = (-b - (b * b - 4f64 * a * c).sqrt()) / (2f64 * a);
+    FourVector::new(t, r, theta, phi)
+}
+
+#[test]
+fn schwarzschild_circular_orbit() {
+    let metric = Metric::Schwarzschild { mass: 1f64 };
+    let r = 10f64;
+    let energy = (1f64 - 2f64 / r) / (1f64 - 3f64 / r).sqrt();
+    let angular_momentum = r.sqrt() / (1f64 - 3f64 / r).sqrt();
+
+    let position = FourVector::new(0f64, r, FRAC_PI_2, 0f64);
+    let velocity = FourVector::new(
+        energy / (1f64 - 2f64 / r),
+        0f64,
+        0f64,
+        angular_momentum / (r * r),
+    );
+    let geodesic = Geodesic::new(metric, position, velocity, 0.5).unwrap();
+    assert_relative(geodesic.state().energy(), energy, 1e-15);
+
+    let last = geodesic.take(2000).last().unwrap();
+    assert_relative(last.position.x, r, 1e-9);
+    assert_eq!(last.energy(), energy);
+    assert_eq!(last.angular_momentum(), angular_momentum);
+    assert_relative(
+        metric.hamiltonian(last.position, last.momentum),
+        -0.5,
+        1e-12,
+    );
+    // Kepler's third law holds in Schwarzschild coordinates, dφ/dt = SquareRoot(M / r^3)
+    assert_relative(last.position.z / last.position.t, r.powf(-1.5), 1e-9);
+}
+
+#[test]
+fn kerr_conserved_quantities() {
+    let metric = Metric::Kerr {
+        mass: 1f64,
+        spin: 0.9,
+    };
+    let position = FourVector::new(0f64, 12f64, FRAC_PI_2 - 0.3, 0f64);
+    let velocity = timelike(metric, position, 0.01, 0.01, 0.022, -1f64);
+    let geodesic = Geodesic::new(metric, position, velocity, 0.1).unwrap();
+
+    let first = geodesic.state();
+    let carter = metric.carter_constant(first.position, first.momentum);
+    assert_relative(
+        metric.hamiltonian(first.position, first.momentum),
+        -0.5,
+        1e-12,
+    );
+    assert!(carter > 0f64);
+
+    let mut count = 0;
+    let mut min_theta = PI;
+    for state in geodesic.take(20_000) {
+        count += 1;
+        min_theta = min_theta.min(state.position.y);
+        assert_eq!(state.energy(), first.energy());
+        assert_eq!(state.angular_momentum(), first.angular_momentum());
+        assert_relative(
+            metric.hamiltonian(state.position, state.momentum),
+            -0.5,
+            1e-9,
+        );
+        assert_relative(
+            metric.carter_constant(state.position, state.momentum),
+            carter,
+            1e-8,
+        );
+    }
+    // A bound orbit that leaves the starting plane
+    assert_eq!(count, 20_000);
+    assert!(min_theta < FRAC_PI_2 - 0.3);
+}
+
+#[test]
+fn light_rays() {
+    // A light ray that passes a spinning black hole keeps H = 0
+    let metric = Metric::Kerr {
+        mass: 1f64,
+        spin: 0.5,
+    };
+    let position = FourVector::new(0f64, 30f64, FRAC_PI_2 - 0.2, 0f64);
+    let velocity = timelike(metric, position, -1f64, 0f64, 0.01, 0f64);
+    let geodesic = Geodesic::new(metric, position, velocity, 0.05).unwrap();
+    let carter = metric.carter_constant(position, geodesic.state().momentum);
+
+    let last = geodesic.take(3000).last().unwrap();
+    assert!(metric.hamiltonian(last.position, last.momentum).abs() < 1e-10);
+    assert_relative(
+        metric.carter_constant(last.position, last.momentum),
+        carter,
+        1e-8,
+    );
+    assert!(last.position.x > 30f64);
+
+    // A light ray falling straight in stops at the horizon
+    let metric = Metric::Schwarzschild { mass: 1f64 };
+    let position = FourVector::new(0f64, 20f64, FRAC_PI_2, 0f64);
+    let velocity = FourVector::new(1f64 / (1f64 - 2f64 / 20f64), -1f64, 0f64, 0f64);
+    let last = Geodesic::new(metric, position, velocity, 0.01)
+        .unwrap()
+        .last()
+        .unwrap();
+    assert!(last.position.x < 2.01 && last.position.x > metric.horizon_radius());
+
+    // Without a horizon, there is nowhere to stop
+    let naked = Metric::Kerr {
+        mass: 1f64,
+        spin: 1.5,
+    };
+    assert!(naked.horizon_radius().is_nan());
+    assert_eq!(
+        Geodesic::new(naked, position, velocity, 0.01),
+        Err(RelativityError::NakedSingularity)
+    );
+    let extremal = Metric::Kerr {
+        mass: 1f64,
+        spin: -1f64,
+    };
+    assert_eq!(extremal.horizon_radius(), 1f64);
+    assert!(Geodesic::new(extremal, position, velocity, 0.01).is_ok());
+    assert_eq!(
+        Geodesic::new(
+            Metric::Schwarzschild { mass: -1f64 },
+            position,
+            velocity,
+            0.01
+        ),
+        Err(RelativityError::NegativeMass)
+    );
+}
@@ -0,0 +1,115 @@
+use crate::assert_close;
+use mathonomy::consts::{SPEED_OF_LIGHT, STANDARD_GRAVITY};
+use mathonomy::relativity::kinematics::*;
+use mathonomy::relativity::rocket::*;
+use mathonomy::vectors::{vec3::Vec3, GenericVector};
+
+#[test]
+fn time_dilation_and_length_contraction() {
+    assert_close(stime_dilation(1f64, 0.6 * SPEED_OF_LIGHT), 1.25, 1e-9);
+    assert_close(ctime_dilation(2f64, 1.25), 2.5, 1e-9);
+    assert_close(
+        slength_contraction(100f64, 0.8 * SPEED_OF_LIGHT),
+        60f64,
+        1e-9,
+    );
+    assert_eq!(stime_dilation(3f64, 0f64), 3f64);
+}
+
+#[test]
+fn velocity_addition() {
+    let half = SPEED_OF_LIGHT / 2f64;
+    assert_close(svelocity_addition(half, half), 0.8 * SPEED_OF_LIGHT, 1e-9);
+    assert_close(svelocity_addition(half, -half), 0f64, 1e-9);
+    assert_close(
+        svelocity_addition(half, SPEED_OF_LIGHT),
+        SPEED_OF_LIGHT,
+        1e-9,
+    );
+
+    // Collinear vectors match the scalar form
+    let combined =
+        svelocity_addition_vec3(Vec3::new(half, 0f64, 0f64), Vec3::new(half, 0f64, 0f64));
+    assert_close(combined.x, 0.8 * SPEED_OF_LIGHT, 1e-9);
+    assert_close(combined.y, 0f64, 1e-9);
+
+    // A perpendicular velocity is slowed down by time dilation of the moving frame
+    let c = SPEED_OF_LIGHT;
+    let combined = svelocity_addition_vec3(
+        Vec3::new(0.6 * c, 0f64, 0f64),
+        Vec3::new(0f64, 0.6 * c, 0f64),
+    );
+    assert_close(combined.x, 0.6 * c, 1e-9);
+    assert_close(combined.y, 0.48 * c, 1e-9);
+    assert!(combined.length() < c);
+}
+
+#[test]
+fn rapidity() {
+    let (u, v) = (0.3 * SPEED_OF_LIGHT, 0.9 * SPEED_OF_LIGHT);
+    assert_close(
+        sspeed_from_rapidity(srapidity(u) + srapidity(v)),
+        svelocity_addition(u, v),
+        1e-9,
+    );
+    assert_close(crapidity_from_lorentz(1.25), crapidity(0.6), 1e-9);
+    assert_close(clorentz_from_rapidity(crapidity(0.6)), 1.25, 1e-9);
+    assert_close(cbeta_from_rapidity(crapidity(-0.6)), -0.6, 1e-9);
+}
+
+#[test]
+fn proper_acceleration() {
+    let speed = 0.6 * SPEED_OF_LIGHT;
+    assert_close(sproper_acceleration(speed, 1f64), 1.25f64.powi(3), 1e-9);
+    assert_close(cproper_acceleration(2f64, 3f64), 24f64, 1e-9);
+
+    let velocity = Vec3::new(speed, 0f64, 0f64);
+    let parallel = sproper_acceleration_vec3(velocity, Vec3::new(1f64, 0f64, 0f64));
+    assert_close(parallel.x, 1.25f64.powi(3), 1e-9);
+    let perpendicular = sproper_acceleration_vec3(velocity, Vec3::new(0f64, 1f64, 0f64));
+    assert_close(perpendicular.x, 0f64, 1e-9);
+    assert_close(perpendicular.y, 1.25f64.powi(2), 1e-9);
+}
+
+#[test]
+fn rocket() {
+    // After a short time, the rocket follows Newtonian mechanics
+    let state = srocket(STANDARD_GRAVITY, 1f64);
+    assert!((state.distance - STANDARD_GRAVITY / 2f64).abs() < 1e-9);
+    assert!((state.speed - STANDARD_GRAVITY).abs() < 1e-9);
+
+    // One year on board at 1g: t ≈ 1.19 years, v ≈ 0.77c, γ ≈ 1.58
+    let year = 365.25 * 86400f64;
+    let state = srocket(STANDARD_GRAVITY, year);
+    assert!((state.coordinate_time / year - 1.19).abs() < 0.01);
+    assert!((state.speed / SPEED_OF_LIGHT - 0.77).abs() < 0.01);
+    assert!((state.lorentz - 1.58).abs() < 0.01);
+    assert_close(
+        state.lorentz,
+        1f64 / (1f64 - (state.speed / SPEED_OF_LIGHT).powi(2)).sqrt(),
+        1e-9,
+    );
+
+    let same = srocket_coordinate_time(STANDARD_GRAVITY, state.coordinate_time);
+    assert_close(same.proper_time, year, 1e-9);
+    assert_close(same.distance, state.distance, 1e-9);
+}
+
+#[test]
+fn rocket_delta_v() {
+    // A photon rocket reaches (R^2 - 1) / (R^2 + 1) c
+    assert_close(
+        srocket_delta_v(SPEED_OF_LIGHT, 10f64),
+        99f64 / 101f64 * SPEED_OF_LIGHT,
+        1e-9,
+    );
+    assert_close(
+        crocket_delta_v(SPEED_OF_LIGHT, 10f64, 1f64),
+        99f64 / 101f64 * SPEED_OF_LIGHT,
+        1e-9,
+    );
+
+    // Slow exhausts follow the classical Tsiolkovsky equation
+    let classical = 4000f64 * 10f64.ln();
+    assert!((srocket_delta_v(4000f64, 10f64) - classical).abs() / classical < 1e-9);
+}
@@ -1,3 +1,4 @@
+use crate::assert_relative;
 use mathonomy::relativity::lorentz::*;
 
 #[test]
@@ -9,6 +10,108 @@ fn lorentz() {
 
     assert_eq!(
         slorentz(mathonomy::consts::SPEED_OF_LIGHT - 1f64),
-        12243.211587721227
+        // c / SquareRoot(2c - 1), correctly rounded
+        12243.211557838898
     )
 }
+
+#[test]
+fn lorentz_checked() {
+    use mathonomy::consts::SPEED_OF_LIGHT;
+    use mathonomy::relativity::error::RelativityError;
+
+    assert_eq!(
+        slorentz_checked(SPEED_OF_LIGHT - 1f64),
+        Ok(slorentz(SPEED_OF_LIGHT - 1f64))
+    );
+    assert_eq!(
+        slorentz_checked(-SPEED_OF_LIGHT / 2f64),
+        Ok(slorentz(SPEED_OF_LIGHT / 2f64))
+    );
+    assert_eq!(
+        slorentz_checked(SPEED_OF_LIGHT),
+        Err(RelativityError::SuperluminalSpeed)
+    );
+    // A value from the benchmarks, which returns NaN unchecked
+    assert!(slorentz(83823847328f64).is_nan());
+    assert_eq!(
+        slorentz_checked(83823847328f64),
+        Err(RelativityError::SuperluminalSpeed)
+    );
+    assert_eq!(
+        slorentz_checked(f64::NAN),
+        Err(RelativityError::NonFiniteInput)
+    );
+    assert_eq!(
+        slorentzt_checked(0f64, f64::INFINITY),
+        Err(RelativityError::NonFiniteInput)
+    );
+    assert_eq!(slorentztr_checked(1f64, 0f64), Ok(1f64));
+    assert_eq!(
+        slorentztr_checked(1f64, 1f64),
+        Err(RelativityError::SuperluminalSpeed)
+    );
+    assert_eq!(clorentz_checked(2f64, 1f64), Ok(2f64));
+    assert_eq!(
+        clorentz_checked(1f64, 0f64),
+        Err(RelativityError::SuperluminalSpeed)
+    );
+}
+
+/// Compares against references calculated with 50 significant digits, rounded to f64
+#[test]
+fn numerically_stable() {
+    use mathonomy::consts::SPEED_OF_LIGHT;
+    use mathonomy::relativity::energy::skinetic_energy;
+
+    // speed, γ, γ - 1, kinetic energy of 1kg
+    let references = [
+        (0.001, 1.0, 5.563250280268092e-24, 5e-7),
+        (1.0, 1.0, 5.5632502802680925e-18, 0.5),
+        (
+            1000.0,
+            1.0000000000055633,
+            5.563250280314517e-12,
+            500000.00000417244,
+        ),
+        (
+            29780.0,
+            1.0000000049337596,
+            4.933759646368285e-9,
+            443424203.28162265,
+        ),
+        (
+            SPEED_OF_LIGHT / 2f64,
+            1.1547005383792515,
+            0.15470053837925152,
+            1.3903791002172612e16,
+        ),
+        (
+            SPEED_OF_LIGHT * 0.999999,
+            707.1069579659049,
+            706.1069579659049,
+            6.346172852139574e19,
+        ),
+    ];
+
+    for (speed, lorentz, minus_one, kinetic) in references {
+        assert_relative(slorentz(speed), lorentz, 4e-16);
+        assert_relative(slorentz_minus_one(speed), minus_one, 1e-15);
+        assert_relative(skinetic_energy(speed, 1f64), kinetic, 1e-15);
+    }
+}
+
+#[test]
+fn lorentz_single_precision() {
+    let c = mathonomy::consts::SPEED_OF_LIGHT as f32;
+    let single = slorentz(0.6f32 * c);
+
+    assert!((single - 1.25f32).abs() <= 2f32 * f32::EPSILON, "{single}");
+    assert!(
+        (f64::from(single) - slorentz(0.6f64 * mathonomy::consts::SPEED_OF_LIGHT)).abs() < 1e-6
+    );
+    assert_eq!(
+        slorentz_checked(c),
+        Err(mathonomy::relativity::error::RelativityError::SuperluminalSpeed)
+    );
+}
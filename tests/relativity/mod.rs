@@ -1,2 +1,11 @@
+mod boost;
+mod collision;
+mod curvature;
+mod doppler;
 mod energy;
+mod geodesic;
+mod kinematics;
 mod lorentz;
+mod momentum;
+mod schwarzschild;
+mod typed;
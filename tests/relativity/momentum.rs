@@ -0,0 +1,88 @@
+use crate::assert_relative;
+use mathonomy::consts::{SPEED_OF_LIGHT, SPEED_OF_LIGHT_SQUARED};
+use mathonomy::relativity::energy::*;
+use mathonomy::relativity::momentum::*;
+use mathonomy::vectors::vec3::Vec3;
+
+#[test]
+fn energy_momentum_relation() {
+    let (speed, mass) = (0.6 * SPEED_OF_LIGHT, 2f64);
+    let momentum = smomentum(speed, mass);
+    assert_relative(momentum, 1.5 * SPEED_OF_LIGHT, 1e-12);
+    assert_relative(srest_energy(mass), 2f64 * SPEED_OF_LIGHT_SQUARED, 1e-12);
+    assert_relative(
+        stotal_energy(speed, mass),
+        2.5 * SPEED_OF_LIGHT_SQUARED,
+        1e-12,
+    );
+    assert_relative(
+        senergy_from_momentum(momentum, mass),
+        stotal_energy(speed, mass),
+        1e-12,
+    );
+    assert_relative(
+        stotal_energy(speed, mass) - srest_energy(mass),
+        skinetic_energy(speed, mass),
+        1e-12,
+    );
+    assert_relative(
+        smass_from_energy_momentum(stotal_energy(speed, mass), momentum),
+        mass,
+        1e-12,
+    );
+    assert_relative(
+        smomentum_from_energy(stotal_energy(speed, mass), mass),
+        momentum,
+        1e-12,
+    );
+}
+
+#[test]
+fn conversions() {
+    let (speed, mass) = (0.99 * SPEED_OF_LIGHT, 9.109e-31);
+    let kinetic = skinetic_energy(speed, mass);
+    let momentum = smomentum(speed, mass);
+
+    assert_relative(sspeed_from_momentum(momentum, mass), speed, 1e-12);
+    assert_relative(sspeed_from_kinetic_energy(kinetic, mass), speed, 1e-12);
+    assert_relative(
+        smomentum_from_kinetic_energy(kinetic, mass),
+        momentum,
+        1e-12,
+    );
+    assert_relative(
+        skinetic_energy_from_momentum(momentum, mass),
+        kinetic,
+        1e-12,
+    );
+
+    // Slow particles follow Newtonian mechanics without cancellation
+    let slow = skinetic_energy_from_momentum(1e-3 * mass, mass);
+    assert_relative(slow, 0.5 * mass * 1e-6, 1e-12);
+
+    let velocity = Vec3::new(0.3, -0.5, 0.7) * SPEED_OF_LIGHT;
+    let back = svelocity_from_momentum_vec3(smomentum_vec3(velocity, mass), mass);
+    assert_relative(back.x, velocity.x, 1e-12);
+    assert_relative(back.y, velocity.y, 1e-12);
+    assert_relative(back.z, velocity.z, 1e-12);
+}
+
+#[test]
+fn massless_particles() {
+    let energy = 1e-19;
+    let momentum = smomentum_from_energy(energy, 0f64);
+    assert_relative(momentum, energy / SPEED_OF_LIGHT, 1e-12);
+    assert_relative(senergy_from_momentum(momentum, 0f64), energy, 1e-12);
+    assert_relative(skinetic_energy_from_momentum(momentum, 0f64), energy, 1e-12);
+    assert_eq!(sspeed_from_momentum(momentum, 0f64), SPEED_OF_LIGHT);
+    assert_eq!(sspeed_from_kinetic_energy(energy, 0f64), SPEED_OF_LIGHT);
+    assert_eq!(smass_from_energy_momentum(energy, momentum), 0f64);
+
+    let velocity = svelocity_from_momentum_vec3(Vec3::new(0f64, momentum, 0f64), 0f64);
+    assert_eq!(velocity, Vec3::new(0f64, SPEED_OF_LIGHT, 0f64));
+
+    // Neither mass nor momentum
+    assert!(sspeed_from_momentum(0f64, 0f64).is_nan());
+    assert!(sspeed_from_kinetic_energy(0f64, 0f64).is_nan());
+    assert!(svelocity_from_momentum_vec3(Vec3::zero(), 0f64).x.is_nan());
+}
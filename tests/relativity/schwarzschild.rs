@@ -0,0 +1,69 @@
+use crate::assert_relative;
+use mathonomy::consts::SPEED_OF_LIGHT;
+use mathonomy::relativity::schwarzschild::*;
+
+const EARTH_MASS: f64 = 5.972e24;
+const SUN_MASS: f64 = 1.989e30;
+
+#[test]
+fn radii() {
+    let radius = sschwarzschild_radius(SUN_MASS);
+    assert_relative(radius, 2954.126555055405, 1e-14);
+    assert_relative(
+        sschwarzschild_radius(EARTH_MASS),
+        0.008869805825435335,
+        1e-14,
+    );
+    assert_eq!(sphoton_sphere_radius(SUN_MASS), 1.5 * radius);
+    assert_eq!(sisco_radius(SUN_MASS), 3f64 * radius);
+
+    // Light cannot escape from the event horizon
+    assert_relative(cescape_velocity(radius, radius), SPEED_OF_LIGHT, 1e-15);
+    assert_relative(sescape_velocity(EARTH_MASS, 6.371e6), 11185.7, 1e-4);
+}
+
+#[test]
+fn time_dilation_and_redshift() {
+    let radius = sschwarzschild_radius(SUN_MASS);
+    assert_eq!(cgravitational_time_dilation(radius, radius), 0f64);
+    assert_relative(
+        cgravitational_time_dilation(radius, 4f64 * radius),
+        0.75f64.sqrt(),
+        1e-15,
+    );
+
+    // Light from the surface of the sun
+    assert_relative(
+        sgravitational_redshift(SUN_MASS, 6.957e8),
+        2.1231392576482864e-6,
+        1e-12,
+    );
+
+    // A redshift on the way up is a blueshift on the way down
+    let up = cgravitational_redshift(radius, 2f64 * radius, 8f64 * radius);
+    let down = cgravitational_redshift(radius, 8f64 * radius, 2f64 * radius);
+    assert_relative((1f64 + up) * (1f64 + down), 1f64, 1e-15);
+    assert_relative(
+        1f64 + cgravitational_redshift(radius, 4f64 * radius, f64::INFINITY),
+        1f64 / cgravitational_time_dilation(radius, 4f64 * radius),
+        1e-15,
+    );
+}
+
+#[test]
+fn satellite_clocks() {
+    // A GPS satellite runs about 38µs per day faster than a clock on the equator
+    let drift = sclock_drift(EARTH_MASS, 26_561_750f64, 3874f64, 6_378_137f64, 465.1);
+    assert_relative(drift, 4.460739850387487e-10, 1e-9);
+    assert!((drift * 86400e6 - 38.5).abs() < 0.1);
+
+    // A clock at rest far away is the reference of the rates
+    assert_eq!(sclock_rate(EARTH_MASS, f64::INFINITY, 0f64), 1f64);
+    let rate = sclock_rate(EARTH_MASS, 26_561_750f64, 3874f64);
+    assert_relative(
+        rate,
+        sgravitational_time_dilation(EARTH_MASS, 26_561_750f64)
+            * (1f64 - (3874f64 / SPEED_OF_LIGHT).powi(2)).sqrt(),
+        1e-15,
+    );
+}
@@ -0,0 +1,30 @@
+use mathonomy::consts::*;
+use mathonomy::quantities::{Energy, Mass, Time, Velocity};
+use mathonomy::relativity::{energy, lorentz, typed};
+
+#[test]
+fn matches_untyped() {
+    let speed = SPEED_OF_LIGHT / 2f64;
+
+    assert_eq!(
+        typed::slorentz(Velocity::new(speed)),
+        lorentz::slorentz(speed)
+    );
+    assert_eq!(
+        typed::skinetic_energy(Velocity::new(speed), Mass::new(3f64)),
+        Energy::new(energy::skinetic_energy(speed, 3f64))
+    );
+    assert_eq!(
+        typed::ckinetic_energy(Velocity::new(speed), Mass::new(3f64), 2f64),
+        Energy::new(energy::ckinetic_energy(speed, 3f64, 2f64))
+    );
+    assert_eq!(typed::clorentz(Time::new(2f64), Time::new(1f64)), 2f64);
+    assert_eq!(
+        typed::slorentzt(Velocity::new(speed), Time::new(2f64)),
+        Time::new(lorentz::slorentzt(speed, 2f64))
+    );
+    assert_eq!(
+        typed::slorentztr(Time::new(2f64), 0.36),
+        Time::new(lorentz::slorentztr(2f64, 0.36))
+    );
+}
@@ -0,0 +1,73 @@
+use mathonomy::matrices::{mat4::Mat4, GenericMatrix};
+use mathonomy::tensors::IndexPosition::{Lower, Upper};
+use mathonomy::tensors::*;
+
+fn delta() -> Tensor2 {
+    Tensor2::from_fn(
+        [Upper, Lower],
+        |[mu, nu]| if mu == nu { 1f64 } else { 0f64 },
+    )
+}
+
+#[test]
+fn components() {
+    let mut tensor = Tensor3::from_fn([Upper, Lower, Lower], |[a, b, c]| {
+        (a * 100 + b * 10 + c) as f64
+    });
+    assert_eq!(tensor.rank(), 3);
+    assert_eq!(tensor.get([1, 2, 3]), 123f64);
+    assert_eq!(tensor.components[27], 123f64);
+
+    tensor.set([3, 0, 1], -1f64);
+    assert_eq!(tensor.get([3, 0, 1]), -1f64);
+    assert_eq!((tensor * 2f64 - tensor).get([1, 2, 3]), 123f64);
+}
+
+#[test]
+fn contraction() {
+    // δ^μ_μ is the number of dimensions
+    let trace: Tensor0 = delta().contract(0, 1);
+    assert_eq!(trace.scalar(), 4f64);
+
+    // δ^μ_ν v^ν = v^μ
+    let vector = Tensor1::new([1f64, 2f64, 3f64, 4f64], [Upper]);
+    let product: Tensor3 = delta().outer(vector);
+    assert_eq!(product.indices, [Upper, Lower, Upper]);
+    assert_eq!(product.contract::<1, 4>(1, 2), vector);
+
+    let covector = Tensor1::new([4f64, 3f64, 2f64, 1f64], [Lower]);
+    assert_eq!(vector.contract_all(covector), 20f64);
+}
+
+#[test]
+#[should_panic]
+fn contraction_of_two_upper_indices() {
+    let tensor = Tensor2::zero([Upper, Upper]);
+    let _: Tensor0 = tensor.contract(0, 1);
+}
+
+#[test]
+fn raising_and_lowering() {
+    let minkowski = MetricTensor::minkowski();
+    let velocity = Tensor1::new([2f64, 1f64, 0.5, 0f64], [Upper]);
+    let lowered = velocity.lower(0, &minkowski);
+    assert_eq!(lowered.components, [-2f64, 1f64, 0.5, 0f64]);
+    assert_eq!(lowered.raise(0, &minkowski), velocity);
+    // u_μ u^μ
+    assert_eq!(lowered.contract_all(velocity), -2.75);
+
+    // g^μλ g_λν = δ^μ_ν for a non-diagonal metric
+    let metric = MetricTensor::new(Mat4::new(
+        -0.8, 0f64, 0f64, -0.3, //
+        0f64, 1.25, 0f64, 0f64, //
+        0f64, 0f64, 4f64, 0f64, //
+        -0.3, 0f64, 0f64, 2f64,
+    ))
+    .unwrap();
+    let mixed = metric.tensor().raise(0, &metric);
+    for (a, b) in mixed.components.iter().zip(delta().components) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+
+    assert!(MetricTensor::new(Mat4::zero()).is_none());
+}
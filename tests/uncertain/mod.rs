@@ -0,0 +1,96 @@
+use crate::assert_relative;
+use mathonomy::consts::codata::{ELECTRON_MASS, NEWTONIAN_CONSTANT_OF_GRAVITATION};
+use mathonomy::consts::{astro, SPEED_OF_LIGHT_SQUARED};
+use mathonomy::prelude::NumericalExtensions;
+use mathonomy::relativity::energy::{skinetic_energy, srest_energy};
+use mathonomy::uncertain::{spropagate, Uncertain};
+
+#[test]
+fn arithmetic() {
+    let a = Uncertain::new(10f64, 3f64);
+    let b = Uncertain::new(20f64, 4f64);
+
+    assert_eq!((a + b).mean, 30f64);
+    assert_eq!((a + b).std_dev(), 5f64);
+    assert_eq!((a - b).std_dev(), 5f64);
+    assert_eq!((a * 2f64).std_dev(), 6f64);
+    // Relative uncertainties add in quadrature for products and quotients
+    assert_relative((a * b).relative_uncertainty(), 0.3f64.hypot(0.2), 1e-15);
+    assert_relative((a / b).relative_uncertainty(), 0.3f64.hypot(0.2), 1e-15);
+    assert_relative(a.powi(2).std_dev(), 2f64 * 10f64 * 3f64, 1e-15);
+    assert_relative(
+        NumericalExtensions::sqrt(b).std_dev(),
+        4f64 / (2f64 * 20f64.sqrt()),
+        1e-15,
+    );
+    assert_eq!(Uncertain::<0>::exact(1f64).std_dev(), 0f64);
+    assert_eq!(format!("{:.1}", a + b), "30.0 ± 5.0");
+}
+
+#[test]
+fn correlations() {
+    // Independent values do not know that they are the same
+    let independent = Uncertain::new(5f64, 1f64);
+    assert_relative((independent - independent).std_dev(), 2f64.sqrt(), 1e-15);
+
+    // Tracked values do
+    let x = Uncertain::<2>::tracked(5f64, 1f64, 0);
+    let y = Uncertain::<2>::tracked(3f64, 2f64, 1);
+    assert_eq!((x - x).std_dev(), 0f64);
+    assert_eq!((x + x).std_dev(), 2f64);
+    assert_eq!((x / x).std_dev(), 0f64);
+    assert_relative((x + y).std_dev(), 5f64.sqrt(), 1e-15);
+
+    let sum = x + y;
+    assert_eq!(x.covariance(y), 0f64);
+    assert_eq!(sum.covariance(x), 1f64);
+    assert_relative(sum.correlation(y), 2f64 / 5f64.sqrt(), 1e-15);
+    assert_relative((-x).correlation(x), -1f64, 1e-15);
+}
+
+#[test]
+fn formulas() {
+    // σ_E = SquareRoot((∂E/∂v σ_v)^2 + (∂E/∂m σ_m)^2)
+    let (speed, mass) = (Uncertain::new(1e8, 1e5), Uncertain::new(2f64, 0.01));
+    let energy = spropagate(|[v, m]| skinetic_energy(v, m), [speed, mass]);
+    assert_relative(energy.mean, 1.0920235003569958e16, 1e-13);
+    assert_relative(energy.std_dev(), 5.959123657766431e13, 1e-12);
+
+    // Tracking the inputs gives the same result when passing them to the formula directly
+    let tracked = skinetic_energy(
+        Uncertain::<2>::tracked(1e8, 1e5, 0),
+        Uncertain::<2>::tracked(2f64, 0.01, 1),
+    );
+    assert_relative(tracked.mean, energy.mean, 1e-15);
+    assert_relative(tracked.std_dev(), energy.std_dev(), 1e-12);
+}
+
+#[test]
+fn constants() {
+    let electron = ELECTRON_MASS.uncertain();
+    let rest = srest_energy(electron);
+
+    assert_eq!(rest.mean, ELECTRON_MASS.value * SPEED_OF_LIGHT_SQUARED);
+    assert_relative(
+        rest.relative_uncertainty(),
+        ELECTRON_MASS.relative_uncertainty(),
+        1e-12,
+    );
+    assert_eq!(Uncertain::<0>::from(astro::PARSEC).std_dev(), 0f64);
+
+    // The mass of the sun is calculated from GM and G, so it is fully correlated with G
+    let g = Uncertain::<1>::tracked(
+        NEWTONIAN_CONSTANT_OF_GRAVITATION.value,
+        NEWTONIAN_CONSTANT_OF_GRAVITATION.uncertainty,
+        0,
+    );
+    let sun = Uncertain::exact(astro::SOLAR_GM.value) / g;
+    assert_relative(sun.mean, astro::SOLAR_MASS.value, 1e-5);
+    assert_relative(
+        sun.relative_uncertainty(),
+        NEWTONIAN_CONSTANT_OF_GRAVITATION.relative_uncertainty(),
+        1e-12,
+    );
+    assert_relative((g * sun).mean, astro::SOLAR_GM.value, 1e-15);
+    assert!((g * sun).relative_uncertainty() < 1e-15);
+}
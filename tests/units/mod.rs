@@ -0,0 +1,203 @@
+use crate::assert_relative;
+use mathonomy::consts::codata::{ELECTRON_MASS, PLANCK_LENGTH};
+use mathonomy::consts::SPEED_OF_LIGHT;
+use mathonomy::relativity::energy::*;
+use mathonomy::relativity::error::RelativityError;
+use mathonomy::relativity::lorentz::*;
+use mathonomy::relativity::momentum::{cmomentum_in, smomentum_in, sspeed_from_momentum_in};
+use mathonomy::relativity::schwarzschild::*;
+use mathonomy::units::*;
+
+/// The constants of a system have to match its units
+fn check_consistency<U: UnitSystem>() {
+    let si = Si::SPEED_OF_LIGHT;
+    assert_relative(U::SPEED_OF_LIGHT, si * U::TIME / U::LENGTH, 1e-15);
+    assert_relative(U::SPEED_OF_LIGHT_SQUARED, U::SPEED_OF_LIGHT.powi(2), 1e-15);
+    assert_relative(
+        U::ENERGY,
+        U::MASS * U::LENGTH.powi(2) / U::TIME.powi(2),
+        1e-15,
+    );
+    assert_relative(
+        U::REDUCED_PLANCK_CONSTANT,
+        Si::REDUCED_PLANCK_CONSTANT / (U::ENERGY * U::TIME),
+        1e-15,
+    );
+    assert_relative(
+        U::GRAVITATIONAL_CONSTANT,
+        Si::GRAVITATIONAL_CONSTANT * U::MASS.powi(2) / (U::ENERGY * U::LENGTH),
+        1e-15,
+    );
+}
+
+#[test]
+fn consistency() {
+    check_consistency::<Si>();
+    check_consistency::<Natural>();
+    check_consistency::<Geometrized>();
+    check_consistency::<Gaussian>();
+
+    // G = ħc / (Planck mass)^2 and ħ = (Planck length)^2
+    assert_relative(Natural::GRAVITATIONAL_CONSTANT, 6.70883e-57, 1e-5);
+    assert_relative(
+        Geometrized::REDUCED_PLANCK_CONSTANT,
+        PLANCK_LENGTH.value.powi(2),
+        1e-5,
+    );
+}
+
+#[test]
+fn conversions() {
+    let electron = convert_mass::<Si, Natural, _>(ELECTRON_MASS.value);
+    assert_relative(electron, 510998.95, 1e-9);
+    assert_relative(
+        convert_mass::<Natural, Si, _>(electron),
+        ELECTRON_MASS.value,
+        1e-15,
+    );
+    assert_relative(convert_mass::<Si, Geometrized, _>(1.98841e30), 1476.6, 1e-4);
+
+    assert_eq!(convert_energy::<Si, Gaussian, _>(1f64), 1e7);
+    assert_eq!(convert_length::<Gaussian, Si, _>(100f64), 1f64);
+    assert_relative(
+        convert_time::<Si, Geometrized, _>(1f64),
+        SPEED_OF_LIGHT,
+        1e-15,
+    );
+    // ħc = 197.327 MeV fm
+    assert_relative(
+        convert_length::<Natural, Si, _>(1e-6) * 1e15,
+        197.3269804,
+        1e-9,
+    );
+}
+
+#[test]
+fn formulas() {
+    // β = 0.6 gives γ = 1.25 in every system
+    assert_relative(slorentz_in::<Natural, _>(0.6), 1.25, 1e-15);
+    assert_relative(
+        slorentz_in::<Gaussian, _>(0.6 * Gaussian::SPEED_OF_LIGHT),
+        1.25,
+        1e-15,
+    );
+    assert_eq!(
+        slorentz_in::<Si, _>(0.6 * SPEED_OF_LIGHT),
+        slorentz(0.6 * SPEED_OF_LIGHT)
+    );
+    assert_relative(slorentz_minus_one_in::<Natural, _>(0.6), 0.25, 1e-15);
+    assert_relative(slorentz_minus_one_in::<Natural, _>(1e-9), 5e-19, 1e-9);
+    assert_eq!(
+        slorentz_minus_one_in::<Si, _>(1f64),
+        slorentz_minus_one(1f64)
+    );
+
+    // E = m in natural units, E^2 = p^2 + m^2
+    assert_eq!(srest_energy_in::<Natural, _>(510998.95), 510998.95);
+    assert_eq!(senergy_from_momentum_in::<Natural, _>(3f64, 4f64), 5f64);
+    assert_relative(smomentum_in::<Natural, _>(0.6, 4f64), 3f64, 1e-15);
+    assert_relative(
+        sspeed_from_momentum_in::<Natural, _>(3f64, 4f64),
+        0.6,
+        1e-15,
+    );
+
+    // The same kinetic energy in all systems
+    let (speed, mass) = (0.3 * SPEED_OF_LIGHT, 2f64);
+    let joules = skinetic_energy(speed, mass);
+    let natural = skinetic_energy_in::<Natural, _>(0.3, convert_mass::<Si, Natural, _>(mass));
+    assert_relative(convert_energy::<Natural, Si, _>(natural), joules, 1e-14);
+    let gaussian = skinetic_energy_in::<Gaussian, _>(speed * 100f64, mass * 1000f64);
+    assert_relative(convert_energy::<Gaussian, Si, _>(gaussian), joules, 1e-14);
+
+    // rₛ = 2M in geometrized units
+    let sun = convert_mass::<Si, Geometrized, _>(1.98841e30);
+    assert_eq!(sschwarzschild_radius_in::<Geometrized, _>(sun), 2f64 * sun);
+    assert_relative(2f64 * sun, sschwarzschild_radius(1.98841e30), 1e-15);
+    assert_relative(
+        sschwarzschild_radius_in::<Gaussian, _>(1.98841e33),
+        sschwarzschild_radius(1.98841e30) * 100f64,
+        1e-14,
+    );
+}
+
+#[test]
+fn complex_and_checked_formulas() {
+    // γ = 1.25 at β = 0.6
+    assert_eq!(clorentz_in::<Natural, _>(2.5, 2f64), 1.25);
+    assert_eq!(
+        slorentz_checked_in::<Natural, _>(0.6),
+        Ok(slorentz_in::<Natural, _>(0.6))
+    );
+    assert_eq!(
+        slorentz_checked_in::<Natural, _>(1f64),
+        Err(RelativityError::SuperluminalSpeed)
+    );
+    // 0.6 m/s is far below c in SI
+    assert!(slorentz_checked(0.6f64).is_ok());
+
+    let electron = 510998.95;
+    assert_relative(
+        ckinetic_energy_in::<Natural, _>(0.6, electron, 1.25),
+        0.25 * electron,
+        1e-15,
+    );
+    assert_eq!(
+        skinetic_energy_checked_in::<Natural, _>(0.6, electron),
+        Ok(skinetic_energy_in::<Natural, _>(0.6, electron))
+    );
+    assert_eq!(
+        ckinetic_energy_checked_in::<Natural, _>(1.5, electron, 1.25),
+        Err(RelativityError::SuperluminalSpeed)
+    );
+    assert_eq!(
+        skinetic_energy_checked_in::<Natural, _>(0.6, -1f64),
+        Err(RelativityError::NegativeMass)
+    );
+    assert_eq!(
+        ctotal_energy_in::<Natural, _>(electron, 1.25),
+        1.25 * electron
+    );
+    assert_eq!(
+        ctotal_energy_in::<Si, _>(2f64, 1.25),
+        ctotal_energy(2f64, 1.25)
+    );
+    assert_relative(cmomentum_in::<Natural, _>(0.6, 4f64, 1.25), 3f64, 1e-15);
+
+    // rₛ = 2M and v = SquareRoot(rₛ / r) in geometrized units
+    assert_eq!(cschwarzschild_radius_in::<Geometrized, _>(3f64), 6f64);
+    assert_relative(
+        cschwarzschild_radius_in::<Gaussian, _>(1.32712440018e26),
+        cschwarzschild_radius(1.32712440018e20) * 100f64,
+        1e-14,
+    );
+    assert_eq!(cescape_velocity_in::<Geometrized, _>(2f64, 8f64), 0.5);
+    assert_relative(
+        cescape_velocity_in::<Gaussian, _>(2f64, 8f64),
+        cescape_velocity(2f64, 8f64) * 100f64,
+        1e-15,
+    );
+    assert_eq!(
+        cgravitational_time_dilation_in::<Geometrized, _>(2f64, 8f64),
+        cgravitational_time_dilation(2f64, 8f64)
+    );
+    assert_eq!(
+        cgravitational_redshift_in::<Geometrized, _>(2f64, 8f64, f64::INFINITY),
+        cgravitational_redshift(2f64, 8f64, f64::INFINITY)
+    );
+    // At rest at r = 8M, dτ/dt = SquareRoot(1 - 2/8), and moving with β = 0.6 multiplies it by 0.8
+    assert_relative(
+        cclock_rate_in::<Geometrized, _>(2f64, 8f64, 0.6),
+        0.75f64.sqrt() * 0.8,
+        1e-15,
+    );
+}
+
+#[test]
+fn single_precision_conversions() {
+    let electron = convert_mass::<Si, Natural, _>(9.109384e-31f32);
+    assert!((electron - 510998.95f32).abs() <= 0.1, "{electron}");
+    assert_eq!(convert_energy::<Si, Gaussian, _>(2f32), 2e7f32);
+    assert_eq!(convert_length::<Si, Gaussian, _>(3f32), 300f32);
+    assert_eq!(convert_time::<Si, Si, _>(4f32), 4f32);
+}
@@ -1,4 +1,12 @@
+mod vecn;
+
+use crate::assert_close;
+use mathonomy::matrices::mat4::Mat4;
+use mathonomy::vectors::vec2::Vec2;
 use mathonomy::vectors::vec3::Vec3;
+use mathonomy::vectors::vec4::Vec4;
+use mathonomy::vectors::vecn::VecN;
+use mathonomy::vectors::GenericVector;
 
 #[test]
 pub fn test_vectors() {
@@ -6,4 +14,138 @@ pub fn test_vectors() {
     let backward = Vec3::backward();
 
     let new = forward + backward;
+    assert_eq!(new, Vec3::zero());
+}
+
+#[test]
+fn arithmetic() {
+    let a = Vec3::new(1.0, 2.0, 3.0);
+    let b = Vec3::new(4.0, 5.0, 6.0);
+
+    assert_eq!(a + b, Vec3::new(5.0, 7.0, 9.0));
+    assert_eq!(b - a, Vec3::filled(3.0));
+    assert_eq!(a * b, Vec3::new(4.0, 10.0, 18.0));
+    assert_eq!(b / a, Vec3::new(4.0, 2.5, 2.0));
+    assert_eq!(a + 1.0, Vec3::new(2.0, 3.0, 4.0));
+    assert_eq!(a - 1.0, Vec3::new(0.0, 1.0, 2.0));
+    assert_eq!(a * 2.0, Vec3::new(2.0, 4.0, 6.0));
+    assert_eq!(a / 2.0, Vec3::new(0.5, 1.0, 1.5));
+    assert_eq!(-a, Vec3::new(-1.0, -2.0, -3.0));
+    assert!(Vec3::one().all_eq());
+    assert!(!a.all_eq());
+
+    assert_eq!(
+        Vec2::new(1.0, 2.0) + Vec2::new(3.0, 4.0),
+        Vec2::new(4.0, 6.0)
+    );
+    assert_eq!(Vec2::new(1.0, 2.0) * 3.0, Vec2::new(3.0, 6.0));
+}
+
+#[test]
+fn dot_and_cross() {
+    let a = Vec3::new(1.0, 2.0, 3.0);
+    let b = Vec3::new(4.0, 5.0, 6.0);
+
+    assert_eq!(a | b, 32.0);
+    assert_eq!(a ^ b, Vec3::new(-3.0, 6.0, -3.0));
+    assert_eq!(Vec3::xaxis() ^ Vec3::yaxis(), Vec3::zaxis());
+    assert_eq!((a ^ b) | a, 0.0);
+
+    assert_eq!(Vec2::new(1.0, 2.0) | Vec2::new(3.0, 4.0), 11.0);
+    assert_eq!(Vec2::xaxis() ^ Vec2::yaxis(), 1.0);
+    assert_eq!(Vec2::yaxis() ^ Vec2::xaxis(), -1.0);
+}
+
+#[test]
+fn geometry() {
+    let a = Vec3::new(3.0, 0.0, 4.0);
+
+    assert_eq!(a.length(), 5.0);
+    assert_eq!(a.length_squared(), 25.0);
+    assert_close(a.normalize().length(), 1.0, 1e-12);
+    assert_eq!(a.distance(Vec3::zero()), 5.0);
+    assert_close(
+        Vec3::xaxis().angle_between(Vec3::yaxis()),
+        core::f64::consts::FRAC_PI_2,
+        1e-12,
+    );
+    assert_close(
+        Vec2::new(1.0, 1.0).angle_between(Vec2::xaxis()),
+        core::f64::consts::FRAC_PI_4,
+        1e-12,
+    );
+
+    assert_eq!(a.project(Vec3::xaxis()), Vec3::new(3.0, 0.0, 0.0));
+    assert_eq!(a.reject(Vec3::xaxis()), Vec3::new(0.0, 0.0, 4.0));
+    assert_eq!(
+        Vec3::new(1.0, -1.0, 0.0).reflect(Vec3::new(0.0, 2.0, 0.0)),
+        Vec3::new(1.0, 1.0, 0.0)
+    );
+}
+
+#[test]
+fn homogeneous_coordinates() {
+    let point = Vec3::new(2.0, 4.0, 6.0);
+
+    assert_eq!(Vec4::from_point(point), Vec4::new(2.0, 4.0, 6.0, 1.0));
+    assert_eq!(Vec4::from_direction(point), Vec4::new(2.0, 4.0, 6.0, 0.0));
+    assert_eq!(Vec4::from(point).w, 0.0);
+    assert_eq!(Vec3::from(Vec4::from_point(point)), point);
+    assert_eq!(
+        Vec4::new(2.0, 4.0, 6.0, 2.0).perspective_divide(),
+        Vec3::new(1.0, 2.0, 3.0)
+    );
+
+    // Adding an offset (w = 0) to a point keeps it a point
+    let offset = Vec4::new(1.0, 1.0, 1.0, 0.0);
+    assert_eq!(
+        (Vec4::from_point(point) + offset).perspective_divide(),
+        Vec3::new(3.0, 5.0, 7.0)
+    );
+
+    // Translating a point moves it, translating a direction does not
+    let translation = Mat4::translation(Vec3::new(1.0, 1.0, 1.0));
+    assert_eq!(
+        translation * Vec4::from_point(point),
+        Vec4::new(3.0, 5.0, 7.0, 1.0)
+    );
+    assert_eq!(
+        translation * Vec4::from_direction(point),
+        Vec4::from_direction(point)
+    );
+
+    assert_eq!(Vec4::xaxis() ^ Vec4::yaxis(), Vec4::zaxis());
+    assert_eq!(Vec4::one() | Vec4::filled(2.0), 8.0);
+    assert_eq!(Vec4::forward() + Vec4::backward(), Vec4::zero());
+    assert_eq!(Vec4::new(0.0, 3.0, 0.0, 4.0).length(), 5.0);
+}
+
+#[test]
+fn single_precision_vectors() {
+    let a: Vec3<f32> = Vec3::new(1f32, 2f32, 2f32);
+
+    assert_eq!(a.length(), 3f32);
+    assert_eq!(a * 2f32, Vec3::new(2f32, 4f32, 4f32));
+    assert_eq!(a | a, 9f32);
+    assert_eq!(Vec3::<f32>::axis(0) ^ Vec3::axis(1), Vec3::axis(2));
+    assert_eq!(Vec3::<f32>::zero(), Vec3::filled(0f32));
+    assert_eq!(Vec4::<f32>::one().length_squared(), 4f32);
+    assert_eq!(Vec2::new(1f32, 0f32) ^ Vec2::new(0f32, 1f32), 1f32);
+    assert!((a.normalize().length() - 1f32).abs() <= f32::EPSILON);
+    assert_eq!(VecN::from(a).components, [1f32, 2f32, 2f32]);
+    assert_eq!(Vec4::from_point(a).w, 1f32);
+}
+
+#[test]
+fn generic_constructors() {
+    fn unit_x<V: GenericVector>() -> V {
+        V::axis(0)
+    }
+
+    assert_eq!(unit_x::<Vec2<f32>>(), Vec2::new(1f32, 0f32));
+    assert_eq!(unit_x::<Vec3>(), Vec3::xaxis());
+    assert_eq!(unit_x::<Vec4>(), Vec4::forward());
+    assert_eq!(unit_x::<VecN<5>>(), VecN::axis(0));
+    assert_eq!(<Vec3 as GenericVector>::zero(), Vec3::zero());
+    assert_eq!(<Vec2 as GenericVector>::one(), Vec2::one());
 }
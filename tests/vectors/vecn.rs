@@ -0,0 +1,42 @@
+use mathonomy::vectors::vec2::Vec2;
+use mathonomy::vectors::vec3::Vec3;
+use mathonomy::vectors::vecn::VecN;
+use mathonomy::vectors::GenericVector;
+
+#[test]
+fn lossless_conversions() {
+    let vec3 = Vec3::new(0.1, -2.5, 1e300);
+    assert_eq!(Vec3::from(VecN::from(vec3)), vec3);
+
+    let vec2 = Vec2::new(f64::MIN_POSITIVE, -0.0);
+    assert_eq!(Vec2::from(VecN::from(vec2)), vec2);
+
+    assert_eq!(VecN::from(vec3).components, [0.1, -2.5, 1e300]);
+}
+
+#[test]
+fn state_vectors() {
+    // Position and velocity in one 6-dimensional vector
+    let state = VecN::new([1.0, 2.0, 3.0, 0.5, 0.5, 0.5]);
+    let derivative = VecN::new([0.5, 0.5, 0.5, 0.0, 0.0, -9.81]);
+
+    let next = state + derivative * 2.0;
+    assert_eq!(next, VecN::new([2.0, 3.0, 4.0, 0.5, 0.5, -19.12]));
+    assert_eq!(next[5], -19.12);
+
+    assert_eq!(VecN::<12>::one().length_squared(), 12.0);
+    assert_eq!(VecN::<9>::axis(4) | VecN::<9>::axis(4), 1.0);
+    assert_eq!(VecN::<9>::axis(4) | VecN::<9>::axis(5), 0.0);
+    assert!(VecN::<6>::filled(3.0).all_eq());
+    assert_eq!(VecN::<6>::default(), VecN::zero());
+    assert_eq!(
+        VecN::new([3.0, 4.0, 0.0, 0.0, 0.0, 0.0]).normalize()[1],
+        0.8
+    );
+}
+
+#[test]
+fn cross_products() {
+    assert_eq!(VecN::<3>::axis(0) ^ VecN::<3>::axis(1), VecN::<3>::axis(2));
+    assert_eq!(VecN::new([1.0, 0.0]) ^ VecN::new([0.0, 1.0]), 1.0);
+}